@@ -0,0 +1,87 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+"""
+Compares looking up the function containing an address by walking back
+over the symbols of a `SortedDict` from `SortedDict.partitionPoint`, which is
+what `SymbolsSegment.getFunctionContaining` does, against the previous
+approach of calling `SortedDict.getKeyRight` once per skipped label.
+
+Run from the root of the repository:
+
+    python3 benchmarks/sortedDictLookups.py
+"""
+
+from __future__ import annotations
+
+import argparse
+from pathlib import Path
+import random
+import sys
+import timeit
+
+sys.path.insert(0, str(Path(__file__).parent.parent / "spimdisasm" / "common"))
+
+from SortedDict import SortedDict # type: ignore
+
+
+def buildDict(functionsCount: int, labelsPerFunction: int) -> tuple[SortedDict[bool], list[int]]:
+    "Each function is followed by `labelsPerFunction` labels, 0x10 bytes apart. The values tell if the key is a function"
+    sortedDict: SortedDict[bool] = SortedDict()
+    pairs: list[tuple[int, bool]] = []
+    address = 0x80000000
+    for _ in range(functionsCount):
+        pairs.append((address, True))
+        for i in range(labelsPerFunction):
+            pairs.append((address + 0x10 * (i + 1), False))
+        address += 0x10 * (labelsPerFunction + 1)
+    sortedDict.addMany(pairs)
+    return sortedDict, [key for key, _ in pairs]
+
+def findFunctionRepeatedLookups(sortedDict: SortedDict[bool], address: int) -> int|None:
+    key = address
+    while True:
+        pair = sortedDict.getKeyRight(key, inclusive=True)
+        if pair is None:
+            return None
+        symVram, isFunction = pair
+        if isFunction:
+            return symVram
+        key = symVram - 1
+
+def findFunctionPartitionPoint(sortedDict: SortedDict[bool], address: int) -> int|None:
+    "Same approach as `SymbolsSegment.getFunctionContaining`"
+    index = sortedDict.partitionPoint(address, inclusive=True)
+    while index > 0:
+        index -= 1
+        symVram, isFunction = sortedDict.getPairAtIndex(index)
+        if isFunction:
+            return symVram
+    return None
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--functions", help="Amount of functions. Defaults to 5000", type=int, default=5000)
+    parser.add_argument("--lookups", help="Amount of lookups per measurement. Defaults to 20000", type=int, default=20000)
+    parser.add_argument("--repeat", help="Amount of measurements, the best one is reported. Defaults to 5", type=int, default=5)
+    args = parser.parse_args()
+
+    random.seed(0)
+    print(f"{'labels/func':>11} {'getKeyRight':>12} {'partitionPoint':>15} {'speedup':>8}")
+    for labelsPerFunction in (0, 1, 4, 16, 64):
+        sortedDict, keys = buildDict(args.functions, labelsPerFunction)
+        addresses = [random.choice(keys) + 4 for _ in range(args.lookups)]
+
+        for address in addresses[:100]:
+            assert findFunctionRepeatedLookups(sortedDict, address) == findFunctionPartitionPoint(sortedDict, address)
+
+        old = min(timeit.repeat(lambda: [findFunctionRepeatedLookups(sortedDict, x) for x in addresses], number=1, repeat=args.repeat))
+        new = min(timeit.repeat(lambda: [findFunctionPartitionPoint(sortedDict, x) for x in addresses], number=1, repeat=args.repeat))
+        print(f"{labelsPerFunction:>11} {old*1000:>10.2f}ms {new*1000:>13.2f}ms {old/new:>7.2f}x")
+
+
+if __name__ == "__main__":
+    main()
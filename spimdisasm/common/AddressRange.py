@@ -0,0 +1,190 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2022-2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+"""
+Address range utilities.
+
+Everything exported from this module is considered part of the public API, so
+downstream tools don't need to reimplement rom/vram translation or range
+arithmetic.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+
+
+@dataclasses.dataclass(order=True)
+class AddressRange:
+    """
+    A half-open `[start, end)` range of addresses, either rom or vram.
+
+    Ranges are ordered by their start, and then by their end.
+    """
+
+    start: int
+    end: int
+
+    @staticmethod
+    def fromSize(start: int, size: int) -> AddressRange:
+        return AddressRange(start, start + size)
+
+    @property
+    def size(self) -> int:
+        return self.end - self.start
+
+    def isEmpty(self) -> bool:
+        return self.end <= self.start
+
+    def isInRange(self, address: int) -> bool:
+        return self.start <= address < self.end
+
+    def __contains__(self, address: int) -> bool:
+        return self.isInRange(address)
+
+    def containsRange(self, other: AddressRange) -> bool:
+        "Checks if `other` is fully inside this range. Empty ranges are contained by any range which includes their start"
+        return self.start <= other.start and other.end <= self.end
+
+    def overlaps(self, other: AddressRange) -> bool:
+        return self.start < other.end and other.start < self.end
+
+    def intersection(self, other: AddressRange) -> AddressRange|None:
+        "Returns the range shared by both ranges, or `None` if they don't overlap"
+        if not self.overlaps(other):
+            return None
+        return AddressRange(max(self.start, other.start), min(self.end, other.end))
+
+    def union(self, other: AddressRange) -> AddressRange:
+        "Returns the smallest range containing both ranges, including the gap between them if any"
+        return AddressRange(min(self.start, other.start), max(self.end, other.end))
+
+    def offsetOf(self, address: int) -> int:
+        "Returns the offset of `address` relative to the start of this range. Raises `ValueError` if it is outside of it"
+        if not self.isInRange(address):
+            raise ValueError(f"Address 0x{address:08X} is outside of {self}")
+        return address - self.start
+
+    def sliceBytes(self, buffer: bytes, bufferStart: int=0) -> bytes:
+        """
+        Returns the bytes of this range from `buffer`, where `bufferStart` is
+        the address of the first byte of `buffer`. Usually used with a whole
+        rom and a rom range.
+
+        Raises `ValueError` if the range is not fully inside of `buffer`.
+        """
+        bufferRange = AddressRange(bufferStart, bufferStart + len(buffer))
+        if not bufferRange.containsRange(self):
+            raise ValueError(f"{self} is outside of the buffer {bufferRange}")
+        return buffer[self.start - bufferStart:self.end - bufferStart]
+
+    def decreaseStart(self, address: int) -> None:
+        if address < self.start:
+            self.start = address
+        return None
+
+    def increaseEnd(self, address: int) -> None:
+        if address > self.end:
+            self.end = address
+        return None
+
+    def __str__(self) -> str:
+        return f"AddressRange(0x{self.start:08X}, 0x{self.end:08X})"
+
+    def __repr__(self) -> str:
+        return self.__str__()
+
+
+@dataclasses.dataclass
+class RomVramMapping:
+    """
+    Links a rom range to the vram it is loaded at, allowing to translate
+    addresses between both.
+
+    The vram range may be bigger than the rom one, to account for bss.
+    """
+
+    rom: AddressRange
+    vram: AddressRange
+
+    @staticmethod
+    def fromRom(romStart: int, romEnd: int, vramStart: int, bssSize: int=0) -> RomVramMapping:
+        return RomVramMapping(AddressRange(romStart, romEnd), AddressRange(vramStart, vramStart + romEnd - romStart + bssSize))
+
+    def vromToVram(self, vrom: int) -> int|None:
+        "Returns the vram of the given rom address, or `None` if it is outside of the rom range"
+        if not self.rom.isInRange(vrom):
+            return None
+        return self.vram.start + vrom - self.rom.start
+
+    def vramToVrom(self, vram: int) -> int|None:
+        "Returns the rom address of the given vram, or `None` if it is outside of the range backed by rom (i.e. bss)"
+        if not self.vram.isInRange(vram):
+            return None
+        vrom = self.rom.start + vram - self.vram.start
+        if not self.rom.isInRange(vrom):
+            return None
+        return vrom
+
+
+@dataclasses.dataclass
+class PiecewiseRomVramMapping:
+    """
+    Maps a contiguous vram range to multiple physically separated rom chunks,
+    for segments built from more than one rom range (i.e. code plus a patch
+    placed somewhere else on the rom).
+
+    Each chunk is loaded at the vram right after the end of the previous one.
+    """
+
+    chunks: list[RomVramMapping]
+
+    @staticmethod
+    def fromRomRanges(vramStart: int, romRanges: list[AddressRange]) -> PiecewiseRomVramMapping:
+        chunks: list[RomVramMapping] = []
+        vram = vramStart
+        for romRange in romRanges:
+            chunks.append(RomVramMapping(romRange, AddressRange.fromSize(vram, romRange.size)))
+            vram += romRange.size
+        return PiecewiseRomVramMapping(chunks)
+
+    @staticmethod
+    def concatChunks(vramStart: int, chunks: list[tuple[int, bytes]]) -> tuple[bytes, PiecewiseRomVramMapping]:
+        """
+        Joins the bytes of each `(romStart, bytes)` chunk, returning them
+        together with the mapping for them.
+        """
+        buffer = b"".join(chunkBytes for _, chunkBytes in chunks)
+        romRanges = [AddressRange.fromSize(romStart, len(chunkBytes)) for romStart, chunkBytes in chunks]
+        return buffer, PiecewiseRomVramMapping.fromRomRanges(vramStart, romRanges)
+
+    @property
+    def vram(self) -> AddressRange:
+        return AddressRange(self.chunks[0].vram.start, self.chunks[-1].vram.end)
+
+    @property
+    def romStart(self) -> int:
+        return min(chunk.rom.start for chunk in self.chunks)
+
+    @property
+    def romEnd(self) -> int:
+        return max(chunk.rom.end for chunk in self.chunks)
+
+    def isRomInRange(self, vrom: int) -> bool:
+        return any(chunk.rom.isInRange(vrom) for chunk in self.chunks)
+
+    def vromToVram(self, vrom: int) -> int|None:
+        for chunk in self.chunks:
+            vram = chunk.vromToVram(vrom)
+            if vram is not None:
+                return vram
+        return None
+
+    def vramToVrom(self, vram: int) -> int|None:
+        for chunk in self.chunks:
+            vrom = chunk.vramToVrom(vram)
+            if vrom is not None:
+                return vrom
+        return None
@@ -0,0 +1,93 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from . import Utils
+
+
+class Charmap:
+    """
+    A user-provided table mapping byte sequences to text, used to detect and
+    display strings with custom encodings.
+
+    Strings decoded with a charmap are emitted as `.byte` sequences, with the
+    decoded text as a comment, since the assembler can't encode them back.
+    """
+
+    def __init__(self, terminator: int=0) -> None:
+        self.entries: dict[bytes, str] = dict()
+        self.terminator: int = terminator
+        "Byte value used to end each string"
+        self._maxEntryLength: int = 1
+
+    def addEntry(self, sequence: bytes, text: str) -> None:
+        self.entries[sequence] = text
+        self._maxEntryLength = max(self._maxEntryLength, len(sequence))
+
+    def decode(self, buf: bytes, offset: int) -> tuple[str, int]:
+        """
+        Decodes a terminated string starting at `offset`, using the longest
+        matching sequence at each position.
+
+        Returns the decoded text and the size in bytes of the string, not
+        counting the terminator. The size is negative if the string can't be
+        decoded, following `Utils.decodeBytesToStrings`.
+        """
+
+        result = ""
+        i = 0
+        while offset + i < len(buf) and buf[offset + i] != self.terminator:
+            for length in range(min(self._maxEntryLength, len(buf) - offset - i), 0, -1):
+                text = self.entries.get(buf[offset+i:offset+i+length])
+                if text is not None:
+                    result += text
+                    i += length
+                    break
+            else:
+                # Unknown byte
+                return "", -10
+
+        if offset + i >= len(buf):
+            # Reached the end of the buffer without finding the terminator
+            return "", -80
+
+        # To be a valid aligned string, the next word-aligned bytes needs to be the terminator
+        checkStartOffset = offset + i
+        checkEndOffset = min((checkStartOffset & ~3) + 4, len(buf))
+        while checkStartOffset < checkEndOffset:
+            if buf[checkStartOffset] != self.terminator:
+                return "", -100
+            checkStartOffset += 1
+
+        return result, i
+
+    @staticmethod
+    def fromTblFile(path: Path, terminator: int=0) -> Charmap:
+        """
+        Reads a charmap in the `.tbl` format, where each line looks like
+        `8140=text`, with the byte sequence written in hex. Empty lines and
+        lines starting with `#` are ignored. Malformed lines are skipped with
+        a warning.
+        """
+
+        charmap = Charmap(terminator)
+        with path.open(encoding="utf-8") as f:
+            for lineNumber, line in enumerate(f, 1):
+                line = line.rstrip("\r\n")
+                if line == "" or line.startswith("#"):
+                    continue
+                sequence, separator, text = line.partition("=")
+                try:
+                    sequenceBytes = bytes.fromhex(sequence.strip())
+                except ValueError:
+                    sequenceBytes = b""
+                if separator == "" or len(sequenceBytes) == 0:
+                    Utils.eprint(f"Warning: {path}:{lineNumber}: malformed charmap entry '{line}'. Skipping it.")
+                    continue
+                charmap.addEntry(sequenceBytes, text)
+        return charmap
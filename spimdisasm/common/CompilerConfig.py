@@ -19,9 +19,19 @@ class CompilerProperties:
     prevAlign_jumptable: int|None = None
     prevAlign_string: int|None = 2
     prevAlign_function: int|None = None
+    prevAlign_quadword: int|None = None
+    "Alignment of symbols accessed as 128 bits values (R5900's `lq`/`sq`)"
 
     pairMultipleHiToSameLow: bool = True
 
+    hasRedundantFunctionEnd: bool = False
+    """
+    The compiler may emit redundant and unreferenced function ends
+    (`jr $ra; nop`), which can be merged into the previous function.
+
+    See `GlobalConfig.DETECT_REDUNDANT_FUNCTION_END`.
+    """
+
     allowRdataMigration: bool = False
 
     bigAddendWorkaroundForMigratedFunctions: bool = True
@@ -79,7 +89,7 @@ class Compiler(enum.Enum):
     GCC = CompilerProperties("GCC", prevAlign_jumptable=3)
 
     # N64
-    IDO = CompilerProperties("IDO", hasLateRodata=True, pairMultipleHiToSameLow=False, bigAddendWorkaroundForMigratedFunctions=False, sectionAlign_text=4, sectionAlign_rodata=4)
+    IDO = CompilerProperties("IDO", hasLateRodata=True, pairMultipleHiToSameLow=False, hasRedundantFunctionEnd=True, bigAddendWorkaroundForMigratedFunctions=False, sectionAlign_text=4, sectionAlign_rodata=4)
     KMC = CompilerProperties("KMC", prevAlign_jumptable=3, sectionAlign_text=4, sectionAlign_rodata=4)
     SN64 = CompilerProperties("SN64", prevAlign_double=3, prevAlign_jumptable=3, allowRdataMigration=True, sectionAlign_text=4, sectionAlign_rodata=4)
 
@@ -90,8 +100,8 @@ class Compiler(enum.Enum):
     PSYQ = CompilerProperties("PSYQ", prevAlign_double=3, prevAlign_jumptable=3, allowRdataMigration=True)
 
     # PS2
-    MWCCPS2 = CompilerProperties("MWCCPS2", prevAlign_jumptable=4)
-    EEGCC = CompilerProperties("EEGCC", prevAlign_jumptable=3, prevAlign_string=3, prevAlign_function=3)
+    MWCCPS2 = CompilerProperties("MWCCPS2", prevAlign_jumptable=4, prevAlign_quadword=4)
+    EEGCC = CompilerProperties("EEGCC", prevAlign_jumptable=3, prevAlign_string=3, prevAlign_function=3, prevAlign_quadword=4)
 
     @staticmethod
     def fromStr(value: str) -> Compiler|None:
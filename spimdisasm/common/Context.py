@@ -7,38 +7,159 @@ from __future__ import annotations
 
 import argparse
 import dataclasses
+import json
 from pathlib import Path
+import pickle
+from typing import Any, Generator
+
+import rabbitizer
 
 from . import Utils
-from .ContextSymbols import ContextSymbol
+from .GlobalConfig import GlobalConfig
+from .Errors import SegmentOverlapError
+from .FileSectionType import FileSectionType
+from .ContextSymbols import ContextSymbol, SymbolReference, SymbolSpecialType, ReferencedAddress
 from .SymbolsSegment import SymbolsSegment
 from .GpAccesses import GpAccessContainer
+from .SortedDict import SortedDict
 from .Relocation import RelocationInfo, RelocType
+from .Diagnostics import Diagnostic, DiagnosticKind
+from .InstructionOverride import InstructionOverride
+from .AddressRange import AddressRange
+from .OverlayTable import OverlayTableEntry
+from .SymbolCreationVeto import SymbolCreationReason, SymbolCreationVetoCallback
+from .ProgressEvents import ProgressPhase, ProgressEvent, ProgressCallback
+
+
+@dataclasses.dataclass(frozen=True)
+class AddressInfo:
+    """
+    Everything the context knows about an address.
+
+    See `Context.queryAddress`.
+    """
+
+    vram: int
+    segment: SymbolsSegment
+    "The segment owning the address. It is `Context.unknownSegment` if no known segment contains it"
+    contextSym: ContextSymbol|None
+    "The symbol containing the address, or `None` if the address is not part of any known symbol"
+    offset: int
+    "Offset in bytes from the start of `contextSym`, 0 if there's no symbol"
+
+    @property
+    def sectionType(self) -> FileSectionType:
+        if self.contextSym is None:
+            return FileSectionType.Unknown
+        return self.contextSym.sectionType
+
+    def __str__(self) -> str:
+        if self.contextSym is None:
+            return f"0x{self.vram:08X}"
+        if self.offset == 0:
+            return self.contextSym.getName()
+        return f"{self.contextSym.getName()} + 0x{self.offset:X}"
 
 
 @dataclasses.dataclass
-class AddressRange:
-    start: int
-    end: int
+class FunctionContinuation:
+    """
+    Links two ranges of code which belong to the same functions, like the
+    code of bank-switched games continuing on another bank.
 
-    def isInRange(self, address: int) -> bool:
-        return self.start <= address < self.end
+    See `Context.addFunctionContinuation`.
+    """
 
-    def decreaseStart(self, address: int) -> None:
-        if address < self.start:
-            self.start = address
-        return None
+    fromRange: AddressRange
+    "The range where the functions start"
+    toRange: AddressRange
+    "The range where the functions continue"
 
-    def increaseEnd(self, address: int) -> None:
-        if address > self.end:
-            self.end = address
-        return None
+    def links(self, vramA: int, vramB: int) -> bool:
+        "Checks if one of the passed addresses is inside of `fromRange` and the other one is inside of `toRange`"
+        if self.fromRange.isInRange(vramA) and self.toRange.isInRange(vramB):
+            return True
+        if self.fromRange.isInRange(vramB) and self.toRange.isInRange(vramA):
+            return True
+        return False
+
+@dataclasses.dataclass
+class StringPoolEntry:
+    vram: int
+    vromAddress: int|None
+    size: int
+    "Size of the string in bytes, including the null terminator"
+    value: str
+    "The decoded and escaped string"
+    overlayCategory: str|None = None
+
+    def containsVram(self, vram: int) -> bool:
+        return self.vram <= vram < self.vram + self.size
 
     def __str__(self) -> str:
-        return f"AddressRange(0x{self.start:08X}, 0x{self.end:08X})"
+        return f"0x{self.vram:08X}: \"{self.value}\""
+
+
+@dataclasses.dataclass(frozen=True)
+class SourceLocation:
+    """
+    A line of the source code which generated some code, usually read from
+    debug information. See `Context.addSourceLine`.
+    """
+
+    fileName: str
+    line: int
+
+    def __str__(self) -> str:
+        return f"{self.fileName}:{self.line}"
+
+
+@dataclasses.dataclass
+class UnknownSegmentPlaceholder:
+    """
+    A symbol referenced from the analyzed sections which is outside of every
+    known segment, so it has to be provided externally (i.e. by the linker
+    script or another object file).
+    """
+
+    contextSym: ContextSymbol
+    inferredSize: int|None
+    "Size given by the user, implied by the symbol's type or guessed from the distance to the next placeholder"
+
+    def toCsv(self) -> str:
+        size = f"0x{self.inferredSize:X}" if self.inferredSize is not None else ""
+        return f"0x{self.contextSym.vram:08X},{self.contextSym.getName()},{self.contextSym.getType()},{size},{self.contextSym.referenceCounter}"
+
+
+@dataclasses.dataclass
+class UnreferencedFunction:
+    """
+    A function which is never referenced by code nor data, which may be dead
+    code or be referenced by a pointer table which hasn't been discovered.
+    """
+
+    contextSym: ContextSymbol
+    onlySelfReferenced: bool
+    "The function is only referenced by itself, like a recursive call"
+
+    def toCsv(self) -> str:
+        size = f"0x{self.contextSym.getSize():X}"
+        return f"0x{self.contextSym.vram:08X},{self.contextSym.getName()},{size},{self.contextSym.isUserDeclared},{self.onlySelfReferenced}"
+
+
+@dataclasses.dataclass
+class ContextMergeConflict:
+    """
+    Two symbols at the same address of the same segment found while merging
+    two contexts. See `Context.merge`.
+    """
+
+    kept: ContextSymbol
+    "The symbol which stays on the merged context. The references of the discarded symbol are moved to this one"
+    discarded: ContextSymbol
+    keptFromOther: bool
+    "The kept symbol comes from the merged context instead of the context being merged into"
 
-    def __repr__(self) -> str:
-        return self.__str__()
 
 class SymbolsRanges:
     def __init__(self, start: int, end: int) -> None:
@@ -76,6 +197,9 @@ class SymbolsRanges:
         return ret
 
 class Context:
+    CACHE_FORMAT_VERSION = 4
+    "Bump this value each time the layout of the context changes in a way that invalidates old caches"
+
     N64DefaultBanned = {
         0x7FFFFFE0, # osInvalICache
         0x7FFFFFF0, # osInvalDCache, osWritebackDCache, osWritebackDCacheAll
@@ -89,7 +213,7 @@ class Context:
         self.globalSegment = SymbolsSegment(self, 0x0, 0x1000, 0x80000000, 0x80001000, overlayCategory=None)
         # For symbols that we don't know where they come from
         self.unknownSegment = SymbolsSegment(self, None, None, 0x00000000, 0xFFFFFFFF, overlayCategory=None)
-        self._isTheUnknownSegment = True
+        self.unknownSegment._isTheUnknownSegment = True
 
         self.overlaySegments: dict[str, dict[int, SymbolsSegment]] = dict()
         "Outer key is overlay type, inner key is the vrom of the overlay's segment"
@@ -100,12 +224,67 @@ class Context:
         # Stuff that looks like pointers, but the disassembler shouldn't count it as a pointer
         self.bannedSymbols: set[int] = set()
         self.bannedRangedSymbols: list[AddressRange] = list()
+        self.symbolCreationVetoes: list[SymbolCreationVetoCallback] = list()
+
+        self.progressCallbacks: list[ProgressCallback] = list()
+        "See `addProgressCallback`"
+
+        self.rawDataRanges: list[AddressRange] = list()
+        "Vram ranges of data which should never be symbolized, see `addRawDataRange`"
+
+        self.entryPoints: set[int] = set()
+        "Vram addresses of functions reachable from outside of the analyzed code, see `addEntryPoint`"
+
+        self.instrCategoryRanges: list[tuple[AddressRange, rabbitizer.Enum]] = list()
+        "See `addInstrCategoryRange`"
+
+        self.stringGuessAllowedRanges: list[AddressRange] = list()
+        "See `addStringGuessAllowedRange`"
+        self.stringGuessDeniedRanges: list[AddressRange] = list()
+        "See `addStringGuessDeniedRange`"
 
         self.globalRelocationOverrides: dict[int, RelocationInfo] = dict()
         "key: vrom address"
 
+        self.instructionOverrides: dict[int, InstructionOverride] = dict()
+        "key: vrom address of the instruction"
+
+        self.functionContinuations: list[FunctionContinuation] = list()
+
         self.gpAccesses = GpAccessContainer()
 
+        self.stringPool: dict[str|None, SortedDict[StringPoolEntry]] = dict()
+        """
+        Every string found by the string pool analysis, see
+        `GlobalConfig.STRING_POOL_ANALYSIS`.
+
+        key: overlay category of the string, `None` for the global segment
+
+        value: the strings of that overlay category, keyed by the vram of the
+        start of each string
+        """
+
+        self.stringSymbolsByContent: dict[str, list[ContextSymbol]] = dict()
+        """
+        Every string symbol found during the analysis, grouped by their
+        decoded contents. See `getDuplicatedStrings`.
+        """
+        self._stringContentBySymbol: dict[ContextSymbol, str] = dict()
+
+        self.functionSignatures: dict[str, str] = dict()
+        "key: signature hash of a function, value: name of the function"
+
+        self.diagnostics: list[Diagnostic] = list()
+        "Suspicious patterns found during the analysis, see `addDiagnostic`"
+
+        self.sourceLines: SortedDict[SourceLocation] = SortedDict()
+        """
+        The source line which generated the code at each address, usually
+        read from debug information. See `addSourceLine`.
+
+        key: vram of the first instruction generated by the line
+        """
+
 
     def changeGlobalSegmentRanges(self, vromStart: int, vromEnd: int, vramStart: int, vramEnd: int) -> None:
         if vromStart == vromEnd:
@@ -120,18 +299,334 @@ class Context:
         self.totalVramRange.decreaseStart(vramStart)
         self.totalVramRange.increaseEnd(vramEnd)
 
-    def addOverlaySegment(self, overlayCategory: str, segmentVromStart: int, segmentVromEnd: int, segmentVramStart: int, segmentVramEnd: int) -> SymbolsSegment:
+    def _checkOverlaySegmentOverlaps(self, overlayCategory: str, segmentVromStart: int, vromStart: int|None, vromEnd: int|None, vramStart: int, vramEnd: int) -> SymbolsSegment|None:
+        """
+        Raises a `SegmentOverlapError` if the new segment collides with an
+        existing segment of the same category, either by using the same
+        segment rom or by overlapping their rom ranges.
+
+        Returns the existing segment if it has the exact same ranges.
+        """
+        segmentsPerVrom = self.overlaySegments.get(overlayCategory, dict())
+
+        existing = segmentsPerVrom.get(segmentVromStart)
+        if existing is not None:
+            if existing.vromStart == vromStart and existing.vromEnd == vromEnd and existing.vramStart == vramStart and existing.vramEnd == vramEnd:
+                return existing
+            raise SegmentOverlapError(f"Another segment was already registered at segment rom 0x{segmentVromStart:06X}", vromStart, vromEnd, vramStart, vramEnd, overlayCategory, existing.vromStart, existing.vromEnd, existing.vramStart, existing.vramEnd)
+
+        if vromStart is None or vromEnd is None or vromStart == vromEnd:
+            return None
+        for other in segmentsPerVrom.values():
+            if other.vromStart is None or other.vromEnd is None or other.vromStart == other.vromEnd:
+                continue
+            if vromStart < other.vromEnd and other.vromStart < vromEnd:
+                raise SegmentOverlapError("The rom range overlaps the one of another segment", vromStart, vromEnd, vramStart, vramEnd, overlayCategory, other.vromStart, other.vromEnd, other.vramStart, other.vramEnd)
+        return None
+
+    def _registerOverlaySegment(self, overlayCategory: str, segmentVromStart: int, segment: SymbolsSegment) -> None:
         if overlayCategory not in self.overlaySegments:
             self.overlaySegments[overlayCategory] = dict()
-        segment = SymbolsSegment(self, segmentVromStart, segmentVromEnd, segmentVramStart, segmentVramEnd, overlayCategory=overlayCategory)
         self.overlaySegments[overlayCategory][segmentVromStart] = segment
 
         if self._defaultVramRanges:
-            self.totalVramRange.mainAddressRange.start = segmentVramStart
-            self.totalVramRange.mainAddressRange.end = segmentVramEnd
+            self.totalVramRange.mainAddressRange.start = segment.vramStart
+            self.totalVramRange.mainAddressRange.end = segment.vramEnd
             self._defaultVramRanges = False
-        self.totalVramRange.decreaseStart(segmentVramStart)
-        self.totalVramRange.increaseEnd(segmentVramEnd)
+        self.totalVramRange.decreaseStart(segment.vramStart)
+        self.totalVramRange.increaseEnd(segment.vramEnd)
+
+    def addOverlaySegment(self, overlayCategory: str, segmentVromStart: int, segmentVromEnd: int, segmentVramStart: int, segmentVramEnd: int) -> SymbolsSegment:
+        """
+        Registers a segment for an overlay of the given category.
+
+        Adding a segment with the exact same ranges as an already registered
+        one returns the existing segment. A `SegmentOverlapError` is raised if
+        the segment collides with a different segment of the same category.
+
+        Use `addBssOnlyOverlaySegment` for overlays which don't have any rom.
+        """
+        existing = self._checkOverlaySegmentOverlaps(overlayCategory, segmentVromStart, segmentVromStart, segmentVromEnd, segmentVramStart, segmentVramEnd)
+        if existing is not None:
+            return existing
+
+        segment = SymbolsSegment(self, segmentVromStart, segmentVromEnd, segmentVramStart, segmentVramEnd, overlayCategory=overlayCategory)
+        self._registerOverlaySegment(overlayCategory, segmentVromStart, segment)
+        return segment
+
+    def addBssOnlyOverlaySegment(self, overlayCategory: str, segmentVromStart: int, segmentVramStart: int, segmentVramEnd: int) -> SymbolsSegment:
+        """
+        Registers a segment for an overlay of the given category which only
+        has bss, so it doesn't have a rom range.
+
+        `segmentVromStart` is only used to identify the segment, it is the
+        same value that has to be passed as the `segmentVromStart` of the bss
+        sections of this segment. It must not be used by any other segment of
+        the same category.
+        """
+        existing = self._checkOverlaySegmentOverlaps(overlayCategory, segmentVromStart, None, None, segmentVramStart, segmentVramEnd)
+        if existing is not None:
+            return existing
+
+        segment = SymbolsSegment(self, None, None, segmentVramStart, segmentVramEnd, overlayCategory=overlayCategory)
+        self._registerOverlaySegment(overlayCategory, segmentVromStart, segment)
+        return segment
+
+    def addOverlaySegmentsFromTable(self, overlayCategory: str, entries: list[OverlayTableEntry]) -> list[SymbolsSegment]:
+        """
+        Registers an overlay segment for each entry of a parsed overlay table
+        (see `parseOverlayTable`), skipping the ones which already exist.
+
+        Returns the newly added segments.
+        """
+
+        existingSegments = self.overlaySegments.get(overlayCategory, dict())
+        segments: list[SymbolsSegment] = []
+        for entry in entries:
+            if entry.vromStart in existingSegments:
+                continue
+            segments.append(self.addOverlaySegment(overlayCategory, entry.vromStart, entry.vromEnd, entry.vramStart, entry.vramEnd))
+        return segments
+
+    def addStringToPool(self, entry: StringPoolEntry) -> None:
+        self.stringPool.setdefault(entry.overlayCategory, SortedDict())[entry.vram] = entry
+
+    def getStringFromPool(self, vram: int, overlayCategory: str|None=None) -> StringPoolEntry|None:
+        """
+        Returns the string of the pool which contains the given address, even
+        if it points to the middle of the string.
+
+        Only the strings of the given overlay category are considered, since
+        overlays of different categories may share the same vram.
+        """
+
+        pool = self.stringPool.get(overlayCategory)
+        if pool is None:
+            return None
+        pair = pool.getKeyRight(vram, inclusive=True)
+        if pair is None:
+            return None
+        entry = pair[1]
+        if not entry.containsVram(vram):
+            return None
+        return entry
+
+    def addSourceLine(self, vram: int, fileName: str, line: int) -> None:
+        """
+        Registers the source line which generated the code starting at `vram`,
+        which is emitted as a comment when disassembling functions (see
+        `GlobalConfig.ASM_COMMENT_SOURCE_LINES`).
+
+        The line applies until the next registered address.
+        """
+        self.sourceLines[vram] = SourceLocation(fileName, line)
+
+    def getSourceLine(self, vram: int) -> SourceLocation|None:
+        "Returns the source line registered exactly at `vram`, if any"
+        return self.sourceLines.map.get(vram)
+
+    def registerStringSymbol(self, contextSym: ContextSymbol, value: str) -> None:
+        "Registers the decoded contents of a string symbol, used to find duplicated strings"
+        if contextSym in self._stringContentBySymbol:
+            return
+        self._stringContentBySymbol[contextSym] = value
+        self.stringSymbolsByContent.setdefault(value, []).append(contextSym)
+
+    def getDuplicatedStrings(self, contextSym: ContextSymbol) -> list[ContextSymbol]:
+        """
+        Returns the other string symbols which have the exact same contents as
+        the given one, sorted by address.
+        """
+        value = self._stringContentBySymbol.get(contextSym)
+        if value is None:
+            return []
+        return sorted((sym for sym in self.stringSymbolsByContent[value] if sym is not contextSym), key=ContextSymbol._sortKey)
+
+    def getAllDuplicatedStrings(self) -> dict[str, list[ContextSymbol]]:
+        """
+        Returns every string content which was found on more than one symbol,
+        which may help to identify if the compiler was pooling strings or not.
+        """
+        return {value: sorted(symsList, key=ContextSymbol._sortKey) for value, symsList in self.stringSymbolsByContent.items() if len(symsList) > 1}
+
+    def getSegmentForVram(self, vram: int, overlayCategory: str|None=None, segmentVromStart: int|None=None) -> SymbolsSegment:
+        """
+        Returns the segment containing the given address.
+
+        The global segment is checked first. Overlay segments can be narrowed
+        down to a category and the rom start of one of its segments, since
+        different overlays may share the same vram. Without those hints the
+        first overlay segment containing the address is returned.
+
+        Returns `unknownSegment` if no segment contains the address.
+        """
+        if self.globalSegment.isVramInRange(vram):
+            return self.globalSegment
+
+        for category, segmentsPerVrom in self.overlaySegments.items():
+            if overlayCategory is not None and category != overlayCategory:
+                continue
+            for vromStart, segment in segmentsPerVrom.items():
+                if segmentVromStart is not None and vromStart != segmentVromStart:
+                    continue
+                if segment.isVramInRange(vram):
+                    return segment
+        return self.unknownSegment
+
+    def queryAddress(self, vram: int, overlayCategory: str|None=None, segmentVromStart: int|None=None) -> AddressInfo:
+        """
+        Finds the segment owning the given address and the symbol containing
+        it, together with the offset of the address inside that symbol. Meant
+        for tools like debuggers or trace viewers which need to show a raw
+        address as `symbol + offset`.
+
+        Labels inside functions are skipped over, so addresses inside a
+        function are reported relative to the function itself.
+
+        See `getSegmentForVram` for the overlay hints.
+        """
+        segment = self.getSegmentForVram(vram, overlayCategory, segmentVromStart)
+        address = segment.translateVram(vram)
+
+        key = address
+        while True:
+            pair = segment.symbols.getKeyRight(key, inclusive=True)
+            if pair is None:
+                break
+            symVram, contextSym = pair
+            symType = contextSym.getTypeSpecial()
+            if isinstance(symType, SymbolSpecialType) and symType.isTargetLabel():
+                key = symVram - 1
+                continue
+            if address < symVram + contextSym.getSize():
+                return AddressInfo(vram, segment, contextSym, address - symVram)
+            break
+        return AddressInfo(vram, segment, None, 0)
+
+    def getReferencedAddresses(self) -> list[ReferencedAddress]:
+        """
+        Returns a read-only snapshot of every symbol gathered so far, from the
+        global segment first and then from every overlay segment.
+
+        See `SymbolsSegment.getReferencedAddresses`.
+        """
+        referencedAddresses = self.globalSegment.getReferencedAddresses()
+        for segmentsPerVrom in self.overlaySegments.values():
+            for segment in segmentsPerVrom.values():
+                referencedAddresses.extend(segment.getReferencedAddresses())
+        return referencedAddresses
+
+    def addDiagnostic(self, kind: DiagnosticKind, message: str, vram: int, vromAddress: int|None=None, symbolName: str|None=None) -> Diagnostic:
+        diagnostic = Diagnostic(kind, message, vram, vromAddress, symbolName)
+        self.diagnostics.append(diagnostic)
+        return diagnostic
+
+    def getDiagnostics(self, kind: DiagnosticKind|None=None) -> list[Diagnostic]:
+        if kind is None:
+            return list(self.diagnostics)
+        return [x for x in self.diagnostics if x.kind == kind]
+
+    def getDiagnosticsInRange(self, vramStart: int, vramEnd: int, vromStart: int|None=None, vromEnd: int|None=None) -> list[Diagnostic]:
+        """
+        Returns the diagnostics reported inside the given vram range.
+
+        If a vrom range is given too then diagnostics which know their vrom
+        must be inside of it, so diagnostics of overlays sharing the same vram
+        don't leak into each other.
+        """
+        result: list[Diagnostic] = []
+        for x in self.diagnostics:
+            if not (vramStart <= x.vram < vramEnd):
+                continue
+            if vromStart is not None and vromEnd is not None and x.vromAddress is not None:
+                if not (vromStart <= x.vromAddress < vromEnd):
+                    continue
+            result.append(x)
+        return result
+
+    def validateUserSymbols(self, sectionRanges: list[AddressRange]|None=None) -> list[Diagnostic]:
+        """
+        Checks the user declared symbols for problems which would produce weird
+        results during analysis, without registering them on the context.
+
+        Should be called after every user symbol has been added and before
+        analyzing any section. The following problems are reported:
+
+        - User declared sizes overlapping the next symbol.
+        - Symbols not aligned to the size of their declared type.
+        - User declared sizes going past the end of their segment, or past the
+          end of any of the given `sectionRanges`.
+        """
+
+        diagnostics: list[Diagnostic] = []
+
+        for segment in self.getSegmentsSorted():
+            symbolsList = [x for x in segment.symbols.values() if x.isUserDeclared]
+            for i, contextSym in enumerate(symbolsList):
+                name = contextSym.getName()
+
+                typeSize = contextSym.getSizeFromType()
+                if typeSize is not None and typeSize > 1 and contextSym.vram % min(typeSize, 8) != 0:
+                    diagnostics.append(Diagnostic(DiagnosticKind.MISALIGNED_SYMBOL, f"Symbol of type '{contextSym.getType()}' is not aligned to {min(typeSize, 8)} bytes", contextSym.vram, contextSym.vromAddress, name))
+
+                if contextSym.userDeclaredSize is None:
+                    continue
+                symEnd = contextSym.vram + contextSym.userDeclaredSize
+
+                if i + 1 < len(symbolsList):
+                    nextSym = symbolsList[i + 1]
+                    if symEnd > nextSym.vram:
+                        diagnostics.append(Diagnostic(DiagnosticKind.OVERLAPPING_SYMBOLS, f"User declared size (0x{contextSym.userDeclaredSize:X}) overlaps the next symbol {nextSym.getName()} (0x{nextSym.vram:08X})", contextSym.vram, contextSym.vromAddress, name))
+
+                if segment.vramStart <= contextSym.vram < segment.vramEnd and symEnd > segment.vramEnd:
+                    diagnostics.append(Diagnostic(DiagnosticKind.SYMBOL_CROSSES_BOUNDARY, f"User declared size (0x{contextSym.userDeclaredSize:X}) goes past the end of its segment (0x{segment.vramEnd:08X})", contextSym.vram, contextSym.vromAddress, name))
+                    continue
+
+                for sectionRange in sectionRanges or []:
+                    if sectionRange.isInRange(contextSym.vram) and symEnd > sectionRange.end:
+                        diagnostics.append(Diagnostic(DiagnosticKind.SYMBOL_CROSSES_BOUNDARY, f"User declared size (0x{contextSym.userDeclaredSize:X}) goes past the end of its section (0x{sectionRange.end:08X})", contextSym.vram, contextSym.vromAddress, name))
+                        break
+
+        return diagnostics
+
+    def readFunctionSignaturesCsv(self, filepath: Path) -> None:
+        """
+        Reads a database of known functions, where each row has the signature
+        hash of a function and its name.
+
+        Functions whose signature matches any of the hashes will be named
+        accordingly, unless they already have a name.
+
+        The signature hash of a function can be obtained with
+        `SymbolFunction.getSignatureHash`.
+        """
+
+        if not filepath.exists():
+            return
+
+        signatures_file = Utils.readCsv(filepath)
+        for row in signatures_file:
+            if len(row) < 2:
+                continue
+
+            signatureHash, funcName = row[0], row[1]
+            if signatureHash.strip() in {"", "-"}:
+                continue
+            self.functionSignatures[signatureHash.strip().lower()] = funcName.strip()
+
+    def addOverlaySegmentAlternativeVram(self, overlayCategory: str, segmentVromStart: int, vramStart: int) -> SymbolsSegment:
+        """
+        Registers an alternative address the given overlay segment may be
+        loaded at.
+
+        See `SymbolsSegment.alternativeVramStarts`.
+        """
+
+        segment = self.overlaySegments[overlayCategory][segmentVromStart]
+        segment.addAlternativeVramStart(vramStart)
+
+        self.totalVramRange.decreaseStart(vramStart)
+        self.totalVramRange.increaseEnd(vramStart + segment.vramSize)
 
         return segment
 
@@ -171,6 +666,37 @@ class Context:
     def addBannedSymbolRangeBySize(self, rangeStart: int, size: int) -> None:
         self.bannedRangedSymbols.append(AddressRange(rangeStart, rangeStart + size))
 
+    def addSymbolCreationVeto(self, callback: SymbolCreationVetoCallback) -> None:
+        """
+        Registers a callback which is consulted every time the analysis finds
+        a reference it would create a symbol for, allowing to reject it
+        depending on the reason it is being created.
+
+        Only references automatically discovered by the analysis are checked,
+        user declared symbols are always created.
+        """
+        self.symbolCreationVetoes.append(callback)
+
+    def isSymbolCreationVetoed(self, address: int, reason: SymbolCreationReason) -> bool:
+        for callback in self.symbolCreationVetoes:
+            if callback(address, reason):
+                return True
+        return False
+
+    def addProgressCallback(self, callback: ProgressCallback) -> None:
+        """
+        Registers a callback which will be notified of coarse progress events,
+        like loading symbol files into the context or analyzing each section.
+        """
+        self.progressCallbacks.append(callback)
+
+    def reportProgress(self, phase: ProgressPhase, percent: float, *, segmentName: str|None=None, sectionName: str|None=None) -> None:
+        if len(self.progressCallbacks) == 0:
+            return
+        event = ProgressEvent(phase, min(max(percent, 0.0), 100.0), segmentName, sectionName)
+        for callback in self.progressCallbacks:
+            callback(event)
+
     def isAddressBanned(self, address: int) -> bool:
         if address in self.bannedSymbols:
             return True
@@ -179,11 +705,406 @@ class Context:
                 return True
         return False
 
+    def addRawDataRange(self, rangeStart: int, rangeEnd: int) -> None:
+        """
+        Declares the words in the given vram range as raw data, so they are
+        never symbolized even if they look like pointers (like RNG seeds or
+        colors).
+
+        Unlike banned addresses, this doesn't affect references to this range.
+        """
+        self.rawDataRanges.append(AddressRange(rangeStart, rangeEnd))
+
+    def addRawDataRangeBySize(self, rangeStart: int, size: int) -> None:
+        self.rawDataRanges.append(AddressRange(rangeStart, rangeStart + size))
+
+    def isRawDataAddress(self, address: int) -> bool:
+        for ranged in self.rawDataRanges:
+            if ranged.isInRange(address):
+                return True
+        return False
+
+    def addStringGuessAllowedRange(self, rangeStart: int, rangeEnd: int) -> None:
+        """
+        Symbols starting in the given vram range skip the string guesser level
+        heuristics, so they are guessed as strings whenever their contents can
+        be decoded and satisfy the rest of the string guesser settings.
+        """
+        self.stringGuessAllowedRanges.append(AddressRange(rangeStart, rangeEnd))
+
+    def addStringGuessDeniedRange(self, rangeStart: int, rangeEnd: int) -> None:
+        """
+        Symbols starting in the given vram range are never guessed as strings.
+        Takes precedence over `addStringGuessAllowedRange`.
+        """
+        self.stringGuessDeniedRanges.append(AddressRange(rangeStart, rangeEnd))
+
+    def getStringGuessRangeOverride(self, address: int) -> bool|None:
+        """
+        Returns `False` if the address is on a denied string guessing range,
+        `True` if it is on an allowed one or `None` otherwise.
+        """
+        for ranged in self.stringGuessDeniedRanges:
+            if ranged.isInRange(address):
+                return False
+        for ranged in self.stringGuessAllowedRanges:
+            if ranged.isInRange(address):
+                return True
+        return None
+
+    def addInstrCategoryRange(self, rangeStart: int, rangeEnd: int, instrCat: rabbitizer.Enum) -> None:
+        """
+        Decodes the instructions on the given vram range with the given
+        instruction category instead of the one of the text section containing
+        them, like a handwritten function using a different ISA.
+
+        Ranges added later take precedence over earlier ones. See also
+        `ContextSymbol.instrCategory`.
+        """
+        self.instrCategoryRanges.append((AddressRange(rangeStart, rangeEnd), instrCat))
+
+    def getInstrCategoryRangeOverride(self, address: int) -> rabbitizer.Enum|None:
+        "Returns the instruction category of the last range added with `addInstrCategoryRange` containing this address, if any"
+        for ranged, instrCat in reversed(self.instrCategoryRanges):
+            if ranged.isInRange(address):
+                return instrCat
+        return None
+
     def addGlobalReloc(self, vromAddres: int, relocType: RelocType, symbol: ContextSymbol|str, addend: int=0) -> RelocationInfo:
         reloc = RelocationInfo(relocType, symbol, addend, globalReloc=True)
         self.globalRelocationOverrides[vromAddres] = reloc
         return reloc
 
+    def addFunctionContinuation(self, vramStart: int, vramEnd: int, continuationVramStart: int, continuationVramEnd: int) -> FunctionContinuation:
+        """
+        Declares the code at `[continuationVramStart, continuationVramEnd)` as
+        the continuation of the functions at `[vramStart, vramEnd)`, even if
+        they belong to different sections.
+
+        Branches between both ranges are treated as branches inside the same
+        function instead of branches outside of it or tail calls.
+        """
+        continuation = FunctionContinuation(AddressRange(vramStart, vramEnd), AddressRange(continuationVramStart, continuationVramEnd))
+        self.functionContinuations.append(continuation)
+        return continuation
+
+    def areFunctionContinuations(self, vramA: int, vramB: int) -> bool:
+        "Checks if code at both addresses was declared as belonging to the same functions by `addFunctionContinuation`"
+        for continuation in self.functionContinuations:
+            if continuation.links(vramA, vramB):
+                return True
+        return False
+
+    def getFunctionContinuationStartingAt(self, vram: int) -> FunctionContinuation|None:
+        for continuation in self.functionContinuations:
+            if continuation.toRange.start == vram:
+                return continuation
+        return None
+
+    def getFunctionContinuationEndingAt(self, vramEnd: int) -> FunctionContinuation|None:
+        for continuation in self.functionContinuations:
+            if continuation.fromRange.end == vramEnd:
+                return continuation
+        return None
+
+    def _getOrCreateInstructionOverride(self, vromAddress: int) -> InstructionOverride:
+        override = self.instructionOverrides.get(vromAddress)
+        if override is None:
+            override = InstructionOverride()
+            self.instructionOverrides[vromAddress] = override
+        return override
+
+    def addInstructionRelocOverride(self, vromAddress: int, relocType: RelocType, symbol: ContextSymbol|str, addend: int=0) -> InstructionOverride:
+        "Force the instruction at the given vrom to use the given relocation"
+        override = self._getOrCreateInstructionOverride(vromAddress)
+        override.reloc = RelocationInfo(relocType, symbol, addend)
+        return override
+
+    def addInstructionConstantOverride(self, vromAddress: int) -> InstructionOverride:
+        "Don't symbolize the instruction at the given vrom"
+        override = self._getOrCreateInstructionOverride(vromAddress)
+        override.isConstant = True
+        return override
+
+    def addInstructionPairingOverride(self, loVromAddress: int, hiVromAddress: int) -> InstructionOverride:
+        "Pair the %lo instruction at `loVromAddress` with the %hi instruction at `hiVromAddress`"
+        override = self._getOrCreateInstructionOverride(loVromAddress)
+        override.pairedHiVrom = hiVromAddress
+        return override
+
+    def getInstructionOverride(self, vromAddress: int) -> InstructionOverride|None:
+        return self.instructionOverrides.get(vromAddress)
+
+    def getSegmentsSorted(self) -> list[SymbolsSegment]:
+        """
+        Returns every segment known by the context in a deterministic order:
+        the global segment first, then the overlay segments sorted by category
+        and vrom, and the unknown segment last.
+
+        The symbols of each segment can be iterated in address order with
+        `SymbolsSegment.symbols`.
+        """
+
+        segments = [self.globalSegment]
+        for overlayCategory in sorted(self.overlaySegments.keys()):
+            segmentsPerVrom = self.overlaySegments[overlayCategory]
+            for segmentVrom in sorted(segmentsPerVrom.keys()):
+                segments.append(segmentsPerVrom[segmentVrom])
+        segments.append(self.unknownSegment)
+        return segments
+
+    def iterSymbols(self, *, includeUnknownSegment: bool=True) -> Generator[ContextSymbol, None, None]:
+        """
+        Iterates over every symbol known by the context, segment by segment in
+        the order given by `getSegmentsSorted` and sorted by address inside
+        each segment.
+        """
+
+        for segment in self.getSegmentsSorted():
+            if segment is self.unknownSegment and not includeUnknownSegment:
+                continue
+            for contextSym in segment.symbols.values():
+                yield contextSym
+
+    def getSymbolByName(self, name: str) -> ContextSymbol|None:
+        """
+        Searches a symbol by its name or any of its aliases (see
+        `ContextSymbol.addAlias`) on every segment, following the order of
+        `getSegmentsSorted`.
+        """
+
+        for segment in self.getSegmentsSorted():
+            contextSym = segment.getSymbolByName(name)
+            if contextSym is not None:
+                return contextSym
+        return None
+
+    def getReferencesTo(self, vram: int) -> list[SymbolReference]:
+        """
+        Returns every known place referencing the given address, both from
+        functions and from data symbols.
+
+        Since overlays may share the same vram, references to symbols from
+        every segment containing a symbol at the given address are returned.
+
+        The references are only known after the sections have been analyzed.
+        """
+
+        references: list[SymbolReference] = []
+
+        for segment in self.getSegmentsSorted():
+            contextSym = segment.getSymbol(vram, tryPlusOffset=False)
+            if contextSym is not None:
+                references.extend(contextSym.referenceLocations)
+        return references
+
+    def getUnknownSegmentPlaceholders(self) -> list[UnknownSegmentPlaceholder]:
+        """
+        Returns every symbol outside of every known segment, sorted by
+        address, alongside their inferred sizes.
+        """
+
+        placeholders: list[UnknownSegmentPlaceholder] = []
+        symbolsList = list(self.unknownSegment.symbols.values())
+        for i, contextSym in enumerate(symbolsList):
+            if contextSym.isDefined:
+                continue
+
+            inferredSize = contextSym.userDeclaredSize
+            if inferredSize is None:
+                inferredSize = contextSym.getSizeFromType()
+            if inferredSize is None and i + 1 < len(symbolsList):
+                distance = symbolsList[i + 1].vram - contextSym.vram
+                if distance <= GlobalConfig.UNKNOWN_SEGMENT_MAX_INFERRED_SIZE:
+                    inferredSize = distance
+            placeholders.append(UnknownSegmentPlaceholder(contextSym, inferredSize))
+        return placeholders
+
+    def saveUnknownSegmentReportToFile(self, reportPath: Path) -> None:
+        """
+        Writes a csv listing every symbol outside of every known segment. See
+        `getUnknownSegmentPlaceholders`.
+        """
+
+        with reportPath.open("w") as f:
+            f.write("address,name,type,inferred size,reference count\n")
+            for placeholder in self.getUnknownSegmentPlaceholders():
+                f.write(placeholder.toCsv() + "\n")
+
+    def addEntryPoint(self, vramAddress: int) -> None:
+        """
+        Declares the function at the given address as an entry point, like the
+        reset vector, the boot function or the entrypoint of an overlay.
+
+        Entry points are considered to be referenced even if nothing on the
+        analyzed code references them, and are used as the starting points of
+        `GlobalConfig.TEXT_REACHABILITY_ANALYSIS`.
+        """
+        self.entryPoints.add(vramAddress)
+
+    def getUnreferencedFunctions(self) -> list[UnreferencedFunction]:
+        """
+        Returns every analyzed function which is not referenced by any code nor
+        data, ignoring entry points (see `addEntryPoint`).
+
+        Functions referenced by jumptables or by other symbols are considered
+        referenced. Must be called after analyzing every section.
+        """
+
+        unreferenced: list[UnreferencedFunction] = []
+        for contextSym in self.iterSymbols(includeUnknownSegment=False):
+            if contextSym.getTypeSpecial() != SymbolSpecialType.function or not contextSym.isDefined:
+                continue
+            if contextSym.vram in self.entryPoints:
+                continue
+            if len(contextSym.referenceSymbols) > 0:
+                continue
+            otherFunctions = contextSym.referenceFunctions - {contextSym}
+            if len(otherFunctions) > 0:
+                continue
+            unreferenced.append(UnreferencedFunction(contextSym, len(contextSym.referenceFunctions) > 0))
+        return unreferenced
+
+    def saveUnreferencedFunctionsReportToFile(self, reportPath: Path) -> None:
+        """
+        Writes a csv listing every function which is never referenced. See
+        `getUnreferencedFunctions`.
+        """
+
+        with reportPath.open("w") as f:
+            f.write("address,name,size,user declared,only self referenced\n")
+            for entry in self.getUnreferencedFunctions():
+                f.write(entry.toCsv() + "\n")
+
+    @staticmethod
+    def _shouldKeepOtherOnMerge(selfSym: ContextSymbol, otherSym: ContextSymbol, preferOther: bool) -> bool:
+        if selfSym.isUserDeclared != otherSym.isUserDeclared:
+            return otherSym.isUserDeclared
+        if selfSym.isDefined != otherSym.isDefined:
+            return otherSym.isDefined
+        return preferOther
+
+    @staticmethod
+    def _absorbMergedSymbol(survivor: ContextSymbol, discarded: ContextSymbol) -> None:
+        survivor.referenceCounter += discarded.referenceCounter
+        survivor.referenceFunctions |= discarded.referenceFunctions
+        survivor.referenceSymbols |= discarded.referenceSymbols
+        survivor.referenceLocations.extend(discarded.referenceLocations)
+        for labels, otherLabels in ((survivor.branchLabels, discarded.branchLabels), (survivor.jumpTables, discarded.jumpTables), (survivor.exceptTableLabels, discarded.exceptTableLabels)):
+            for vram, label in otherLabels.items():
+                if vram not in labels:
+                    labels[vram] = label
+
+    def _mergeSegmentSymbols(self, segment: SymbolsSegment, otherSegment: SymbolsSegment, preferOther: bool, remap: dict[int, ContextSymbol], conflicts: list[ContextMergeConflict]) -> None:
+        for address, otherSym in otherSegment.symbols.items():
+            selfSym = segment.symbols.get(address)
+            if selfSym is None:
+                segment.symbols[address] = otherSym
+                continue
+
+            keepOther = self._shouldKeepOtherOnMerge(selfSym, otherSym, preferOther)
+            survivor, discarded = (otherSym, selfSym) if keepOther else (selfSym, otherSym)
+            self._absorbMergedSymbol(survivor, discarded)
+            segment.symbols[address] = survivor
+            remap[id(discarded)] = survivor
+            conflicts.append(ContextMergeConflict(survivor, discarded, keepOther))
+
+        for value, constant in otherSegment.constants.items():
+            if value not in segment.constants or preferOther:
+                segment.constants[value] = constant
+
+    def merge(self, other: Context, *, preferOther: bool=False) -> list[ContextMergeConflict]:
+        """
+        Moves every segment, symbol and user setting of `other` into this
+        context, so separately analyzed parts of a program can be combined.
+
+        Segments are matched by their overlay category and vrom start. The
+        ones which only exist on `other` are moved as a whole. Symbols at the
+        same address of the same segment are resolved by keeping the user
+        declared symbol, then the defined one, and then the one from this
+        context (or from `other` if `preferOther` is `True`). The references of
+        the discarded symbol are moved to the kept one.
+
+        Per address settings (like relocation and instruction overrides) are
+        resolved the same way as symbols without any user declared nor
+        defined flag. GOT information is not merged.
+
+        `other` should not be used after merging it.
+
+        Returns every symbol conflict found.
+        """
+
+        remap: dict[int, ContextSymbol] = dict()
+        conflicts: list[ContextMergeConflict] = []
+
+        self._mergeSegmentSymbols(self.globalSegment, other.globalSegment, preferOther, remap, conflicts)
+        self._mergeSegmentSymbols(self.unknownSegment, other.unknownSegment, preferOther, remap, conflicts)
+        if not other._defaultVramRanges:
+            self.totalVramRange.decreaseStart(other.totalVramRange.mainAddressRange.start)
+            self.totalVramRange.increaseEnd(other.totalVramRange.mainAddressRange.end)
+
+        for overlayCategory, otherSegmentsPerVrom in other.overlaySegments.items():
+            segmentsPerVrom = self.overlaySegments.setdefault(overlayCategory, dict())
+            for segmentVrom, otherSegment in otherSegmentsPerVrom.items():
+                segment = segmentsPerVrom.get(segmentVrom)
+                if segment is None:
+                    otherSegment.context = self
+                    segmentsPerVrom[segmentVrom] = otherSegment
+                    self.totalVramRange.decreaseStart(otherSegment.vramStart)
+                    self.totalVramRange.increaseEnd(otherSegment.vramEnd)
+                    continue
+                self._mergeSegmentSymbols(segment, otherSegment, preferOther, remap, conflicts)
+
+        def remapSym(sym: ContextSymbol|None) -> ContextSymbol|None:
+            if sym is None:
+                return None
+            return remap.get(id(sym), sym)
+
+        if len(remap) > 0:
+            for contextSym in self.iterSymbols():
+                contextSym.parentFunction = remapSym(contextSym.parentFunction)
+                contextSym.innerLabelOf = remapSym(contextSym.innerLabelOf)
+                contextSym.autoCreatedPadMainSymbol = remapSym(contextSym.autoCreatedPadMainSymbol)
+                contextSym.jumpTableBase = remapSym(contextSym.jumpTableBase)
+                contextSym.referenceFunctions = {remap.get(id(x), x) for x in contextSym.referenceFunctions}
+                contextSym.referenceSymbols = {remap.get(id(x), x) for x in contextSym.referenceSymbols}
+                for location in contextSym.referenceLocations:
+                    location.referencer = remap.get(id(location.referencer), location.referencer)
+                for labels in (contextSym.branchLabels, contextSym.jumpTables, contextSym.exceptTableLabels):
+                    for vram, label in list(labels.items()):
+                        labels[vram] = remap.get(id(label), label)
+
+        self.bannedSymbols |= other.bannedSymbols
+        self.bannedRangedSymbols.extend(other.bannedRangedSymbols)
+        self.symbolCreationVetoes.extend(other.symbolCreationVetoes)
+        self.rawDataRanges.extend(other.rawDataRanges)
+        self.entryPoints |= other.entryPoints
+        self.instrCategoryRanges.extend(other.instrCategoryRanges)
+        self.stringGuessAllowedRanges.extend(other.stringGuessAllowedRanges)
+        self.stringGuessDeniedRanges.extend(other.stringGuessDeniedRanges)
+        self.functionContinuations.extend(other.functionContinuations)
+        self.diagnostics.extend(other.diagnostics)
+
+        for vrom, reloc in other.globalRelocationOverrides.items():
+            if vrom not in self.globalRelocationOverrides or preferOther:
+                self.globalRelocationOverrides[vrom] = reloc
+        for vrom, override in other.instructionOverrides.items():
+            if vrom not in self.instructionOverrides or preferOther:
+                self.instructionOverrides[vrom] = override
+        for signature, funcName in other.functionSignatures.items():
+            if signature not in self.functionSignatures or preferOther:
+                self.functionSignatures[signature] = funcName
+        for overlayCategory, otherPool in other.stringPool.items():
+            pool = self.stringPool.setdefault(overlayCategory, SortedDict())
+            for vram, entry in otherPool.items():
+                if vram not in pool or preferOther:
+                    pool[vram] = entry
+
+        for contextSym, contents in other._stringContentBySymbol.items():
+            self.registerStringSymbol(remap.get(id(contextSym), contextSym), contents)
+
+        return conflicts
+
     def saveContextToFile(self, contextPath: Path) -> None:
         with contextPath.open("w") as f:
             self.globalSegment.saveContextToFile(f)
@@ -202,11 +1123,114 @@ class Context:
                     overlaySegment.saveContextToFile(f)
 
 
+    def saveCHeaderToFile(self, headerPath: Path, typeMapping: dict[str, str]|None=None) -> None:
+        """
+        Writes C declarations for every symbol known by the context.
+
+        `typeMapping` allows to override which C type is used for each type
+        known by spimdisasm. See `ContextSymbol.getCDeclaration`.
+        """
+
+        with headerPath.open("w") as f:
+            self.globalSegment.saveCHeaderToFile(f, typeMapping)
+
+            for overlayCategory, segmentsPerVrom in self.overlaySegments.items():
+                for segmentVrom, overlaySegment in segmentsPerVrom.items():
+                    f.write(f"\n/* {overlayCategory} 0x{segmentVrom:06X} */\n")
+                    overlaySegment.saveCHeaderToFile(f, typeMapping)
+
+    @staticmethod
+    def _getCacheHeader() -> dict[str, str|int]:
+        from .. import __version__
+        return {
+            "magic": "spimdisasm context cache",
+            "formatVersion": Context.CACHE_FORMAT_VERSION,
+            "spimdisasmVersion": __version__,
+        }
+
+    def saveCacheToFile(self, cachePath: Path) -> None:
+        """
+        Serializes the whole context (segments, symbols and references) into
+        the given file, so it can be reloaded in later runs with
+        `Context.loadCacheFromFile` instead of analyzing everything again.
+
+        Callbacks set with `ContextSymbol.setNameGetCallback`, symbol creation
+        vetoes and progress callbacks are not preserved.
+
+        Requires `GlobalConfig.ALLOW_CONTEXT_CACHE` to be enabled.
+        """
+
+        if not GlobalConfig.ALLOW_CONTEXT_CACHE:
+            raise RuntimeError("Saving the context cache requires `ALLOW_CONTEXT_CACHE` to be enabled")
+
+        # Callbacks may not be picklable
+        symbolCreationVetoes = self.symbolCreationVetoes
+        progressCallbacks = self.progressCallbacks
+        self.symbolCreationVetoes = list()
+        self.progressCallbacks = list()
+        try:
+            with cachePath.open("wb") as f:
+                f.write(json.dumps(self._getCacheHeader()).encode("utf-8") + b"\n")
+                pickle.dump(self, f)
+        finally:
+            self.symbolCreationVetoes = symbolCreationVetoes
+            self.progressCallbacks = progressCallbacks
+
+    @staticmethod
+    def loadCacheFromFile(cachePath: Path) -> Context|None:
+        """
+        Loads a context previously saved with `Context.saveCacheToFile`.
+
+        `None` is returned if the file does not exist or if the cache was
+        generated by a different version of spimdisasm.
+
+        The cache is stored with `pickle`, so loading a file from an untrusted
+        source can execute arbitrary code. Because of this, loading a cache
+        requires `GlobalConfig.ALLOW_CONTEXT_CACHE` to be enabled.
+        """
+
+        if not GlobalConfig.ALLOW_CONTEXT_CACHE:
+            raise RuntimeError("Loading the context cache requires `ALLOW_CONTEXT_CACHE` to be enabled")
+
+        if not cachePath.exists():
+            return None
+
+        with cachePath.open("rb") as f:
+            # The header is plain json, so stale or foreign files are rejected without unpickling anything
+            try:
+                header = json.loads(f.readline().decode("utf-8"))
+            except (UnicodeDecodeError, json.JSONDecodeError):
+                Utils.eprint(f"Warning: '{cachePath}' is not a valid context cache. Ignoring it.")
+                return None
+            if header != Context._getCacheHeader():
+                Utils.eprint(f"Warning: context cache '{cachePath}' is stale. Ignoring it.")
+                return None
+
+            context = pickle.load(f)
+        assert isinstance(context, Context)
+        return context
+
+    def __getstate__(self) -> dict[str, Any]:
+        state = self.__dict__.copy()
+        # rabbitizer's enums can't be pickled
+        state["instrCategoryRanges"] = [(ranged, instrCat.name) for ranged, instrCat in self.instrCategoryRanges]
+        return state
+
+    def __setstate__(self, state: dict[str, Any]) -> None:
+        state["instrCategoryRanges"] = [(ranged, getattr(rabbitizer.InstrCategory, instrCat)) for ranged, instrCat in state["instrCategoryRanges"]]
+        self.__dict__.update(state)
+
+
     @staticmethod
     def addParametersToArgParse(parser: argparse.ArgumentParser) -> None:
         contextParser = parser.add_argument_group("Context configuration")
 
         contextParser.add_argument("--save-context", help="Saves the context to a file", metavar="FILENAME")
+        contextParser.add_argument("--save-c-header", help="Writes C declarations for every known symbol to a file", metavar="FILENAME")
+        contextParser.add_argument("--save-unknown-segment-report", help="Writes a csv listing every referenced symbol which is outside of every known segment", metavar="FILENAME")
+        contextParser.add_argument("--save-unreferenced-functions-report", help="Writes a csv listing every function which is never referenced by code nor data, which may be dead code or referenced from an undetected pointer table", metavar="FILENAME")
+        contextParser.add_argument("--entry-point", help="Declares the function at the given address as an entry point, so it is not listed as unreferenced. Can be passed multiple times", action="append", type=Context._parseEntryPoint, metavar="VRAM")
+        contextParser.add_argument("--c-header-type", help="Use a custom C type instead of the given type when generating the C header. Can be passed multiple times", action="append", type=Context._parseCHeaderTypePair, metavar="TYPE=CTYPE")
 
 
         csvConfig = parser.add_argument_group("Context .csv input files")
@@ -215,6 +1239,8 @@ class Context:
         csvConfig.add_argument("--variables", help="Path to a variables csv", action="append")
         csvConfig.add_argument("--constants", help="Path to a constants csv", action="append")
         csvConfig.add_argument("--symbol-addrs", help="Path to a splat-compatible symbol_addrs.txt file", action="append")
+        csvConfig.add_argument("--hardware-regs-csv", help="Path to a csv of `address,name` pairs of memory mapped IO registers, symbolized as the built-in hardware registers with their real names", action="append")
+        csvConfig.add_argument("--function-signatures", help="Path to a csv of function signature hashes and names, used to name known functions", action="append")
 
 
         symbolsConfig = parser.add_argument_group("Context default symbols configuration")
@@ -226,6 +1252,31 @@ class Context:
         symbolsConfig.add_argument("--named-hardware-regs", help="Use actual names for the hardware registers", action=Utils.BooleanOptionalAction)
 
 
+    @staticmethod
+    def parseCHeaderTypeMappingArgs(args: argparse.Namespace) -> dict[str, str]:
+        typeMapping: dict[str, str] = dict()
+        if args.c_header_type is not None:
+            for pair in args.c_header_type:
+                symType, cType = pair
+                typeMapping[symType] = cType
+        return typeMapping
+
+    @staticmethod
+    def _parseCHeaderTypePair(pair: str) -> tuple[str, str]:
+        if "=" not in pair:
+            raise argparse.ArgumentTypeError(f"expected TYPE=CTYPE, got '{pair}'")
+        symType, cType = pair.split("=", 1)
+        if symType == "" or cType == "":
+            raise argparse.ArgumentTypeError(f"expected TYPE=CTYPE, got '{pair}'")
+        return symType, cType
+
+    @staticmethod
+    def _parseEntryPoint(vram: str) -> int:
+        try:
+            return int(vram, 0)
+        except ValueError:
+            raise argparse.ArgumentTypeError(f"expected an address, got '{vram}'")
+
     def parseArgs(self, args: argparse.Namespace) -> None:
         if args.default_banned != False:
             self.fillDefaultBannedSymbols()
@@ -248,3 +1299,12 @@ class Context:
         if args.symbol_addrs is not None:
             for filepath in args.symbol_addrs:
                 self.globalSegment.readSplatSymbolAddrs(Path(filepath))
+        if args.hardware_regs_csv is not None:
+            for filepath in args.hardware_regs_csv:
+                self.globalSegment.readHardwareRegsCsv(Path(filepath))
+        if args.function_signatures is not None:
+            for filepath in args.function_signatures:
+                self.readFunctionSignaturesCsv(Path(filepath))
+        if args.entry_point is not None:
+            for entryPoint in args.entry_point:
+                self.addEntryPoint(entryPoint)
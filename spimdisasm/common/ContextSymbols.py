@@ -7,10 +7,10 @@ from __future__ import annotations
 
 import dataclasses
 import enum
-from typing import Callable
+from typing import Any, Callable, ClassVar
 import rabbitizer
 
-from .GlobalConfig import GlobalConfig, Compiler
+from .GlobalConfig import GlobalConfig, Compiler, SharedRodataMigrationPolicy, BranchLabelStyle
 from .FileSectionType import FileSectionType
 from .SortedDict import SortedDict
 
@@ -109,6 +109,53 @@ gKnownTypes: set[str] = {
 for kind in gAccessKinds.values():
     gKnownTypes |= kind.getAllTypes()
 
+gStructSizes: dict[str, int] = {
+    "OSThread": 0x1B0,
+    "OSMesgQueue": 0x18,
+    "OSViMode": 0x50,
+}
+"Sizes of known struct types, used to declare arrays of them on C headers"
+
+
+@dataclasses.dataclass
+class SymbolReference:
+    """
+    A single place where a symbol gets referenced.
+    """
+
+    referencer: ContextSymbol
+    "The symbol (function or data) containing the reference"
+    vrom: int|None
+    "The rom address of the instruction or word containing the reference"
+    isFromFunction: bool
+    "The reference comes from an instruction instead of a data word"
+
+
+@dataclasses.dataclass(frozen=True)
+class ReferencedAddress:
+    """
+    A read-only snapshot of what has been gathered so far about a symbol,
+    meant to inspect the planned symbolization.
+
+    Changes to the symbol done after taking the snapshot are not reflected on
+    it. Use the `ContextSymbol` it was taken from to adjust it instead.
+    """
+
+    vram: int
+    vromAddress: int|None
+    overlayCategory: str|None
+    type: SymbolSpecialType|str|None
+    "User declared type if any, otherwise the autodetected one"
+    accessType: rabbitizer.Enum|None
+    size: int|None
+    "Only the user declared or autodetected size, since the rest of the fallbacks of `ContextSymbol.getSize` are guesses"
+    alignmentShift: int|None
+    "See `ContextSymbol.getAlignmentShift`"
+    referenceCount: int
+    "See `ContextSymbol.getReferenceCount`"
+    isUserDeclared: bool
+    isAutogenerated: bool
+
 
 @dataclasses.dataclass
 class ContextSymbol:
@@ -117,6 +164,15 @@ class ContextSymbol:
     nameEnd: str|None = None
     userDeclaredSize: int|None = None
     autodetectedSize: int|None = None
+    arrayElementSize: int|None = None
+    "User declared size of each element, if this symbol is an array. See `getArrayElementSize`"
+    arrayElementCount: int|None = None
+    "User declared amount of elements, if this symbol is an array. See `getArrayElementCount`"
+    distanceToNextSymbol: int|None = None
+    """
+    Distance from this symbol to the next one in its section. Set when the
+    containing section splits its symbols. Used as a fallback by `getSize`.
+    """
     userDeclaredType: SymbolSpecialType|str|None = None
     autodetectedType: SymbolSpecialType|str|None = None
 
@@ -149,13 +205,21 @@ class ContextSymbol:
     "Which functions reference this symbol"
     referenceSymbols: set[ContextSymbol] = dataclasses.field(default_factory=set)
     "Which symbols reference this symbol"
+    referenceLocations: list[SymbolReference] = dataclasses.field(default_factory=list)
+    "Every place this symbol is referenced from, both from functions and symbols"
 
     parentFunction: ContextSymbol|None = None
-    "Parent function for branch labels, jump tables, and jump table labels"
+    "Parent function for branch labels, jump tables, jump table labels and except table labels"
     branchLabels: SortedDict[ContextSymbol] = dataclasses.field(default_factory=SortedDict)
     "For functions, the branch and jump table labels which are contained in this function"
     jumpTables: SortedDict[ContextSymbol] = dataclasses.field(default_factory=SortedDict)
     "For functions, the jump tables which are contained in this function"
+    jumpTableBase: ContextSymbol|None = None
+    """For position-independent jumptables, the symbol its entries are relative to.
+
+    The entries of the jumptable are emitted as `label - base`. See `GlobalConfig.DETECT_RELATIVE_JUMPTABLES`"""
+    exceptTableLabels: SortedDict[ContextSymbol] = dataclasses.field(default_factory=SortedDict)
+    "For functions, the landing pad labels referenced by except tables which are contained in this function"
 
     parentFileName: str|None = None
     "Name of the file containing this symbol"
@@ -180,12 +244,17 @@ class ContextSymbol:
     gotIndex: int|None = None
 
     accessedAsGpRel: bool = False
+    isSmallData: bool = False
+    "This symbol is placed on a gp-relative small section (`.sdata`, `.sbss`, etc)"
 
     _isStatic: bool = False
 
     isAutoCreatedPad: bool = False
     autoCreatedPadMainSymbol: ContextSymbol|None = None
 
+    innerLabelOf: ContextSymbol|None = None
+    "This symbol is a label in the middle of the given symbol, so it doesn't split it. See `GlobalConfig.INNER_LABELS_FOR_CODE_REFERENCES`"
+
     firstLoAccess: int|None = None
 
     isElfNotype: bool = False
@@ -224,6 +293,15 @@ class ContextSymbol:
     may get lost in limbo.
     """
 
+    sharedRodataMigrationPolicy: SharedRodataMigrationPolicy|None = None
+    """
+    How to migrate this symbol if it is referenced by more than one function.
+
+    Uses `GlobalConfig.SHARED_RODATA_MIGRATION_POLICY` if `None`.
+
+    This field is ignored if applied on anything that is not a rodata symbol.
+    """
+
     allowedToReferenceAddends: bool = False
     notAllowedToReferenceAddends: bool = False
 
@@ -242,10 +320,91 @@ class ContextSymbol:
 
     isAutocreatedSymFromOtherSizedSym: bool = False
 
+    hasTrustedBoundaries: bool = False
+    """
+    The start and size of this function were given by an external source
+    (see `SymbolsSegment.addKnownFunctions`), so the function analysis splits
+    the text exactly at its boundaries even if its heuristics disagree.
+
+    The `nop`s after its end are emitted as trailing padding of it, unless a
+    known symbol starts in the middle of them.
+    """
+
     isMips1Double: bool = False
 
     visibility: str|None = None
 
+    aliases: list[str] = dataclasses.field(default_factory=list)
+    "Secondary names of this symbol, emitted as extra labels. See `GlobalConfig.ASM_ALIASES_AS_SET`"
+
+    userDeclaredAlignment: int|None = None
+    """
+    Alignment (in bytes) required by this symbol, as declared by the user.
+
+    An alignment directive will be emitted before the symbol if possible.
+
+    Must be a power of two, other values are ignored.
+    """
+
+    forceFunctionContinuation: bool = False
+    """
+    Prevent the function analysis from starting a new function at the address
+    of this symbol, making the previous function to continue past this point
+    even if a function end (like a `jr $ra`) was found before it.
+
+    Useful for handwritten functions with returns in the middle of the
+    function or with multiple entry points. In the latter case the symbol is
+    emitted as a label in the middle of the function.
+
+    Only affects symbols in .text sections.
+    """
+
+    instrCategory: rabbitizer.Enum|None = None
+    """
+    Decode the instructions of this function with the given instruction
+    category instead of the one of its text section.
+
+    Takes precedence over `Context.addInstrCategoryRange`. Function boundaries
+    are still detected using the category of the section, so the size of the
+    function should usually be declared too.
+
+    Only affects symbols in .text sections.
+    """
+
+    identifiedBySignature: bool = False
+    """
+    The name of this function was taken from the function signatures database
+    of the context because its instructions matched one of the signatures.
+
+    See `Context.functionSignatures`.
+    """
+
+    isCommon: bool = False
+    """
+    Emit this symbol as a COMMON symbol (`.comm`) instead of a label followed
+    by a `.space` directive.
+
+    Only affects .bss symbols.
+    """
+    commonAlignment: int|None = None
+    """
+    Alignment (in bytes) used by the `.comm` directive of COMMON symbols.
+
+    The alignment is omitted from the directive if it is `None`.
+    """
+
+    pointeeType: str|None = None
+    """
+    For untyped tables of pointers, the kind of symbol every entry points to,
+    either `POINTEE_FUNCTION` or `POINTEE_STRING`.
+
+    Used to emit pointer declarations on the C header. Set by
+    `mips.propagatePointerTypes`.
+    """
+
+    POINTEE_FUNCTION: ClassVar[str] = "function"
+    POINTEE_STRING: ClassVar[str] = "string"
+
 
     @property
     def vram(self) -> int:
@@ -280,6 +439,19 @@ class ContextSymbol:
             return False
         return (self.autodetectedType is not None and self.autodetectedType != "") or self.accessType is not None
 
+    def isTextDataPool(self) -> bool:
+        """
+        Checks if this symbol should be treated as a chunk of data if it is
+        placed inside a text section, like literal pools placed between
+        handwritten functions.
+
+        The user must declare both a non special type (i.e. `u32`) and a size
+        for the symbol to be considered a data pool.
+        """
+        if not self.isUserDeclared or self.userDeclaredSize is None:
+            return False
+        return isinstance(self.userDeclaredType, str) and self.userDeclaredType != ""
+
 
     def isTrustableFunction(self, rsp: bool=False) -> bool:
         """Checks if the function symbol should be trusted based on the current disassembler settings"""
@@ -289,7 +461,7 @@ class ContextSymbol:
         if self.isGotLocal:
             return False
 
-        if self.isAutocreatedSymFromOtherSizedSym:
+        if self.isAutocreatedSymFromOtherSizedSym or self.hasTrustedBoundaries:
             return True
 
         currentType = self.getTypeSpecial()
@@ -337,6 +509,20 @@ class ContextSymbol:
             return True
         return False
 
+    def isDword(self) -> bool:
+        if not GlobalConfig.USE_DOT_DWORD:
+            return False
+
+        currentType = self.getTypeSpecial()
+
+        if gAccessKinds[rabbitizer.AccessType.DOUBLEWORD].typeMatchesAccess(currentType):
+            return True
+        if currentType is not None:
+            return False
+        if self.accessType in {rabbitizer.AccessType.DOUBLEWORD, rabbitizer.AccessType.QUADWORD}:
+            return True
+        return False
+
 
     def isString(self) -> bool:
         if self.failedStringDecoding:
@@ -443,8 +629,26 @@ class ContextSymbol:
             return False
         return self.name.startswith(".")
 
+    def getSharedRodataMigrationPolicy(self) -> SharedRodataMigrationPolicy:
+        if self.sharedRodataMigrationPolicy is not None:
+            return self.sharedRodataMigrationPolicy
+        return GlobalConfig.SHARED_RODATA_MIGRATION_POLICY
+
+    def isSharedRodataDuplicatedOnMigration(self) -> bool:
+        if len(self.referenceFunctions) <= 1:
+            return False
+        return self.getSharedRodataMigrationPolicy() == SharedRodataMigrationPolicy.DUPLICATE_WEAK
+
+    def getSharedRodataMigrationOwner(self) -> ContextSymbol|None:
+        "The function a rodata symbol referenced by more than one function gets migrated to, unless it is kept in rodata"
+        if len(self.referenceFunctions) <= 1:
+            return None
+        if self.getSharedRodataMigrationPolicy() == SharedRodataMigrationPolicy.KEEP_IN_RODATA:
+            return None
+        return min(self.referenceFunctions, key=lambda x: x.vram)
+
     def isLateRodata(self) -> bool:
-        if not GlobalConfig.COMPILER.value.hasLateRodata:
+        if not GlobalConfig.getCompilerProperties().hasLateRodata:
             # late rodata only exists in IDO world
             return False
         # if self.referenceCounter > 1: return False # ?
@@ -470,12 +674,60 @@ class ContextSymbol:
     def hasUserDeclaredSize(self) -> bool:
         return self.userDeclaredSize is not None
 
+    def getArrayElementSize(self) -> int|None:
+        """
+        Returns the size of each element of this symbol if it was declared as
+        an array, either directly or by declaring its amount of elements, in
+        which case the size of the symbol must be a multiple of it.
+
+        Returns `None` if this symbol is not an array.
+        """
+        if self.arrayElementSize is not None:
+            if self.arrayElementSize <= 0:
+                return None
+            return self.arrayElementSize
+
+        if self.arrayElementCount is not None and self.arrayElementCount > 0:
+            size = self.getSize()
+            if size % self.arrayElementCount == 0:
+                return size // self.arrayElementCount
+        return None
+
+    def getArrayElementCount(self) -> int|None:
+        "Returns the amount of elements of this symbol, or `None` if it is not an array. See `getArrayElementSize`"
+        elementSize = self.getArrayElementSize()
+        if elementSize is None:
+            return None
+        if self.arrayElementCount is not None:
+            return self.arrayElementCount
+        return (self.getSize() + elementSize - 1) // elementSize
+
+    def getAlignmentShift(self) -> int|None:
+        """
+        Returns the alignment required by this symbol, in log2, or `None` if
+        the alignment is unknown.
+        """
+        if self.userDeclaredAlignment is not None and self.userDeclaredAlignment > 0 and self.userDeclaredAlignment & (self.userDeclaredAlignment - 1) == 0:
+            return self.userDeclaredAlignment.bit_length() - 1
+
+        if not GlobalConfig.ASM_EMIT_ALIGNMENT_FROM_ACCESS_TYPE:
+            return None
+
+        if self.accessType in {rabbitizer.AccessType.DOUBLEWORD, rabbitizer.AccessType.DOUBLEFLOAT}:
+            return 3
+        if self.accessType == rabbitizer.AccessType.QUADWORD:
+            return 4
+        return None
+
     def _defaultName_suffix(self) -> str:
         suffix = ""
         if self.overlayCategory is not None:
-            suffix = "_"
-            if self.vromAddress is not None:
-                suffix += f"{self.vromAddress:06X}"
+            if GlobalConfig.AUTOGENERATED_NAMES_INCLUDE_OVERLAY_CATEGORY:
+                suffix += f"_{self.overlayCategory}"
+            if not self._defaultName_useRomAddress():
+                suffix += "_"
+                if self.vromAddress is not None:
+                    suffix += f"{self.vromAddress:06X}"
 
         if GlobalConfig.CUSTOM_SUFFIX:
             suffix += GlobalConfig.CUSTOM_SUFFIX
@@ -499,10 +751,19 @@ class ContextSymbol:
 
         suffix = self._defaultName_suffix()
 
+        address = self.address
+        if self._defaultName_useRomAddress() and self.vromAddress is not None:
+            address = self.vromAddress
+
         # Stringify the address
         if GlobalConfig.LEGACY_SYM_ADDR_ZERO_PADDING:
-            return f"{self.address:06X}{suffix}"
-        return f"{self.address:08X}{suffix}"
+            return f"{address:06X}{suffix}"
+        return f"{address:0{GlobalConfig.AUTOGENERATED_NAMES_ADDRESS_PADDING}X}{suffix}"
+
+    def _defaultName_useRomAddress(self) -> bool:
+        if not GlobalConfig.AUTOGENERATED_NAMES_BASED_ON_ROM_FOR_OVERLAYS:
+            return False
+        return self.overlayCategory is not None and self.vromAddress is not None
 
     def _defaultName_sectionPrefix(self, symType: SymbolSpecialType|str|None) -> str:
         # Functions, labels and jumptables don't get a section prefix because most of the time they are in their respective sections
@@ -521,15 +782,17 @@ class ContextSymbol:
                 return "REL_"
             if self.sectionType == FileSectionType.GccExceptTable:
                 return "EHTBL_"
-        return "D_"
+        return GlobalConfig.AUTOGENERATED_NAMES_DATA_PREFIX
 
     def _defaultName_typePrefix(self, symType: SymbolSpecialType|str|None) -> str:
         if symType == SymbolSpecialType.function:
-            return f"func_"
+            return GlobalConfig.AUTOGENERATED_NAMES_FUNCTION_PREFIX
         if symType in {SymbolSpecialType.branchlabel, SymbolSpecialType.jumptablelabel}:
+            if GlobalConfig.BRANCH_LABEL_STYLE == BranchLabelStyle.GLOBAL:
+                return f"L"
             return f".L"
         if symType == SymbolSpecialType.jumptable:
-            return f"jtbl_"
+            return GlobalConfig.AUTOGENERATED_NAMES_JUMPTABLE_PREFIX
         if symType == SymbolSpecialType.gccexcepttable:
             return f"ehtbl_"
         if symType == SymbolSpecialType.gccexcepttablelabel:
@@ -550,6 +813,8 @@ class ContextSymbol:
         currentType = self.getTypeSpecial()
 
         uniqueIdentifier = self._defaultName_uniqueIdentifier(currentType)
+        if self.unknownSegment and GlobalConfig.AUTOGENERATED_NAMES_UNKNOWN_SEGMENT_PREFIX is not None:
+            return f"{GlobalConfig.AUTOGENERATED_NAMES_UNKNOWN_SEGMENT_PREFIX}{uniqueIdentifier}"
         sectionPrefix = self._defaultName_sectionPrefix(currentType)
         typePrefix = self._defaultName_typePrefix(currentType)
         return f"{sectionPrefix}{typePrefix}{uniqueIdentifier}"
@@ -579,25 +844,52 @@ class ContextSymbol:
             self.nameGetCallback = callback
 
     def getNameEnd(self) -> str|None:
-        return self.nameEnd
+        """
+        Returns the name of the label emitted after the end of this symbol,
+        or `None` if no end label should be emitted.
+
+        If the user did not declare one then it is generated from
+        `GlobalConfig.AUTOGENERATED_NAMES_END_SUFFIX`, except for labels,
+        constants and autogenerated pads.
+        """
+        if self.nameEnd is not None:
+            return self.nameEnd
+        if GlobalConfig.AUTOGENERATED_NAMES_END_SUFFIX is None:
+            return None
+        symType = self.getTypeSpecial()
+        if isinstance(symType, SymbolSpecialType) and (symType.isTargetLabel() or symType in {SymbolSpecialType.constant, SymbolSpecialType.hardwarereg}):
+            return None
+        if self.isAutogeneratedPad():
+            return None
+        return f"{self.getName()}{GlobalConfig.AUTOGENERATED_NAMES_END_SUFFIX}"
 
-    def getSize(self) -> int:
-        # User-declared size first
-        if self.userDeclaredSize is not None:
-            return self.userDeclaredSize
+    def addAlias(self, alias: str) -> bool:
+        "Registers a secondary name for this symbol. Returns `False` if it was already an alias or the main name"
+        if alias == self.name or alias in self.aliases:
+            return False
+        self.aliases.append(alias)
+        return True
 
-        if self.autodetectedSize is not None:
-            return self.autodetectedSize
+    def hasName(self, name: str) -> bool:
+        "Checks if the given name is either the name or an alias of this symbol"
+        return name == self.getName() or name in self.aliases
 
+    def getVisibility(self) -> str|None:
+        "Returns the visibility of this symbol, falling back to `GlobalConfig.DEFAULT_SYMBOL_VISIBILITY`"
+        if self.visibility is not None:
+            return self.visibility
+        return GlobalConfig.DEFAULT_SYMBOL_VISIBILITY
+
+    def getSizeFromType(self) -> int|None:
+        "Returns the size implied by the type of this symbol, or `None` if it can't be inferred"
         currentType = self.getTypeSpecial()
 
         # Infer size based on user-declared type
         if currentType is not None and not isinstance(currentType, SymbolSpecialType):
             for info in gAccessKinds.values():
                 if info.typeMatchesAccess(currentType):
-                    size = info.size
-                    if size is not None:
-                        return size
+                    if info.size is not None:
+                        return info.size
                     break
 
         # Infer size based on instruction access type
@@ -606,6 +898,28 @@ class ContextSymbol:
             if size is not None:
                 return size
 
+        return None
+
+    def getSize(self) -> int:
+        """
+        Returns the size of this symbol, inferred in the following order:
+        user declared size, autodetected size, size implied by its type,
+        distance to the next symbol and finally its address alignment.
+        """
+        # User-declared size first
+        if self.userDeclaredSize is not None:
+            return self.userDeclaredSize
+
+        if self.autodetectedSize is not None:
+            return self.autodetectedSize
+
+        size = self.getSizeFromType()
+        if size is not None:
+            return size
+
+        if self.distanceToNextSymbol is not None:
+            return self.distanceToNextSymbol
+
         # Infer size based on symbol's address alignment
         if self.vram % 4 == 0:
             return 4
@@ -693,7 +1007,7 @@ class ContextSymbol:
             label += GlobalConfig.ASM_JTBL_LABEL
         elif currentType == SymbolSpecialType.gccexcepttablelabel:
             label += GlobalConfig.ASM_EHTBL_LABEL
-        elif self.sectionType == FileSectionType.Text:
+        elif self.sectionType == FileSectionType.Text and not self.isTextDataPool():
             if isInMiddleLabel:
                 label += GlobalConfig.ASM_TEXT_ALT_LABEL
             else:
@@ -702,27 +1016,168 @@ class ContextSymbol:
             label += GlobalConfig.ASM_DATA_LABEL
         return label
 
+    def addReferenceLocation(self, referencer: ContextSymbol, vrom: int|None, isFromFunction: bool) -> None:
+        self.referenceLocations.append(SymbolReference(referencer, vrom, isFromFunction))
+
+    def getReferenceFunctionsSorted(self) -> list[ContextSymbol]:
+        """
+        Same as `referenceFunctions`, but sorted by address.
+
+        Iterating the set directly is not guaranteed to produce the same order
+        between runs, so this should be preferred for anything that ends up on
+        the generated output.
+        """
+        return sorted(self.referenceFunctions, key=ContextSymbol._sortKey)
+
+    def getReferenceSymbolsSorted(self) -> list[ContextSymbol]:
+        "Same as `referenceSymbols`, but sorted by address. See `getReferenceFunctionsSorted`"
+        return sorted(self.referenceSymbols, key=ContextSymbol._sortKey)
+
+    def getCrossReferences(self) -> list[ContextSymbol]:
+        """
+        Returns every function and symbol referencing this symbol, functions
+        first. Each group is sorted by address.
+        """
+        return self.getReferenceFunctionsSorted() + [sym for sym in self.getReferenceSymbolsSorted() if sym not in self.referenceFunctions]
+
+    def _sortKey(self) -> tuple[int, int]:
+        return (self.address, self.vromAddress if self.vromAddress is not None else -1)
+
+    def toReferencedAddress(self) -> ReferencedAddress:
+        size = self.userDeclaredSize
+        if size is None:
+            size = self.autodetectedSize
+        return ReferencedAddress(
+            self.vram,
+            self.vromAddress,
+            self.overlayCategory,
+            self.getTypeSpecial(),
+            self.accessType,
+            size,
+            self.getAlignmentShift(),
+            self.getReferenceCount(),
+            self.isUserDeclared,
+            self.isAutogenerated,
+        )
+
+    def getReferenceCount(self) -> int:
+        """
+        Amount of known references to this symbol, counting both the references
+        from instructions and each distinct data symbol referencing it.
+        """
+        return self.referenceCounter + len(self.referenceSymbols)
+
+    def isUnreferencedAutogenerated(self) -> bool:
+        """
+        Checks if this symbol was created by the analysis (and not by the user)
+        but nothing ended up referencing it. Autogenerated pads are not
+        considered.
+        """
+        if not self.isAutogenerated or self.isUserDeclared:
+            return False
+        if self.isAutoCreatedPad:
+            return False
+        if self.getReferenceCount() > 0 or len(self.referenceLocations) > 0:
+            return False
+        return True
+
     def isAutogeneratedPad(self) -> bool:
         return self.isAutoCreatedPad and self.referenceCounter == 0 and self.isAutogenerated
 
+    def isFoldedPad(self) -> bool:
+        """
+        Returns `True` if this symbol is an unreferenced automatically
+        generated pad which will be emitted as trailing padding of the previous
+        symbol instead of as a symbol on its own.
+
+        See `GlobalConfig.FOLD_AUTOGENERATED_PADS`.
+        """
+        if not GlobalConfig.FOLD_AUTOGENERATED_PADS:
+            return False
+        if not self.isAutogeneratedPad():
+            return False
+        if self.getReferenceCount() > 0 or len(self.referenceLocations) > 0:
+            return False
+        return self.getNameEnd() is None
+
     def getReferenceeSymbols(self) -> str:
         if not GlobalConfig.ASM_COMMENT or not GlobalConfig.ASM_REFERENCEE_SYMBOLS:
             return ""
 
         if len(self.referenceFunctions):
             output = "/* Functions referencing this symbol:"
-            for sym in self.referenceFunctions:
+            for sym in self.getReferenceFunctionsSorted():
                 output += f" {sym.getName()}"
             return f"{output} */{GlobalConfig.LINE_ENDS}"
 
         if len(self.referenceSymbols):
             output = "/* Symbols referencing this symbol:"
-            for sym in self.referenceSymbols:
+            for sym in self.getReferenceSymbolsSorted():
                 output += f" {sym.getName()}"
             return f"{output} */{GlobalConfig.LINE_ENDS}"
         return ""
 
 
+    def getCDeclaration(self, typeMapping: dict[str, str]|None=None) -> str|None:
+        """
+        Returns a C declaration for this symbol, like `extern u32 D_80123456;`
+        or `void func_80012345(void);`.
+
+        `typeMapping` allows to override which C type is used for each of the
+        types known by spimdisasm (`u32`, `asciz`, `@jumptable`, etc).
+
+        Returns `None` if the symbol can't be declared in C, like labels,
+        constants or symbols without a valid C identifier as name.
+        """
+
+        if typeMapping is None:
+            typeMapping = dict()
+
+        symType = self.getTypeSpecial()
+        if isinstance(symType, SymbolSpecialType) and symType not in {SymbolSpecialType.function, SymbolSpecialType.jumptable, SymbolSpecialType.gccexcepttable}:
+            return None
+        if self.isAutogeneratedPad():
+            return None
+
+        name = self.getName()
+        if not name.isidentifier():
+            return None
+
+        if symType == SymbolSpecialType.function:
+            returnType = typeMapping.get(SymbolSpecialType.function.toStr(), "void")
+            return f"{returnType} {name}(void);"
+
+        size = self.getSize()
+        if symType is None and self.pointeeType is not None:
+            arraySuffix = f"[{size // 4}]" if size > 4 and size % 4 == 0 else ""
+            if self.pointeeType == ContextSymbol.POINTEE_FUNCTION:
+                return f"extern void (*{name}{arraySuffix})(void);"
+            return f"extern char* {name}{arraySuffix};"
+
+        if isinstance(symType, SymbolSpecialType):
+            cType = typeMapping.get(symType.toStr(), "void*" if symType == SymbolSpecialType.jumptable else "u32")
+            elementSize: int|None = 4
+        else:
+            typeName = self.getType()
+            if typeName == "":
+                typeName = "u32" if size % 4 == 0 and self.vram % 4 == 0 else "u8"
+            elementSize = None
+            if typeName in {"asciz", "char", "String", "Char"}:
+                elementSize = 1
+            elif typeName in gStructSizes:
+                elementSize = gStructSizes[typeName]
+            else:
+                for info in gAccessKinds.values():
+                    if typeName in {info.typeSigned, info.typeUnsigned}:
+                        elementSize = info.size
+                        break
+            cType = typeMapping.get(typeName, "char" if typeName in {"asciz", "String", "Char"} else typeName)
+
+        if elementSize is not None and size > elementSize and size % elementSize == 0:
+            return f"extern {cType} {name}[{size // elementSize}];"
+        return f"extern {cType} {name};"
+
+
     @staticmethod
     def getCsvHeader() -> str:
         output = "address,name,getName,getNameEnd,"
@@ -774,8 +1229,8 @@ class ContextSymbol:
         output += f"{self.isMaybeString},{self.failedStringDecoding},{self.isMaybePascalString},{self.failedPascalStringDecoding},"
         output += f"{self.referenceCounter},"
         if False:
-            output += f"\"{','.join(x.getName() for x in self.referenceFunctions)}\","
-            output += f"\"{','.join(x.getName() for x in self.referenceSymbols)}\","
+            output += f"\"{','.join(x.getName() for x in self.getReferenceFunctionsSorted())}\","
+            output += f"\"{','.join(x.getName() for x in self.getReferenceSymbolsSorted())}\","
 
         if self.parentFunction is not None:
             output += f"{self.parentFunction.getName()},"
@@ -800,6 +1255,25 @@ class ContextSymbol:
         return output
 
 
+    def __getstate__(self) -> dict[str, Any]:
+        state = self.__dict__.copy()
+        # Neither callbacks nor rabbitizer's enums can be pickled
+        state["nameGetCallback"] = None
+        if self.accessType is not None:
+            state["accessType"] = self.accessType.name
+        if self.instrCategory is not None:
+            state["instrCategory"] = self.instrCategory.name
+        return state
+
+    def __setstate__(self, state: dict[str, Any]) -> None:
+        accessType = state.get("accessType")
+        if accessType is not None:
+            state["accessType"] = getattr(rabbitizer.AccessType, accessType)
+        instrCategory = state.get("instrCategory")
+        if instrCategory is not None:
+            state["instrCategory"] = getattr(rabbitizer.InstrCategory, instrCategory)
+        self.__dict__.update(state)
+
     def __eq__(self, other: object) -> bool:
         if not isinstance(other, ContextSymbol):
             return False
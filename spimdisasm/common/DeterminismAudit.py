@@ -0,0 +1,156 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import csv
+import dataclasses
+import io
+import os
+from pathlib import Path
+import subprocess
+import sys
+import tempfile
+from typing import Callable
+
+from .Context import Context
+
+
+ContextSnapshot = dict[str, list[str]]
+"""
+The symbol tables of every segment of a context, keyed by the segment name,
+as generated by `Context.saveContextToFile`.
+"""
+
+
+@dataclasses.dataclass(frozen=True)
+class DeterminismDivergence:
+    segmentName: str
+    "`global`, `unksegment` or the overlay category and vrom of the segment, like the names used by `Context.saveContextToFile`"
+    firstRun: int
+    "Index of the run used as the reference"
+    divergentRun: int
+    firstLine: str|None
+    "The line from the reference run, or `None` if it only exists on the divergent run"
+    divergentLine: str|None
+    "The line from the divergent run, or `None` if it only exists on the reference run"
+
+    def __str__(self) -> str:
+        if self.firstLine is None:
+            return f"[{self.segmentName}] only on run {self.divergentRun}: {self.divergentLine}"
+        if self.divergentLine is None:
+            return f"[{self.segmentName}] only on run {self.firstRun}: {self.firstLine}"
+        return f"[{self.segmentName}] run {self.firstRun}: {self.firstLine} | run {self.divergentRun}: {self.divergentLine}"
+
+
+def snapshotContext(context: Context) -> ContextSnapshot:
+    "Takes a snapshot of the symbol tables of every segment of `context`"
+    segments = {
+        "global": context.globalSegment,
+        "unksegment": context.unknownSegment,
+    }
+    for overlayCategory, segmentsPerVrom in context.overlaySegments.items():
+        for segmentVrom, overlaySegment in segmentsPerVrom.items():
+            segments[f"{overlayCategory}_{segmentVrom:06X}"] = overlaySegment
+
+    snapshot: ContextSnapshot = dict()
+    for name, segment in segments.items():
+        f = io.StringIO()
+        segment.saveContextToFile(f)
+        snapshot[name] = f.getvalue().splitlines()
+    return snapshot
+
+def _keySnapshotLines(lines: list[str]) -> dict[tuple[str, ...], list[str]]:
+    """
+    Groups the lines of a segment by their category, address and vrom, so
+    lines can be matched between snapshots even if some of them are missing.
+    """
+    vromColumn: int|None = None
+    keyedLines: dict[tuple[str, ...], list[str]] = dict()
+    for row, line in zip(csv.reader(lines), lines):
+        if len(row) == 0:
+            continue
+        if row[0] == "category":
+            if "getVrom" in row:
+                vromColumn = row.index("getVrom")
+            key: tuple[str, ...] = (row[0],)
+        elif row[0] == "symbol" and vromColumn is not None and len(row) > vromColumn:
+            key = (row[0], row[1], row[vromColumn])
+        else:
+            key = (row[0], row[1] if len(row) > 1 else "")
+        keyedLines.setdefault(key, []).append(line)
+    return keyedLines
+
+def compareSnapshots(first: ContextSnapshot, second: ContextSnapshot, firstRun: int=0, secondRun: int=1) -> list[DeterminismDivergence]:
+    """
+    Compares two snapshots, returning every difference found.
+
+    Lines are matched by their address and vrom instead of by their position,
+    so a symbol which only exists on one of the snapshots is reported once
+    instead of shifting every following line.
+    """
+    divergences: list[DeterminismDivergence] = []
+    for segmentName in sorted(first.keys() | second.keys()):
+        firstLines = _keySnapshotLines(first.get(segmentName, []))
+        secondLines = _keySnapshotLines(second.get(segmentName, []))
+        for key in sorted(firstLines.keys() | secondLines.keys()):
+            firstGroup = firstLines.get(key, [])
+            secondGroup = secondLines.get(key, [])
+            for i in range(max(len(firstGroup), len(secondGroup))):
+                firstLine = firstGroup[i] if i < len(firstGroup) else None
+                secondLine = secondGroup[i] if i < len(secondGroup) else None
+                if firstLine != secondLine:
+                    divergences.append(DeterminismDivergence(segmentName, firstRun, secondRun, firstLine, secondLine))
+    return divergences
+
+def _compareRuns(snapshots: list[ContextSnapshot]) -> list[DeterminismDivergence]:
+    divergences: list[DeterminismDivergence] = []
+    for i in range(1, len(snapshots)):
+        divergences += compareSnapshots(snapshots[0], snapshots[i], 0, i)
+    return divergences
+
+
+def auditDeterminism(run: Callable[[], Context], runs: int=2) -> list[DeterminismDivergence]:
+    """
+    Calls `run` `runs` times and compares the symbol tables of the returned
+    contexts. `run` is expected to create a new context and analyze the same
+    input every time.
+
+    Since every run happens on the same process this only catches divergences
+    caused by state leaking between runs. Use `auditDeterminismInSubprocesses`
+    to also vary the hash seed, and with it the iteration order of sets and
+    dictionaries keyed by strings.
+
+    Returns an empty list if every run produced the same symbol tables.
+    """
+    snapshots = [snapshotContext(run()) for _ in range(runs)]
+    return _compareRuns(snapshots)
+
+def auditDeterminismInSubprocesses(arguments: list[str], hashSeeds: list[int]) -> list[DeterminismDivergence]:
+    """
+    Runs the `spimdisasm` cli with the given `arguments` once per each hash
+    seed (`PYTHONHASHSEED`) and compares the saved contexts of every run.
+
+    `arguments` must start with the name of the cli utility to run, for
+    example `singleFileDisasm`. `--save-context` is appended automatically.
+
+    Raises `subprocess.CalledProcessError` if any run fails.
+
+    Returns an empty list if every run produced the same symbol tables.
+    """
+    snapshots: list[ContextSnapshot] = []
+    for seed in hashSeeds:
+        with tempfile.TemporaryDirectory(prefix="spimdisasm_audit_") as tempDir:
+            contextPath = Path(tempDir) / "context.csv"
+            env = dict(os.environ)
+            env["PYTHONHASHSEED"] = str(seed)
+            subprocess.run([sys.executable, "-m", "spimdisasm", *arguments, "--save-context", str(contextPath)], env=env, check=True, stdout=subprocess.DEVNULL)
+
+            snapshot: ContextSnapshot = dict()
+            for path in sorted(Path(tempDir).iterdir()):
+                name = path.stem[len(contextPath.stem)+1:] or "global"
+                snapshot[name] = path.read_text().splitlines()
+            snapshots.append(snapshot)
+    return _compareRuns(snapshots)
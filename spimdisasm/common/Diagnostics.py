@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import enum
+
+
+class DiagnosticKind(enum.Enum):
+    OVERLAPPING_SYMBOLS = enum.auto()
+    "The user declared size of a symbol overlaps other symbols"
+    MISALIGNED_REFERENCE = enum.auto()
+    "A memory access to an address which is not aligned to the size of the access"
+    STRING_DECODE_FAILURE = enum.auto()
+    "A symbol marked or guessed as a string could not be decoded"
+    BRANCH_OUTSIDE_FUNCTION = enum.auto()
+    "A branch instruction targets an address outside of its function"
+    CALL_OUTSIDE_KNOWN_RANGE = enum.auto()
+    "A function call targets an address outside of the known vram ranges"
+    MISALIGNED_SYMBOL = enum.auto()
+    "The address of a symbol is not aligned to the size of its declared type"
+    SYMBOL_CROSSES_BOUNDARY = enum.auto()
+    "The declared size of a symbol goes past the end of its segment or section"
+    UNPAIRED_HI = enum.auto()
+    "A `lui` could not be paired to any `%lo`. Only reported with `UnpairedHiPolicy.ERROR`"
+    UNSYMBOLIZED_ADDRESS = enum.auto()
+    "A value pointing inside the known vram ranges is emitted as a raw number, so the output is not shiftable"
+    JUMPTABLE_LABEL_OUTSIDE_FUNCTION = enum.auto()
+    "A jumptable entry targets an address outside of the function using the jumptable"
+    DELAY_SLOT_HAZARD = enum.auto()
+    "A delay slot contains a branch, an invalid instruction or an instruction which interacts with the registers of its branch. Only reported with `GlobalConfig.DETECT_DELAY_SLOT_HAZARDS`"
+    LATE_RODATA_RATIO = enum.auto()
+    "A function is too small for asm-processor to generate its migrated late rodata, even with `.late_rodata_alignment`"
+
+
+@dataclasses.dataclass
+class Diagnostic:
+    """
+    A suspicious pattern found during analysis.
+
+    Diagnostics don't stop the analysis, they are registered to the `Context`
+    so they can be inspected afterwards (see `Context.getDiagnostics`).
+    """
+
+    kind: DiagnosticKind
+    message: str
+    vram: int
+    vromAddress: int|None = None
+    symbolName: str|None = None
+    "Name of the symbol this diagnostic was reported from, if any"
+
+    def toComment(self) -> str:
+        return f"/* Warning: {self.message} */"
+
+    def __str__(self) -> str:
+        output = f"{self.kind.name} at 0x{self.vram:08X}"
+        if self.vromAddress is not None:
+            output += f" (rom 0x{self.vromAddress:06X})"
+        if self.symbolName is not None:
+            output += f" in {self.symbolName}"
+        return f"{output}: {self.message}"
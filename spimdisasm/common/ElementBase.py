@@ -12,6 +12,7 @@ from .ContextSymbols import ContextSymbol
 from .SymbolsSegment import SymbolsSegment
 from .Context import Context
 from .FileSectionType import FileSectionType
+from .AddressRange import PiecewiseRomVramMapping
 
 
 class ElementBase:
@@ -54,6 +55,22 @@ class ElementBase:
         self.overlayCategory: str|None = overlayCategory
         self.segmentVromStart: int = segmentVromStart
 
+        self.romStart: int|None = None
+        """
+        The offset of this element in the physical ROM, or `None` if it is the
+        same as `vromStart`.
+
+        Those differ on compressed segments, where the disassembled bytes come
+        from a decompressed buffer but the symbols are keyed by their vrom.
+        See `setVromStart`.
+        """
+
+        self.romVramMapping: PiecewiseRomVramMapping|None = None
+        """
+        For elements built from multiple rom chunks, the mapping used to get
+        the vrom of each offset. See `setRomVramMapping`.
+        """
+
         self._ownSegmentReference: SymbolsSegment|None = None
 
 
@@ -78,8 +95,52 @@ class ElementBase:
         return self.name
 
     def getVromOffset(self, localOffset: int) -> int:
+        romVramMapping = self.romVramMapping
+        if romVramMapping is None and self.parent is not None:
+            romVramMapping = self.parent.romVramMapping
+        if romVramMapping is not None:
+            vrom = romVramMapping.vramToVrom(self.getVramOffset(localOffset))
+            if vrom is not None:
+                return vrom
         return self.vromStart + localOffset
 
+    def setRomVramMapping(self, romVramMapping: PiecewiseRomVramMapping) -> None:
+        """
+        Makes this element use the given mapping to calculate the vrom of its
+        contents, for elements built by joining multiple rom chunks (see
+        `PiecewiseRomVramMapping.concatChunks`).
+
+        Symbols are not expected to cross from one chunk to another.
+
+        Must be called before `analyze`.
+        """
+        self.romVramMapping = romVramMapping
+        self.vromStart = romVramMapping.romStart
+        self.vromEnd = romVramMapping.romEnd
+
+    def getRomOffset(self, localOffset: int) -> int:
+        "Returns the physical ROM offset of the given local offset, which differs from its vrom on compressed segments"
+        if self.romStart is not None:
+            return self.romStart + localOffset
+        if self.parent is not None:
+            return self.parent.getRomOffset(self.vram - self.parent.vram + localOffset)
+        return self.getVromOffset(localOffset)
+
+    def setVromStart(self, vromStart: int) -> None:
+        """
+        Moves this element to the given vrom, keeping its current rom range as
+        `romStart`.
+
+        Useful for compressed segments, which are disassembled from their
+        decompressed bytes but have their user symbols keyed by vrom.
+
+        Must be called before `analyze`.
+        """
+        if self.romStart is None:
+            self.romStart = self.vromStart
+        self.vromEnd = vromStart + self.vromEnd - self.vromStart
+        self.vromStart = vromStart
+
     def getVromOffsetNone(self, localOffset: int) -> int|None:
         if self.sectionType == FileSectionType.Bss:
             return None
@@ -102,9 +163,13 @@ class ElementBase:
             label = sym.getLabelMacro(isInMiddleLabel=False)
             if label is None:
                 return ""
-            label += f" {symName or sym.getName()}"
-            if sym.visibility is not None and sym.visibility != "global":
-                label += f", {sym.visibility}"
+            name = symName or sym.getName()
+            visibility = sym.getVisibility()
+            if GlobalConfig.ASM_VISIBILITY_DIRECTIVES and visibility is not None and visibility != "global":
+                return self._getVisibilityDirectiveLabel(label, name, visibility)
+            label += f" {name}"
+            if visibility is not None and visibility != "global":
+                label += f", {visibility}"
             if GlobalConfig.GLABEL_ASM_COUNT:
                 if self.index is not None:
                     label += f" # {self.index}"
@@ -113,6 +178,13 @@ class ElementBase:
         return ""
 
 
+    def _getVisibilityDirectiveLabel(self, labelMacro: str, name: str, visibility: str) -> str:
+        if visibility == "weak":
+            return f".weak {name}{GlobalConfig.LINE_ENDS}{name}:{GlobalConfig.LINE_ENDS}"
+        if visibility == "local":
+            return f"{name}:{GlobalConfig.LINE_ENDS}"
+        return f".{visibility} {name}{GlobalConfig.LINE_ENDS}{labelMacro} {name}{GlobalConfig.LINE_ENDS}"
+
     def analyze(self) -> None:
         """Scans the words of this element, gathering as much info as possible.
 
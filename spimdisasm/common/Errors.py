@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from .FileSectionType import FileSectionType
+
+
+def _formatRange(name: str, start: int|None, end: int|None, width: int) -> str:
+    if start is None or end is None:
+        return f"{name}: unknown"
+    return f"{name}: [0x{start:0{width}X}, 0x{end:0{width}X})"
+
+
+class SectionCreationError(ValueError):
+    """
+    Raised when a section can't be created because of invalid parameters, like
+    an inverted rom range or a rom range which is outside the input bytes.
+    """
+
+    def __init__(self, reason: str, name: str, sectionType: FileSectionType, vromStart: int, vromEnd: int, vramStart: int, vramEnd: int|None=None, segmentVromStart: int|None=None, overlayCategory: str|None=None) -> None:
+        self.reason = reason
+        self.name = name
+        self.sectionType = sectionType
+        self.vromStart = vromStart
+        self.vromEnd = vromEnd
+        self.vramStart = vramStart
+        self.vramEnd = vramEnd
+        self.segmentVromStart = segmentVromStart
+        self.overlayCategory = overlayCategory
+
+        message = f"Unable to create {sectionType.toStr()} section '{name}': {reason}"
+        message += f"\n    {_formatRange('rom', vromStart, vromEnd, 6)}"
+        if vramEnd is not None:
+            message += f"\n    {_formatRange('vram', vramStart, vramEnd, 8)}"
+        else:
+            message += f"\n    vram: 0x{vramStart:08X}"
+        if segmentVromStart is not None:
+            message += f"\n    segment rom: 0x{segmentVromStart:06X}"
+        if overlayCategory is not None:
+            message += f"\n    overlay category: {overlayCategory}"
+        super().__init__(message)
+
+
+class SegmentCreationError(ValueError):
+    """
+    Raised when a `SymbolsSegment` can't be created or modified because of
+    invalid ranges.
+    """
+
+    def __init__(self, reason: str, vromStart: int|None, vromEnd: int|None, vramStart: int, vramEnd: int, overlayCategory: str|None=None) -> None:
+        self.reason = reason
+        self.vromStart = vromStart
+        self.vromEnd = vromEnd
+        self.vramStart = vramStart
+        self.vramEnd = vramEnd
+        self.overlayCategory = overlayCategory
+
+        message = f"Invalid segment: {reason}"
+        message += f"\n    {_formatRange('rom', vromStart, vromEnd, 6)}"
+        message += f"\n    {_formatRange('vram', vramStart, vramEnd, 8)}"
+        if overlayCategory is not None:
+            message += f"\n    overlay category: {overlayCategory}"
+        super().__init__(message)
+
+
+class SegmentOverlapError(SegmentCreationError):
+    """
+    Raised when an overlay segment can't be added because it collides with an
+    already registered segment of the same overlay category.
+    """
+
+    def __init__(self, reason: str, vromStart: int|None, vromEnd: int|None, vramStart: int, vramEnd: int, overlayCategory: str|None, otherVromStart: int|None, otherVromEnd: int|None, otherVramStart: int, otherVramEnd: int) -> None:
+        self.otherVromStart = otherVromStart
+        self.otherVromEnd = otherVromEnd
+        self.otherVramStart = otherVramStart
+        self.otherVramEnd = otherVramEnd
+
+        super().__init__(reason, vromStart, vromEnd, vramStart, vramEnd, overlayCategory)
+        message = self.args[0]
+        message += f"\n    other segment {_formatRange('rom', otherVromStart, otherVromEnd, 6)}"
+        message += f"\n    other segment {_formatRange('vram', otherVramStart, otherVramEnd, 8)}"
+        self.args = (message,)
@@ -20,6 +20,9 @@ class FileSectionType(OrderedEnum):
     Bss             = 4
     Reloc           = 5
     GccExceptTable  = 6
+    Bin             = 7
+    Custom          = 8
+    "User defined section kinds. See `FileSectionType.registerCustomKind`"
 
     @staticmethod
     def fromId(sectionId: int) -> FileSectionType:
@@ -30,8 +33,25 @@ class FileSectionType(OrderedEnum):
 
     @staticmethod
     def fromStr(x: str) -> FileSectionType:
+        if x in gCustomSectionKinds:
+            return FileSectionType.Custom
         return gNameToSectionType.get(x, FileSectionType.Invalid)
 
+    @staticmethod
+    def registerCustomKind(name: str) -> None:
+        """
+        Makes split files recognize `name` (like `.assetidx`) as a section
+        of the `Custom` type.
+
+        The name must start with a dot and names of the built-in section types
+        can't be used.
+        """
+        if not name.startswith("."):
+            raise ValueError(f"Custom section kind '{name}' must start with a dot")
+        if name in gNameToSectionType or name in gSmallNameToSectionType:
+            raise ValueError(f"'{name}' is already used by a built-in section type")
+        gCustomSectionKinds.add(name)
+
     @staticmethod
     def fromSmallStr(x: str) -> FileSectionType:
         return gSmallNameToSectionType.get(x, FileSectionType.Invalid)
@@ -49,6 +69,8 @@ class FileSectionType(OrderedEnum):
             return ".reloc"
         if self == FileSectionType.GccExceptTable:
             return ".gcc_except_table"
+        if self == FileSectionType.Bin:
+            return ".bin"
         return ""
 
     def toCapitalizedStr(self) -> str:
@@ -64,6 +86,10 @@ class FileSectionType(OrderedEnum):
             return "Reloc"
         if self == FileSectionType.GccExceptTable:
             return "GccExceptTable"
+        if self == FileSectionType.Bin:
+            return "Bin"
+        if self == FileSectionType.Custom:
+            return "Custom"
         return ""
 
     def toSectionName(self) -> str:
@@ -79,6 +105,9 @@ class FileSectionType(OrderedEnum):
             return ".ovl"
         if self == FileSectionType.GccExceptTable:
             return ".gcc_except_table"
+        if self == FileSectionType.Bin:
+            # Raw blobs are usually embedded in the data section
+            return ".data"
         return ""
 
 gNameToSectionType = {
@@ -90,9 +119,11 @@ gNameToSectionType = {
     ".ovl":     FileSectionType.Reloc,
     ".reloc":   FileSectionType.Reloc,
     ".gcc_except_table":   FileSectionType.GccExceptTable,
+    ".bin":     FileSectionType.Bin,
     ".end":     FileSectionType.End,
     ".dummy":   FileSectionType.Dummy,
 }
+gCustomSectionKinds: set[str] = set()
 gSmallNameToSectionType = {
     ".sdata":    FileSectionType.Data,
     ".srodata":  FileSectionType.Rodata,
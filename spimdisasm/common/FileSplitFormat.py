@@ -13,7 +13,7 @@ from .FileSectionType import FileSectionType
 
 
 class FileSplitEntry:
-    def __init__(self, offset: int, vram: int, fileName: str, section: FileSectionType, nextOffset: int, isHandwritten: bool, isRsp: bool) -> None:
+    def __init__(self, offset: int, vram: int, fileName: str, section: FileSectionType, nextOffset: int, isHandwritten: bool, isRsp: bool, customKind: str|None=None) -> None:
         self.offset: int = offset
         self.vram: int = vram
         self.fileName: str = fileName
@@ -21,6 +21,8 @@ class FileSplitEntry:
         self.nextOffset: int = nextOffset
         self.isHandwritten: bool = isHandwritten
         self.isRsp: bool = isRsp
+        self.customKind: str|None = customKind
+        "The name of the section kind if `section` is `FileSectionType.Custom`"
 
 
 class FileSplitFormat:
@@ -35,6 +37,7 @@ class FileSplitFormat:
 
     def __iter__(self) -> Generator[FileSplitEntry, None, None]:
         section = FileSectionType.Invalid
+        customKind: str|None = None
 
         for i, row in enumerate(self.splits):
             offsetStr, vramStr, fileName = row
@@ -55,6 +58,7 @@ class FileSplitFormat:
                     break
                 else:
                     section = possibleSection
+                    customKind = fileName if section == FileSectionType.Custom else None
                     continue
 
             vram = int(vramStr, 16)
@@ -71,7 +75,7 @@ class FileSplitFormat:
                     nextOffsetStr = nextOffsetStr[:-1]
                 nextOffset = int(nextOffsetStr, 16)
 
-            yield FileSplitEntry(offset, vram, fileName, section, nextOffset, isHandwritten, isRsp)
+            yield FileSplitEntry(offset, vram, fileName, section, nextOffset, isHandwritten, isRsp, customKind)
 
     def readCsvFile(self, csvPath: Path) -> None:
         self.splits = Utils.readCsv(csvPath)
@@ -89,7 +93,9 @@ class FileSplitFormat:
             vram = f"{element.vram:X}"
             fileName = element.fileName
 
-            if element.section != FileSectionType.Invalid:
+            if element.section == FileSectionType.Custom and element.customKind is not None:
+                self.splits.append(["offset", "vram", element.customKind])
+            elif element.section != FileSectionType.Invalid:
                 section = element.section.toStr()
                 self.splits.append(["offset", "vram", section])
 
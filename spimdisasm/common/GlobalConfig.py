@@ -9,10 +9,17 @@ import argparse
 import dataclasses
 import enum
 import os
+from pathlib import Path
+from typing import Callable, TYPE_CHECKING
 
 from . import Utils
 from .OrderedEnum import OrderedEnum
-from .CompilerConfig import Compiler, compilerOptions
+from .CompilerConfig import Compiler, CompilerProperties, compilerOptions
+from .Charmap import Charmap
+
+if TYPE_CHECKING:
+    import rabbitizer
+    from .Relocation import RelocationInfo
 
 
 class InputEndian(enum.Enum):
@@ -35,6 +42,28 @@ class InputEndian(enum.Enum):
             return "<"
         raise ValueError(f"No struct format string available for : {self}")
 
+class RomByteOrder(enum.Enum):
+    """
+    Byte order of a whole input ROM image, used to normalize it before it is
+    analyzed. See `Utils.normalizeRomBytes`.
+    """
+
+    NATIVE = "native"
+    "Leave the input as is (`.z64` images)"
+    BYTESWAPPED = "byteswapped"
+    "Every pair of bytes is swapped (`.v64` images)"
+    WORDSWAPPED = "wordswapped"
+    "Every 32-bit word is stored in reverse byte order (`.n64` images)"
+    AUTO = "auto"
+    "Detect the byte order by looking at the magic of the N64 ROM header"
+
+    @staticmethod
+    def fromStr(value: str) -> RomByteOrder|None:
+        try:
+            return RomByteOrder(value)
+        except ValueError:
+            return None
+
 
 class Abi(enum.Enum):
     O32    = "O32"
@@ -88,6 +117,171 @@ class InputFileType(enum.Enum):
     ELF = "elf"
 
 
+class SharedRodataMigrationPolicy(enum.Enum):
+    """How to migrate rodata symbols referenced by more than one function"""
+
+    KEEP_IN_RODATA = "keep_in_rodata"
+    "Don't migrate the symbol"
+    MIGRATE_TO_FIRST = "migrate_to_first"
+    "Migrate the symbol to the first function (by address) which references it"
+    DUPLICATE_WEAK = "duplicate_weak"
+    """Migrate the symbol to the first function (by address) which references it,
+    and declare it as a `.weak` reference on every other function which
+    references it, so its bytes are emitted only once"""
+
+    @staticmethod
+    def fromStr(value: str) -> SharedRodataMigrationPolicy|None:
+        try:
+            return SharedRodataMigrationPolicy(value)
+        except ValueError:
+            return None
+
+
+class BranchLabelStyle(enum.Enum):
+    """How to name and emit the labels targeted by branch instructions"""
+
+    LOCAL = "local"
+    "Assembler local labels, like `.L80012345`"
+    GLOBAL = "global"
+    "Regular labels, like `L80012345`, declared with the label macro"
+    NUMERIC = "numeric"
+    "Numeric local labels, like `1:`, referenced as `1f` or `1b`"
+
+    @staticmethod
+    def fromStr(value: str) -> BranchLabelStyle|None:
+        try:
+            return BranchLabelStyle(value)
+        except ValueError:
+            return None
+
+
+class UnpairedHiPolicy(enum.Enum):
+    """How to emit `lui` instructions which could not be paired to any `%lo`"""
+
+    CONSTANT = "constant"
+    "Emit the immediate as a constant expression, like `(0x80012345 >> 16)`"
+    HILO = "hilo"
+    "Emit `%hi(sym)` if a symbol exists at that address, otherwise `%hi(0x80012345)`"
+    RAW = "raw"
+    "Emit the raw immediate"
+    ERROR = "error"
+    "Emit the raw immediate and report an `UNPAIRED_HI` diagnostic"
+
+    @staticmethod
+    def fromStr(value: str) -> UnpairedHiPolicy|None:
+        try:
+            return UnpairedHiPolicy(value)
+        except ValueError:
+            return None
+
+
+class JumptableLabelOutsideFunctionPolicy(enum.Enum):
+    """What to do with jumptable entries which target an address outside of the function using the jumptable"""
+
+    ALLOW = "allow"
+    "Create the label anyways, only reporting a diagnostic"
+    CLAMP = "clamp"
+    "End the jumptable before the offending entry"
+    REJECT = "reject"
+    "Don't create a label for the offending entry, but keep the jumptable going"
+
+    @staticmethod
+    def fromStr(value: str) -> JumptableLabelOutsideFunctionPolicy|None:
+        try:
+            return JumptableLabelOutsideFunctionPolicy(value)
+        except ValueError:
+            return None
+
+
+class AsmAssemblerModesPlacement(enum.Enum):
+    """Where to emit the `.set noat` and `.set noreorder` directives required by the disassembled functions"""
+
+    FILE = "file"
+    "Once on the prelude of each text file"
+    FUNCTION = "function"
+    "Around every function, restoring the default assembler modes after each one"
+    NONE = "none"
+    "Never, the user is expected to set the assembler modes by their own"
+
+    @staticmethod
+    def fromStr(value: str) -> AsmAssemblerModesPlacement|None:
+        try:
+            return AsmAssemblerModesPlacement(value)
+        except ValueError:
+            return None
+
+
+class StringGuesserCharacterClass(enum.Enum):
+    """Which decoded characters the C string guesser accepts as part of a string"""
+
+    ANY = "any"
+    "Any character the encoding is able to decode"
+    PRINTABLE = "printable"
+    "Only printable characters and common whitespace (`\\n`, `\\t`, `\\r`, etc)"
+    ASCII = "ascii"
+    "Only printable ASCII characters and common whitespace"
+
+    def isValidChar(self, char: str) -> bool:
+        if self == StringGuesserCharacterClass.ANY:
+            return True
+        if char in "\n\t\r\f\v":
+            return True
+        if self == StringGuesserCharacterClass.ASCII and ord(char) >= 0x80:
+            return False
+        return char.isprintable()
+
+    @staticmethod
+    def fromStr(value: str) -> StringGuesserCharacterClass|None:
+        try:
+            return StringGuesserCharacterClass(value)
+        except ValueError:
+            return None
+
+
+@dataclasses.dataclass
+class AsmLabelConventions:
+    """
+    The set of macro names used to declare each kind of symbol on the
+    generated assembly.
+
+    An empty string on the `*EndLabel` members means no end label is emitted.
+    """
+
+    textLabel: str = "glabel"
+    "Functions"
+    textAltLabel: str = "glabel"
+    "Alternative entry points and other symbols in the middle of functions"
+    textEndLabel: str = ""
+    "Emitted after the last instruction of each function"
+    jtblLabel: str = "jlabel"
+    "Jumptable labels"
+    dataLabel: str = "dlabel"
+    "Data, rodata and bss symbols"
+    dataEndLabel: str = ""
+    "Emitted after the last word of each data, rodata or bss symbol"
+    ehtblLabel: str = "ehlabel"
+    "Labels referenced by gcc except tables"
+
+    @staticmethod
+    def fromPreset(name: str) -> AsmLabelConventions|None:
+        """
+        Returns the conventions of a known preset:
+        - `default`: The defaults of this class.
+        - `legacy`: `glabel` for every symbol, like older spimdisasm versions.
+        - `splat`: The default, plus the `endlabel` and `enddlabel` macros.
+        """
+        if name == "default":
+            return AsmLabelConventions()
+        if name == "legacy":
+            return AsmLabelConventions(jtblLabel="glabel", dataLabel="glabel", ehtblLabel="glabel")
+        if name == "splat":
+            return AsmLabelConventions(textEndLabel="endlabel", dataEndLabel="enddlabel")
+        return None
+
+
+asmLabelConventionsPresets = ["default", "legacy", "splat"]
+
+
 @dataclasses.dataclass
 class GlobalConfigType:
     DISASSEMBLE_UNKNOWN_INSTRUCTIONS: bool = False
@@ -99,6 +293,12 @@ class GlobalConfigType:
 
     RODATA_STRING_ENCODING: str = "EUC-JP"
     DATA_STRING_ENCODING: str = "ASCII"
+    RODATA_CHARMAP: Charmap|None = None
+    """If not None, rodata strings are detected and decoded with this charmap
+    instead of `RODATA_STRING_ENCODING`, and emitted as `.byte` sequences"""
+    DATA_CHARMAP: Charmap|None = None
+    """If not None, data strings are detected and decoded with this charmap
+    instead of `DATA_STRING_ENCODING`, and emitted as `.byte` sequences"""
 
     RODATA_STRING_GUESSER_LEVEL: int = 1
     """Rodata string guesser"""
@@ -108,8 +308,134 @@ class GlobalConfigType:
 
     PASCAL_RODATA_STRING_GUESSER_LEVEL: int = 0
 
+    RODATA_FLOAT_GUESSER_LEVEL: int = 0
+    """Rodata float guesser. Guesses untyped rodata symbols as `f32` or `f64`
+    if their words decode to reasonable floats.
+
+    - level 0: Disabled.
+    - level 1: Only symbols of a single float or double which are referenced
+      exclusively by functions, like the literal pools of optimized code.
+    - level 2: Symbols referenced by data and arrays of floats are also guessed."""
+
+    RODATA_STRICT_TEXT_POINTERS: bool = False
+    """Only consider a rodata word which points inside a known function as a
+    pointer if it points to the start of the function or to one of its known
+    labels. Unaligned words pointing inside functions are never considered
+    pointers either.
+
+    Cuts down false positives pointing to the middle of functions. Can be
+    overriden per section with `SectionBase.strictTextPointers`."""
+
     PASCAL_DATA_STRING_GUESSER_LEVEL: int = 0
 
+    STRING_GUESSER_MIN_LENGTH: int = 0
+    """Minimum amount of decoded characters (not counting the terminator) a
+    guessed C string must have. Doesn't affect user declared strings"""
+    STRING_GUESSER_MAX_LENGTH: int = 0
+    """Maximum amount of decoded characters (not counting the terminator) a
+    guessed C string may have. 0 means no limit"""
+    STRING_GUESSER_CHARACTER_CLASS: StringGuesserCharacterClass = StringGuesserCharacterClass.ANY
+    """Which decoded characters are allowed on guessed C strings"""
+    STRING_GUESSER_TERMINATOR_ALIGNMENT: int = 1
+    """Guessed C strings must be followed by zeroes from their terminator up
+    to this alignment. 1 means no requirement"""
+
+    MINIMAL_DATA_ANALYSIS: bool = False
+    """
+    Skip the type guessing heuristics of data and rodata sections: the
+    string, Pascal string and float guessers, the string pool analysis and
+    the splitting of big symbols. Symbols are only typed by the user
+    declared information, producing a more predictable output in less time.
+
+    This doesn't disable the symbol detection, so words which look like
+    pointers, jumptables referenced by functions and the pads which follow
+    symbols with user declared sizes still create autogenerated symbols.
+
+    Can be overriden per section with `SectionBase.minimalAnalysis`.
+    """
+
+    STRING_POOL_ANALYSIS: bool = False
+    """
+    Scan rodata and data sections for strings before analyzing any function,
+    registering them on a context-wide string pool.
+
+    References pointing to the middle of a string of the pool are emitted as
+    an addend to the start of the string instead of splitting the string into
+    multiple symbols.
+    """
+
+    JUMPTABLE_LABEL_OUTSIDE_FUNCTION: JumptableLabelOutsideFunctionPolicy = JumptableLabelOutsideFunctionPolicy.ALLOW
+    """
+    What to do with jumptable entries which target an address outside of the
+    function that uses the jumptable, which usually means the jumptable was
+    analyzed wrongly. A `JUMPTABLE_LABEL_OUTSIDE_FUNCTION` diagnostic is
+    reported for each one of them.
+    """
+
+    DETECT_RELATIVE_JUMPTABLES: bool = False
+    """
+    Detect position-independent jumptables, whose entries are offsets
+    relative to the jumptable itself instead of absolute addresses.
+
+    A jumptable is considered relative if its first entry does not point
+    inside its parent function, but does so when added to the address of the
+    jumptable. The base of a jumptable can also be set manually with
+    `ContextSymbol.jumpTableBase`.
+    """
+
+    SHIFTABLE_OUTPUT: bool = False
+    """
+    Verify that the generated assembly stays correct if the code is shifted,
+    by reporting every place where a value pointing inside the known vram
+    ranges could not be symbolized and would be emitted as a raw number.
+
+    This only reports the problems, the generated assembly is not changed and
+    those values are still emitted as raw numbers.
+
+    The problems are registered as `UNSYMBOLIZED_ADDRESS` diagnostics and
+    printed after analysis. Emitting them as comments on the generated
+    assembly can be done with `ASM_COMMENT_DIAGNOSTICS`.
+
+    Only used by the CLI frontends. See `mips.checkShiftability`.
+    """
+
+    DETECT_LIBULTRA_STRUCTS: bool = False
+    """
+    After analyzing every section, try to recognize common libultra structures
+    (`OSThread`, `OSMesgQueue` and `OSViMode` tables) by their size and
+    contents, and use them as the type of the untyped symbols.
+
+    Only used by the CLI frontends. See `mips.detectLibultraStructs`.
+    """
+
+    PROPAGATE_POINTER_TYPES: bool = False
+    """
+    After analyzing every section, type the untyped data symbols whose words
+    all point to functions or to strings as function pointer tables or string
+    tables, so they get proper declarations on the generated C header.
+
+    Only used by the CLI frontends. See `mips.propagatePointerTypes`.
+    """
+
+    INFER_INDIRECT_CALL_TARGETS: bool = False
+    """
+    After analyzing every section, infer the possible targets of the indirect
+    function calls (`jalr`) whose register was set from a known function
+    address, function pointer variable or function pointer table, and count
+    them as references to the called functions.
+
+    Only used by the CLI frontends. See `mips.inferIndirectCallTargets`.
+    """
+
+    RESOLVE_DISPLAY_REFERENCES: bool = False
+    """
+    After analyzing every section, look up once every symbol referenced by the
+    labels, branches and unpaired `%hi`s of each function, instead of searching
+    the context for them while generating the disassembly of every instruction.
+
+    Only used by the CLI frontends. See `SymbolFunction.resolveDisplayReferences`.
+    """
+
     #! @deprecated
     @property
     def STRING_GUESSER(self) -> bool:
@@ -138,6 +464,11 @@ class GlobalConfigType:
 
     ALLOW_MIGRATING_CONST_VARIABLES: bool = False
 
+    SHARED_RODATA_MIGRATION_POLICY: SharedRodataMigrationPolicy = SharedRodataMigrationPolicy.KEEP_IN_RODATA
+    """How to migrate rodata symbols referenced by more than one function, like shared floats or strings.
+
+    Can be overriden per symbol with `ContextSymbol.sharedRodataMigrationPolicy`"""
+
     AUTOGENERATED_NAMES_BASED_ON_SECTION_TYPE: bool = True
     """Name autogenerated symbols after the section those are come from
 
@@ -154,6 +485,14 @@ class GlobalConfigType:
     SEQUENTIAL_LABEL_NAMES: bool = False
     """Name branch and jump table labels after their containing function and a sequential number"""
 
+    BRANCH_LABEL_STYLE: BranchLabelStyle = BranchLabelStyle.LOCAL
+    """
+    How to name and emit branch labels.
+
+    Labels referenced from outside of their function (like jumptable labels or
+    labels targeted by data) never use the `numeric` style.
+    """
+
     #! @deprecated
     LEGACY_SYM_ADDR_ZERO_PADDING: bool = False
     """
@@ -164,8 +503,51 @@ class GlobalConfigType:
 
     CUSTOM_SUFFIX: str = ""
 
+    AUTOGENERATED_NAMES_FUNCTION_PREFIX: str = "func_"
+    """Prefix used for autogenerated function names"""
+    AUTOGENERATED_NAMES_DATA_PREFIX: str = "D_"
+    """Prefix used for autogenerated data symbol names"""
+    AUTOGENERATED_NAMES_JUMPTABLE_PREFIX: str = "jtbl_"
+    """Prefix used for autogenerated jumptable names"""
+    AUTOGENERATED_NAMES_UNKNOWN_SEGMENT_PREFIX: str|None = None
+    """Prefix used for autogenerated names of symbols which are outside of every
+    known segment, like `UNK_`. It replaces every other prefix of the name.
+
+    `None` names those symbols as any other symbol."""
+    UNKNOWN_SEGMENT_MAX_INFERRED_SIZE: int = 0x100
+    """Biggest size that can be inferred for a symbol outside of every known
+    segment from the distance to the next known symbol"""
+
+    AUTOGENERATED_NAMES_ADDRESS_PADDING: int = 8
+    """Amount of digits the address of autogenerated names will be zero-padded to.
+
+    Ignored if `LEGACY_SYM_ADDR_ZERO_PADDING` is turned on"""
+
+    AUTOGENERATED_NAMES_INCLUDE_OVERLAY_CATEGORY: bool = False
+    """Append the overlay category to the autogenerated name of symbols from overlays"""
+
+    AUTOGENERATED_NAMES_BASED_ON_ROM_FOR_OVERLAYS: bool = False
+    """Use the rom address instead of the vram address for autogenerated names of symbols from overlays.
+
+    Useful for overlays that share the same vram, since their default names would collide otherwise"""
+
+    AUTOGENERATED_NAMES_END_SUFFIX: str|None = None
+    """Suffix appended to the name of a symbol to generate the label emitted after its end, like `_end`.
+
+    Applies to functions, data and bss symbols which don't have an user declared `name_end`.
+    `None` only emits the user declared end labels."""
+
     COMPILER: Compiler = Compiler.IDO
 
+    CUSTOM_COMPILER: CompilerProperties|None = None
+    """If not None then these properties are used instead of the ones from `COMPILER`.
+
+    Allows tweaking the compiler specific behaviors for toolchains that don't
+    match any of the built-in compilers. A good starting point is to copy the
+    properties of the closest built-in compiler, like
+    `dataclasses.replace(Compiler.GCC.value, name="MyCompiler", prevAlign_string=3)`.
+    """
+
     SYMBOL_ALIGNMENT_REQUIRES_ALIGNED_SECTION: bool = False
     """If True then emitting an align directive affects the assembler so it
     aligns the section to the biggest symbol alignment.
@@ -183,12 +565,47 @@ class GlobalConfigType:
     """Tries to detect redundant and unreferenced functions ends and merge them together.
     This option is ignored if the compiler is not set to IDO"""
 
+    TEXT_REACHABILITY_ANALYSIS: bool = False
+    """
+    Only disassemble as functions the code reachable from the entry points
+    (see `Context.addEntryPoint`) and the user declared functions, following
+    direct calls, jumps, branches and the pointers to the same section built
+    with `%hi`/`%lo` pairs, including tables of pointers placed on data pools
+    of the section. The unreachable parts of each text section are emitted as
+    data.
+
+    Useful for sections mixing code and assets. Functions only referenced by
+    other sections or by pointers stored on other sections should be declared
+    as entry points.
+    """
+
+    DETECT_DELAY_SLOT_HAZARDS: bool = False
+    """
+    Look for suspicious delay slots, like branches inside delay slots or delay
+    slots modifying the registers used by their branch, which usually mean
+    handwritten code or data decoded as code.
+
+    Each one is reported as a `DELAY_SLOT_HAZARD` diagnostic and annotated with
+    an end-of-line comment.
+    """
+
     ENDIAN: InputEndian = InputEndian.BIG
     """Endian for input binary files"""
     ENDIAN_DATA: InputEndian|None = None
     """If not None then specifies the endian for the .data section"""
     ENDIAN_RODATA: InputEndian|None = None
     """If not None then specifies the endian for the .rodata section"""
+    ROM_BYTE_ORDER: RomByteOrder = RomByteOrder.NATIVE
+    """Byte order of the input ROM image.
+
+    Byteswapped (`.v64`) and wordswapped (`.n64`) images are converted to the
+    native byte order (`.z64`) before being analyzed, so they don't need to be
+    preprocessed by an external tool. `RomByteOrder.AUTO` detects the byte
+    order from the magic of the N64 ROM header, leaving the input unchanged if
+    it can't be recognized.
+
+    Only applies to the frontends reading raw binaries.
+    """
 
     ABI: Abi = Abi.O32
     """Controls tweaks related to the used ABI
@@ -222,12 +639,88 @@ class GlobalConfigType:
     """Treat filtered out addresses as constants pairs"""
     SYMBOL_FINDER_FILTERED_ADDRESSES_AS_HILO: bool = False
     """Allow using %hi/%lo syntax for filtered out addresses"""
+    UNPAIRED_HI_POLICY: UnpairedHiPolicy = UnpairedHiPolicy.CONSTANT
+    """How to emit `lui` instructions which could not be paired to any `%lo`"""
+
+    VALID_POINTER_RANGES: list[tuple[int, int]] = dataclasses.field(default_factory=list)
+    """If not empty, only addresses inside any of these `[start, end)` ranges are
+    considered pointers by the data and instruction analyzers.
+
+    The instruction analyzer filters out the %hi/%lo pairs pointing outside of
+    these ranges the same way as the pairs pointing outside of every segment,
+    so they may be emitted as constants (see
+    `SYMBOL_FINDER_FILTERED_ADDRESSES_AS_CONSTANTS`). Function calls and
+    `$gp` accesses to them don't create symbols either."""
+    POINTER_VALIDITY_CALLBACK: Callable[[int], bool]|None = None
+    """If not None, this callback is called for every word or %hi/%lo pair which
+    may be a pointer before creating a symbol for it. Returning `False`
+    prevents the value from being considered a pointer.
+
+    The only parameter is the address the value would point to."""
+
+    OPERAND_SYMBOLIZATION_CALLBACK: Callable[[rabbitizer.Instruction, int, str|None, RelocationInfo|None], str|None]|None = None
+    """If not None, this callback is called for every instruction of a function
+    before emitting it, allowing to replace how its immediate operand is
+    rendered (i.e. to use custom macros).
+
+    The parameters are the instruction, its vram, the operand spimdisasm would
+    emit (`None` if the raw immediate would be used) and the relocation used to
+    symbolize it, if any. The resolved symbol, if any, is available from the
+    relocation.
+
+    Returning `None` keeps the operand spimdisasm would emit."""
+
+    SEGMENTED_ADDRESS_TABLE: dict[int, int] = dataclasses.field(default_factory=dict)
+    """Maps N64 segment ids (the upper byte of a segmented address, like the
+    `0x04` of `0x04001230`) to the vram where said segment is mapped.
+
+    Words of data symbols containing segmented addresses of a mapped segment
+    are resolved to the symbols of the mapped address."""
+    SEGMENTED_ADDRESS_MACRO: str|None = None
+    """Macro used to emit segmented address references in data, like
+    `SEGMENT_ADDR(0x04, D_80123450)`.
+
+    If `None` then the address is emitted as `sym - base + 0x04000000`."""
+
+    KSEG1_AS_KSEG0_ALIAS: bool = False
+    """Treat KSEG1 addresses (`0xA0000000`-`0xBFFFFFFF`) as uncached aliases of
+    their KSEG0 counterparts (`0x80000000`-`0x9FFFFFFF`) when looking up
+    symbols, instead of creating unrelated symbols for them.
+
+    Addresses with a symbol of their own (like hardware registers) or inside
+    the known vram ranges are not considered aliases.
+
+    The references keep using the KSEG1 address, emitted as
+    `sym + 0x20000000` (see `KSEG1_ALIAS_MACRO`)."""
+    KSEG1_ALIAS_MACRO: str|None = None
+    """Macro used to emit references to KSEG1 aliases in data, like
+    `K0_TO_K1(D_80123450)`.
+
+    If `None` then the reference is emitted as `sym + 0x20000000`."""
 
     ALLOW_UNKSEGMENT: bool = True
     """Allow using symbols from the unknown segment"""
 
+    INNER_LABELS_FOR_CODE_REFERENCES: bool = False
+    """Instead of using an addend, emit a label in the middle of a data symbol
+    when code references an address inside of it. The label does not split the
+    referenced symbol.
+
+    Useful for handwritten assembly which accesses array elements directly."""
+
     ALLOW_ALL_ADDENDS_ON_DATA: bool = True
     """Enable using addends on symbols referenced by data"""
+    DATA_ADDEND_MAX: int|None = None
+    """Biggest addend allowed on symbols referenced by data. Words which would
+    need a bigger addend are emitted as raw words instead.
+
+    `None` means there's no limit."""
+    DATA_ADDEND_FORBIDDEN_TYPES: set[str] = dataclasses.field(default_factory=set)
+    """Symbol types (like `asciz` or `f32`) which can't be referenced with an
+    addend by data. Words pointing into the middle of a symbol of one of those
+    types are emitted as raw words instead.
+
+    Functions and labels are never referenced with addends."""
     ALLOW_ALL_CONSTANTS_ON_DATA: bool = True
     """Enable referencing constants by data"""
 
@@ -235,14 +728,103 @@ class GlobalConfigType:
     ASM_COMMENT: bool = True
     """Toggle the comments in generated assembly code"""
     ASM_COMMENT_OFFSET_WIDTH: int = 6
+    ASM_COMMENT_VROM: bool = False
+    """
+    Emit the vrom of each line next to its file offset in the comment of each
+    line. Useful for sections which have a rom different from their vrom, like
+    compressed segments (see `ElementBase.setVromStart`).
+    """
     GLABEL_ASM_COUNT: bool = True
     """Toggle the glabel count comment on functions"""
+    ASM_VISIBILITY_DIRECTIVES: bool = False
+    """
+    Emit the visibility of symbols as assembler directives instead of passing
+    it as an extra argument to the label macro.
+
+    `weak` symbols are emitted with `.weak` and a plain label, `local` symbols
+    are emitted with only a plain label, and any other visibility (like
+    `hidden`) is emitted as a directive with the same name before the usual
+    label macro.
+    """
+    DEFAULT_SYMBOL_VISIBILITY: str|None = None
+    """Visibility used for symbols which don't have one set. `None` means
+    global"""
+    ASM_ALIASES_AS_SET: bool = False
+    """
+    Emit the aliases of a symbol as `.set alias, sym` directives instead of
+    emitting an extra label for each one of them.
+    """
     ASM_REFERENCEE_SYMBOLS: bool = False
+    ASM_COMMENT_DIAGNOSTICS: bool = False
+    """
+    Emit the diagnostics reported during analysis as comments before the
+    affected symbol. Has no effect if `ASM_COMMENT` is turned off.
+    """
+    ASM_COMMENT_FRAME_INFO: bool = False
+    """
+    Emit a comment before each function summarizing its stack frame size,
+    saved registers and register usage. Has no effect if `ASM_COMMENT` is
+    turned off.
+    """
+    ASM_COMMENT_TAIL_CALLS: bool = False
+    """Add an end-of-line comment to jumps and branches detected as tail calls"""
+    ASM_COMMENT_DUPLICATED_STRINGS: bool = False
+    """
+    Emit a comment before each string symbol listing the other string symbols
+    with the exact same contents. Has no effect if `ASM_COMMENT` is turned off.
+    """
+    ASM_COMMENT_DATA_XREFS: int = 0
+    """
+    Emit a comment before each data, rodata and bss symbol listing up to this
+    amount of functions and symbols referencing it. 0 disables the comment.
+    Has no effect if `ASM_COMMENT` is turned off.
+
+    See `ContextSymbol.getCrossReferences`.
+    """
+    ASM_COMMENT_SOURCE_LINES: bool = True
+    """
+    Emit a comment with the source file and line (like `/* file.c:123 */`)
+    before the instructions generated by each source line.
+
+    Only has effect if source lines were registered on the context, usually by
+    reading debug information. See `Context.addSourceLine`. Has no effect if
+    `ASM_COMMENT` is turned off.
+    """
+    ASM_COMMENT_ARRAY_INDICES: int = 16
+    """
+    Emit a comment with the index of each element of data and rodata symbols
+    declared as arrays which have at least this amount of elements. 0 disables
+    the comment. Has no effect if `ASM_COMMENT` is turned off.
+
+    Elements of arrays are always separated by an empty line, regardless of
+    this setting. See `ContextSymbol.getArrayElementSize`.
+    """
 
     ASM_INDENTATION: int = 4
     """Sets the indentation used for every instruction and data"""
     ASM_INDENTATION_LABELS: int = 2
     """Sets the indentation used for labels within functions"""
+    ASM_USE_TABS: bool = False
+    """Use tabs instead of spaces for indentation and column alignment.
+
+    Indentations are rounded up to whole tabs"""
+    ASM_TAB_WIDTH: int = 8
+    """The width of a tab, used to compute the columns when `ASM_USE_TABS` is enabled"""
+    ASM_INSTRUCTION_COLUMN: int = 0
+    """The column where instructions and data directives start.
+
+    Zero keeps the default of separating them from the line comment with a
+    fixed amount of whitespace"""
+    ASM_DIRECTIVE_LJUST: int = 0
+    """The minimal amount of characters to left-align the name of data
+    directives (like `.word`) to, so their operands line up in the same
+    column. Zero means a single space after the directive.
+
+    The operand column of instructions is configured with rabbitizer's
+    `--opcode-ljust` instead"""
+    ASM_END_OF_LINE_COMMENT_COLUMN: int = 0
+    """The column where end-of-line comments start. Zero keeps the default of
+    a single space after the statement"""
 
     ASM_TEXT_LABEL: str = "glabel"
     ASM_TEXT_ALT_LABEL: str = "glabel"
@@ -252,15 +834,39 @@ class GlobalConfigType:
     ASM_USE_SYMBOL_LABEL: bool = True
     ASM_TEXT_ENT_LABEL: str = ""
     ASM_TEXT_END_LABEL: str = ""
+    ASM_DATA_END_LABEL: str = ""
     ASM_TEXT_FUNC_AS_LABEL: bool = False
     ASM_DATA_SYM_AS_LABEL: bool = False
     ASM_EMIT_SIZE_DIRECTIVE: bool = True
+    ASM_EMIT_BSS_SIZE_DIRECTIVE: bool = False
+    """Also emit a size directive for bss symbols. Has no effect if
+    `ASM_EMIT_SIZE_DIRECTIVE` is turned off"""
+    ASM_EMIT_TYPE_DIRECTIVE: bool = False
+    """Emit `.type sym, @function` for functions and `.type sym, @object` for
+    every other symbol, so the assembled object has properly typed ELF symbols"""
+    ASM_EMIT_ALIGNMENT_FROM_ACCESS_TYPE: bool = False
+    """Emit alignment directives for symbols which are accessed with instructions that require bigger alignment, like `ld` or `ldc1`"""
     ASM_USE_PRELUDE: bool = True
     ASM_PRELUDE_USE_INCLUDES: bool = True
     ASM_PRELUDE_USE_INSTRUCTION_DIRECTIVES: bool = True
     ASM_PRELUDE_USE_SECTION_START: bool = True
     ASM_GENERATED_BY: bool = True
 
+    ASM_ASSEMBLER_MODES_PLACEMENT: AsmAssemblerModesPlacement = AsmAssemblerModesPlacement.FILE
+    """Where to emit the `.set noat` and `.set noreorder` directives. See `AsmAssemblerModesPlacement`"""
+    ASM_REORDER_EQUIVALENT_FUNCTIONS: bool = False
+    """
+    Emit functions in which every delay slot is a `nop` in reorder mode,
+    omitting those `nop`s and letting the assembler fill the delay slots.
+
+    Only functions without labels in delay slots, branch likely instructions
+    and which aren't handwritten are considered. Has no effect unless
+    `ASM_ASSEMBLER_MODES_PLACEMENT` is set to `function`.
+
+    The assembler must not be allowed to reorder instructions into the delay
+    slots (GAS's `-O2`), otherwise the output won't match.
+    """
+
     ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE: bool = False
     """
     Use `ASM_JTBL_LABEL` on text labels that are referenced by non jumptable
@@ -271,19 +877,71 @@ class GlobalConfigType:
     fake references, or jumptables being disassembled as data instead of rodata
     """
 
+    ASM_FOLD_HI_LO_INTO_LA: bool = False
+    """
+    Emit a `la` pseudo instruction in place of a `lui`/`addiu` pair.
+
+    The pair is only folded when it is known to reassemble into the exact same
+    instructions: the `addiu` must immediately follow the `lui`, both must use
+    the same register and the same symbol, neither may be on a delay slot and
+    the `addiu` must not be a branch target. Pairs not satisfying those
+    conditions are always emitted as-is.
+
+    Turned off by default (strict mode), which never folds any pair.
+
+    Folding is also skipped on 64-bit ABIs and when `GP_VALUE` is set, since
+    the assembler may expand `la` differently there. Small data is assumed to
+    be assembled with `-G 0`.
+    """
+
+    ASM_PSEUDO_LI: bool = False
+    """
+    Emit a `li` pseudo instruction in place of `addiu`s and `ori`s which load
+    an immediate into a register.
+
+    Only done for the forms the assembler would produce by itself for that
+    `li`, so it reassembles into the same instruction.
+    """
+
     PRINT_NEW_FILE_BOUNDARIES: bool = False
     """Print to stdout every file boundary found in .text and .rodata"""
 
+    DISASSEMBLY_JOBS: int = 1
+    """
+    Amount of threads used to generate the disassembly of the symbols of each
+    section.
+
+    Generating the disassembly of a symbol does not modify the context, so
+    symbols can be disassembled in parallel. The speedup is only noticeable on
+    Python builds without a global interpreter lock.
+    """
+
     USE_DOT_BYTE: bool = True
     """Disassemble symbols marked as bytes with .byte instead of .word"""
     USE_DOT_SHORT: bool = True
     """Disassemble symbols marked as shorts with .short instead of .word"""
+    USE_DOT_DWORD: bool = False
+    """Disassemble symbols marked as 64 or 128 bits integers (like the ones
+    accessed with `ld`/`sd` or the R5900's `lq`/`sq`) with .dword instead of
+    .word"""
+    DATA_GUESS_DWORDS: bool = False
+    """
+    Disassemble untyped data and rodata symbols as .dword if every pair of
+    words of the symbol looks like a sign extended 64 bits integer, so 64 bits
+    tables aren't split in half.
+
+    Only applies to 8-aligned symbols when `ARCHLEVEL` is MIPS III or newer.
+    """
 
     LINE_ENDS: str = "\n"
 
     PANIC_RANGE_CHECK: bool = False
     """Produce a fatal error if a range check fails instead of just printing a warning"""
 
+    PANIC_LATE_RODATA_RATIO: bool = False
+    """Produce a fatal error if the late rodata migrated to a function can't
+    fit its text for asm-processor instead of just printing a warning"""
+
     CREATE_DATA_PADS: bool = True
     """Create dummy and unreferenced data symbols after another symbol which has non-zero user-declared size.
 
@@ -292,6 +950,44 @@ class GlobalConfigType:
     """Create dummy and unreferenced rodata symbols after another symbol which has non-zero user-declared size.
 
     The generated pad symbols may have non-zero data"""
+    CREATE_BSS_ALIGNMENT_PADS: bool = False
+    """Split the alignment gap after a bss symbol into its own dummy pad symbol.
+
+    Only applies to bss symbols without a user-declared size whose size can be
+    inferred from their type, and only if the remaining gap up to the next
+    symbol is smaller than the alignment of said next symbol"""
+    DATA_MAX_SYMBOL_SIZE: int = 0
+    """Split untyped and unreferenced data symbols bigger than this size into
+    multiple symbols, starting at addresses aligned to this size.
+
+    0 means no limit. Can be overriden per section with `SectionBase.maxSymbolSize`"""
+    RODATA_MAX_SYMBOL_SIZE: int = 0
+    """Same as `DATA_MAX_SYMBOL_SIZE`, but for rodata symbols"""
+    FOLD_AUTOGENERATED_PADS: bool = False
+    """Emit unreferenced automatically generated pads as trailing padding of
+    the previous symbol instead of as symbols on their own.
+
+    All-zero pads are emitted as a `.space` directive, otherwise their words
+    are emitted without a label. The folded pads are not written by
+    `Context.saveContextToFile` nor `Context.saveCHeaderToFile` either, but
+    they can still be queried with `SectionBase.getFoldedPads`"""
+
+    TRUNCATE_SYMBOLS_AT_SPLIT_BOUNDARY: bool = False
+    """Truncate the user-declared size of symbols which go past the end of
+    their file split, instead of only reporting them"""
+
+    PRUNE_UNREFERENCED_SYMBOLS: bool = False
+    """After the analysis, remove the autogenerated data and rodata symbols
+    which ended up not being referenced by anything, merging them into the
+    previous symbol"""
+
+    ALLOW_CONTEXT_CACHE: bool = False
+    """Allow saving and loading the analyzed context with
+    `Context.saveCacheToFile` and `Context.loadCacheFromFile`.
+
+    The cache is stored with `pickle`, so loading a cache file can execute
+    arbitrary code. Only enable this if every cache file that will be loaded
+    was generated locally and can be trusted"""
 
     QUIET: bool = False
     VERBOSE: bool = False
@@ -311,13 +1007,78 @@ class GlobalConfigType:
     """write to files splitted binaries"""
 
 
+    def isPointerAllowed(self, address: int) -> bool:
+        "Checks the address against `VALID_POINTER_RANGES` and `POINTER_VALIDITY_CALLBACK`"
+        if len(self.VALID_POINTER_RANGES) > 0:
+            if not any(start <= address < end for start, end in self.VALID_POINTER_RANGES):
+                return False
+        if self.POINTER_VALIDITY_CALLBACK is not None:
+            if not self.POINTER_VALIDITY_CALLBACK(address):
+                return False
+        return True
+
+    def getSegmentedAddressVram(self, address: int) -> int|None:
+        "Translates a segmented address to its mapped vram, or returns `None` if its segment is not mapped"
+        base = self.SEGMENTED_ADDRESS_TABLE.get(address >> 24)
+        if base is None:
+            return None
+        return base + (address & 0x00FFFFFF)
+
+    def getKseg0AliasVram(self, address: int) -> int|None:
+        "Returns the KSEG0 counterpart of a KSEG1 address, or `None` if the address is not in KSEG1 or the aliasing is disabled"
+        if not self.KSEG1_AS_KSEG0_ALIAS:
+            return None
+        if 0xA0000000 <= address < 0xC0000000:
+            return address - 0x20000000
+        return None
+
+    def getKseg1AliasReference(self, symName: str) -> str:
+        if self.KSEG1_ALIAS_MACRO is not None:
+            return f"{self.KSEG1_ALIAS_MACRO}({symName})"
+        return f"{symName} + 0x20000000"
+
+    def getAsmLabelConventions(self) -> AsmLabelConventions:
+        "Returns the currently used label macros as a single object"
+        return AsmLabelConventions(
+            textLabel=self.ASM_TEXT_LABEL,
+            textAltLabel=self.ASM_TEXT_ALT_LABEL,
+            textEndLabel=self.ASM_TEXT_END_LABEL,
+            jtblLabel=self.ASM_JTBL_LABEL,
+            dataLabel=self.ASM_DATA_LABEL,
+            dataEndLabel=self.ASM_DATA_END_LABEL,
+            ehtblLabel=self.ASM_EHTBL_LABEL,
+        )
+
+    def setAsmLabelConventions(self, conventions: AsmLabelConventions) -> None:
+        "Sets every label macro at once"
+        self.ASM_TEXT_LABEL = conventions.textLabel
+        self.ASM_TEXT_ALT_LABEL = conventions.textAltLabel
+        self.ASM_TEXT_END_LABEL = conventions.textEndLabel
+        self.ASM_JTBL_LABEL = conventions.jtblLabel
+        self.ASM_DATA_LABEL = conventions.dataLabel
+        self.ASM_DATA_END_LABEL = conventions.dataEndLabel
+        self.ASM_EHTBL_LABEL = conventions.ehtblLabel
+
+    def getCompilerProperties(self) -> CompilerProperties:
+        if self.CUSTOM_COMPILER is not None:
+            return self.CUSTOM_COMPILER
+        return self.COMPILER.value
+
+
     def addParametersToArgParse(self, parser: argparse.ArgumentParser) -> None:
+        from .SettingsBundle import settingsPresets
+
         backendConfig = parser.add_argument_group("Disassembler backend configuration")
 
+        backendConfig.add_argument("--settings-preset", help="Apply the settings of a preset for a common kind of project. Settings given explicitly take precedence over the preset", choices=list(settingsPresets))
+        backendConfig.add_argument("--settings-file", help="Path to a json or toml project file with the settings to use. Settings given explicitly take precedence over the file, and the file takes precedence over `--settings-preset`", type=Path)
+
         backendConfig.add_argument("--disasm-unknown", help=f"Force disassembling functions with unknown instructions. Defaults to {self.DISASSEMBLE_UNKNOWN_INSTRUCTIONS}", action=Utils.BooleanOptionalAction)
 
         backendConfig.add_argument("--rodata-string-encoding", help=f"Specify the encoding used for decoding all rodata strings. Defaults to {self.RODATA_STRING_ENCODING}")
         backendConfig.add_argument("--data-string-encoding", help=f"Specify the encoding used for decoding all rodata strings. Defaults to {self.DATA_STRING_ENCODING}")
+        backendConfig.add_argument("--rodata-charmap", help="Path to a `.tbl` charmap used to detect and decode rodata strings with a custom encoding", type=Path)
+        backendConfig.add_argument("--data-charmap", help="Path to a `.tbl` charmap used to detect and decode data strings with a custom encoding", type=Path)
 
         rodataStringGuesserHelp = f"""\
 Sets the level for the rodata C string guesser. Smaller values mean more conservative methods to guess a string, while higher values are more agressive. Level 0 (and negative) completely disables the guessing feature. Defaults to {self.RODATA_STRING_GUESSER_LEVEL}.
@@ -337,33 +1098,70 @@ A C string must start at a 0x4-aligned region, which is '\\0' terminated and pad
         backendConfig.add_argument("--rodata-string-guesser", help=rodataStringGuesserHelp, type=int, metavar="level")
         backendConfig.add_argument("--data-string-guesser", help=f"Sets the level for the data C string guesser. See the explanation of `--rodata-string-guesser`. Defaults to {self.DATA_STRING_GUESSER_LEVEL}.", type=int, metavar="level")
 
+        backendConfig.add_argument("--rodata-float-guesser", help=f"Sets the level for the rodata float guesser, which types untyped symbols whose words look like floats or doubles. 0 disables it, 1 only guesses single floats or doubles referenced exclusively by functions, 2 also guesses arrays and symbols referenced by data. Defaults to {self.RODATA_FLOAT_GUESSER_LEVEL}.", type=int, metavar="level")
+        backendConfig.add_argument("--rodata-strict-text-pointers", help=f"Only consider rodata words pointing inside known functions as pointers if they point to the start of the function or to one of its known labels. Defaults to {self.RODATA_STRICT_TEXT_POINTERS}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--pascal-rodata-string-guesser", help=f"EXPERIMENTAL, this feature may change or be removed in the future. Sets the level for the data Pascal string guesser. See the explanation of `--rodata-string-guesser`. Defaults to {self.PASCAL_RODATA_STRING_GUESSER_LEVEL}.", type=int, metavar="level")
         backendConfig.add_argument("--pascal-data-string-guesser", help=f"EXPERIMENTAL, this feature may change or be removed in the future. Sets the level for the data Pascal string guesser. See the explanation of `--rodata-string-guesser`. Defaults to {self.PASCAL_DATA_STRING_GUESSER_LEVEL}.", type=int, metavar="level")
 
+        backendConfig.add_argument("--jumptable-label-outside-function", help=f"What to do with jumptable entries targeting an address outside of the function using the jumptable. Defaults to {self.JUMPTABLE_LABEL_OUTSIDE_FUNCTION.value}", choices=[x.value for x in JumptableLabelOutsideFunctionPolicy])
+        backendConfig.add_argument("--detect-relative-jumptables", help=f"Detect position-independent jumptables, whose entries are relative to the jumptable itself, and emit their entries as `label - jumptable`. Defaults to {self.DETECT_RELATIVE_JUMPTABLES}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--shiftable-output", help=f"Report every place where an address inside the known vram ranges could not be symbolized, which would break the output if the code is shifted. The output itself is not changed. Defaults to {self.SHIFTABLE_OUTPUT}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--detect-libultra-structs", help=f"Try to recognize common libultra structures (OSThread, OSMesgQueue and OSViMode tables) and type symbols accordingly. Defaults to {self.DETECT_LIBULTRA_STRUCTS}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--propagate-pointer-types", help=f"Type untyped data symbols whose words all point to functions or strings as function pointer or string tables. Defaults to {self.PROPAGATE_POINTER_TYPES}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--infer-indirect-call-targets", help=f"Infer the targets of indirect function calls made through known function pointers and count them as references to the called functions. Defaults to {self.INFER_INDIRECT_CALL_TARGETS}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--resolve-display-references", help=f"Look up the symbols referenced by each function once after the analysis instead of once per disassembled instruction. Defaults to {self.RESOLVE_DISPLAY_REFERENCES}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--minimal-data-analysis", help=f"Skip the string, Pascal string and float guessers, the string pool analysis and the splitting of big symbols on data and rodata sections. Defaults to {self.MINIMAL_DATA_ANALYSIS}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--string-pool-analysis", help=f"Scan rodata and data sections for strings before analyzing functions, so references to the middle of a string don't split it. Defaults to {self.STRING_POOL_ANALYSIS}", action=Utils.BooleanOptionalAction)
+
+        backendConfig.add_argument("--string-guesser-min-length", help=f"Minimum amount of decoded characters a guessed C string must have. Defaults to {self.STRING_GUESSER_MIN_LENGTH}", type=int, metavar="length")
+        backendConfig.add_argument("--string-guesser-max-length", help=f"Maximum amount of decoded characters a guessed C string may have. 0 means no limit. Defaults to {self.STRING_GUESSER_MAX_LENGTH}", type=int, metavar="length")
+        backendConfig.add_argument("--string-guesser-character-class", help=f"Which decoded characters are allowed on guessed C strings. Defaults to {self.STRING_GUESSER_CHARACTER_CLASS.value}", choices=[x.value for x in StringGuesserCharacterClass])
+        backendConfig.add_argument("--string-guesser-terminator-alignment", help=f"Require guessed C strings to be padded with zeroes up to this alignment after their terminator. Defaults to {self.STRING_GUESSER_TERMINATOR_ALIGNMENT}", type=int, metavar="alignment")
+
         backendConfig.add_argument("--string-guesser", help=f"DEPRECATED, prefer `--rodata-string-guesser`. Toggles the string guesser feature. Defaults to {self.STRING_GUESSER}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--aggressive-string-guesser", help=f"DEPRECATED, prefer `--rodata-string-guesser`. Makes the string guesser feature to be more aggressive when trying to detect strings. Requires `--string-guesser` to be enabled. Defaults to {self.AGGRESSIVE_STRING_GUESSER}", action=Utils.BooleanOptionalAction)
 
         backendConfig.add_argument("--allow-migrating-const-variables", help=f"Allow migrating const variables. They must be referenced by a single function at a time. Defaults to {self.ALLOW_MIGRATING_CONST_VARIABLES}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--shared-rodata-migration-policy", help=f"Controls how to migrate rodata symbols referenced by more than one function. `keep_in_rodata` doesn't migrate them, `migrate_to_first` migrates them to the first function which references them and `duplicate_weak` migrates them to the first function too but declares them as `.weak` on every other function which references them. Defaults to {self.SHARED_RODATA_MIGRATION_POLICY.value}", choices=[x.value for x in SharedRodataMigrationPolicy])
 
         backendConfig.add_argument("--name-vars-by-section", help=f"Toggles the naming-after-section feature for autogenerated names. This means autogenerated symbols get a RO_ or B_ prefix if the symbol is from a rodata or bss section. Defaults to {self.AUTOGENERATED_NAMES_BASED_ON_SECTION_TYPE}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--name-vars-by-type", help=f"Toggles the naming-after-type feature for autogenerated names. This means autogenerated symbols can get a STR_, FLT_ or DBL_ prefix if the symbol is a string, float or double. Defaults to {self.AUTOGENERATED_NAMES_BASED_ON_DATA_TYPE}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--name-vars-by-file", help=f"Toggles the naming-after-file feature for autogenerated names. This means autogenerated symbols are named via a file name and an offset if the symbol is from a file. Defaults to {self.AUTOGENERATED_NAMES_BASED_ON_FILE_NAME}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--branch-label-style", help=f"How to name and emit branch labels. Defaults to {self.BRANCH_LABEL_STYLE.value}", choices=[x.value for x in BranchLabelStyle])
         backendConfig.add_argument("--sequential-label-names", help=f"Toggles naming branch and jump table labels after their containing function and a sequential number. Defaults to {self.SEQUENTIAL_LABEL_NAMES}", action=Utils.BooleanOptionalAction)
 
         backendConfig.add_argument("--legacy-sym-addr-zero-padding", help=f"Restore the legacy behavior of padding up to 6 digits with zeroes the autogenerated symbol names. The current behavior is to pad up to 8 digits with zeroes. This option is deprecated and may be removed in the future. Defaults to {self.LEGACY_SYM_ADDR_ZERO_PADDING}", action=Utils.BooleanOptionalAction)
 
         backendConfig.add_argument("--custom-suffix", help="Set a custom suffix for automatically generated symbols")
 
+        backendConfig.add_argument("--autogenerated-function-prefix", help=f"Set the prefix used for automatically generated function names. Defaults to {self.AUTOGENERATED_NAMES_FUNCTION_PREFIX}")
+        backendConfig.add_argument("--autogenerated-data-prefix", help=f"Set the prefix used for automatically generated data symbol names. Defaults to {self.AUTOGENERATED_NAMES_DATA_PREFIX}")
+        backendConfig.add_argument("--autogenerated-jumptable-prefix", help=f"Set the prefix used for automatically generated jumptable names. Defaults to {self.AUTOGENERATED_NAMES_JUMPTABLE_PREFIX}")
+        backendConfig.add_argument("--autogenerated-unknown-segment-prefix", help="Set the prefix used for automatically generated names of symbols outside of every known segment, like `UNK_`. By default those symbols are named as any other symbol")
+        backendConfig.add_argument("--unknown-segment-max-inferred-size", help=f"Biggest size that can be inferred for a symbol outside of every known segment from the distance to the next symbol. Defaults to 0x{self.UNKNOWN_SEGMENT_MAX_INFERRED_SIZE:X}. A hex value is expected")
+        backendConfig.add_argument("--autogenerated-address-padding", help=f"Set the amount of digits the address of automatically generated names will be zero-padded to. Defaults to {self.AUTOGENERATED_NAMES_ADDRESS_PADDING}", type=int)
+        backendConfig.add_argument("--name-vars-by-overlay-category", help=f"Append the overlay category to the automatically generated names of symbols from overlays. Defaults to {self.AUTOGENERATED_NAMES_INCLUDE_OVERLAY_CATEGORY}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--autogenerated-name-end-suffix", help="Emit a label after the end of every function, data and bss symbol without an user declared `name_end`, named as the symbol plus this suffix, like `_end`. By default only the user declared end labels are emitted")
+        backendConfig.add_argument("--name-overlay-vars-by-rom", help=f"Use the rom address instead of the vram address for automatically generated names of symbols from overlays. Useful for overlays sharing the same vram. Defaults to {self.AUTOGENERATED_NAMES_BASED_ON_ROM_FOR_OVERLAYS}", action=Utils.BooleanOptionalAction)
+
         backendConfig.add_argument("--compiler", help=f"Enables some tweaks for the selected compiler. Defaults to {self.COMPILER.name}", choices=list(compilerOptions.keys()))
         backendConfig.add_argument("--symbol-alignment-requires-aligned-section", help=f"Only emit symbol alignment directives if those are not larger than the alignment of the disassembled section. Defaults to {self.SYMBOL_ALIGNMENT_REQUIRES_ALIGNED_SECTION}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--detect-redundant-function-end", help=f"Tries to detect redundant and unreferenced function ends (jr $ra; nop), and merge it into the previous function. Currently it only is applied when the compiler is set to IDO. Defaults to {self.DETECT_REDUNDANT_FUNCTION_END}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--detect-delay-slot-hazards", help=f"Report and comment suspicious delay slots, like branches in delay slots or delay slots modifying the registers used by their branch. Defaults to {self.DETECT_DELAY_SLOT_HAZARDS}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--text-reachability-analysis", help=f"Only disassemble as functions the code reachable from the entry points (see `--entry-point`), user declared functions, following calls, branches and pointers within the same section. Unreachable code is emitted as data. Defaults to {self.TEXT_REACHABILITY_ANALYSIS}", action=Utils.BooleanOptionalAction)
 
-        backendConfig.add_argument("--endian", help=f"Set the endianness of input files. Defaults to {self.ENDIAN.name.lower()}", choices=["big", "little", "middle"], default=self.ENDIAN.name.lower())
+        backendConfig.add_argument("--endian", help=f"Set the endianness of input files. Defaults to {self.ENDIAN.name.lower()}", choices=["big", "little", "middle"])
+        backendConfig.add_argument("--rom-byte-order", help=f"Set the byte order of input ROM images. Byteswapped (.v64) and wordswapped (.n64) images are converted before being analyzed. 'auto' detects it from the ROM header magic. Defaults to {self.ROM_BYTE_ORDER.value}", choices=[x.value for x in RomByteOrder])
 
-        backendConfig.add_argument("--abi", help=f"Changes the ABI of the disassembly, applying corresponding tweaks. Defaults to {self.ABI.name}", choices=["O32", "N32", "O64", "N64", "EABI32", "EABI64"], default=self.ABI.name)
-        backendConfig.add_argument("--arch-level", help=f"Changes the arch level of the disassembly, applying corresponding tweaks. Defaults to {self.ARCHLEVEL.name}", choices=archLevelOptions, default=self.ARCHLEVEL.name)
+        backendConfig.add_argument("--abi", help=f"Changes the ABI of the disassembly, applying corresponding tweaks. Defaults to {self.ABI.name}", choices=["O32", "N32", "O64", "N64", "EABI32", "EABI64"])
+        backendConfig.add_argument("--arch-level", help=f"Changes the arch level of the disassembly, applying corresponding tweaks. Defaults to {self.ARCHLEVEL.name}", choices=archLevelOptions)
 
 
+        backendConfig.add_argument("--valid-pointer-range", help="Only consider values inside the given range as pointers. Can be passed multiple times. Hex values are expected", nargs=2, action="append", metavar=("START", "END"))
+        backendConfig.add_argument("--segmented-address", help="Map a N64 segment id to the vram where it is loaded, allowing to symbolize segmented addresses. Can be passed multiple times. Hex values are expected", nargs=2, action="append", metavar=("SEGMENT", "VRAM"))
+        backendConfig.add_argument("--segmented-address-macro", help="Macro used to emit references to segmented addresses in data")
+        backendConfig.add_argument("--kseg1-as-kseg0-alias", help=f"Treat KSEG1 addresses as uncached aliases of KSEG0 addresses when looking up symbols. Defaults to {self.KSEG1_AS_KSEG0_ALIAS}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--kseg1-alias-macro", help="Macro used to emit references to KSEG1 aliases in data")
         backendConfig.add_argument("--gp", help="Set the value used for loads and stores related to the $gp register. A hex value is expected")
         backendConfig.add_argument("--pic", help=f"Enables PIC analysis and the usage of some rel types, like %%got. Defaults to {self.PIC}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--emit-cpload", help=f"Emits a .cpload directive instead of the corresponding instructions if it were detected on PIC binaries. Defaults to {self.EMIT_CPLOAD}", action=Utils.BooleanOptionalAction)
@@ -373,24 +1171,45 @@ A C string must start at a 0x4-aligned region, which is '\\0' terminated and pad
         backendConfig.add_argument("--filter-low-addresses", help=f"Filter out low addresses (lower than 0x40000000) when searching for pointers. Defaults to {self.SYMBOL_FINDER_FILTER_LOW_ADDRESSES}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--filter-high-addresses", help=f"Filter out high addresses (higher than 0xC0000000) when searching for pointers. Defaults to {self.SYMBOL_FINDER_FILTER_HIGH_ADDRESSES}", action=Utils.BooleanOptionalAction)
         backendConfig.add_argument("--filtered-addresses-as-constants", help=f"Treat filtered out addressed as constants. Defaults to {self.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_CONSTANTS}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--unpaired-hi-policy", help=f"How to emit `lui` instructions which could not be paired to any %%lo. Defaults to {self.UNPAIRED_HI_POLICY.value}", choices=[x.value for x in UnpairedHiPolicy])
         backendConfig.add_argument("--filtered-addresses-as-hilo", help=f"Use %%hi/%%lo syntax for filtered out addresses. Defaults to {self.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_HILO}", action=Utils.BooleanOptionalAction)
 
         backendConfig.add_argument("--allow-unksegment", help=f"Allow using symbols from the unknown segment. Defaults to {self.ALLOW_UNKSEGMENT}", action=Utils.BooleanOptionalAction)
 
         backendConfig.add_argument("--allow-all-addends-on-data", help=f"Enable using addends on symbols referenced by data. Defaults to {self.ALLOW_ALL_ADDENDS_ON_DATA}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--inner-labels-for-code-references", help=f"Emit a label in the middle of a data symbol instead of using an addend when code references an address inside of it. Defaults to {self.INNER_LABELS_FOR_CODE_REFERENCES}", action=Utils.BooleanOptionalAction)
+        backendConfig.add_argument("--data-addend-max", help="Biggest addend allowed on symbols referenced by data. Bigger addends are emitted as raw words. A hex value is expected")
+        backendConfig.add_argument("--data-addend-forbidden-type", help="Don't reference symbols of the given type with an addend in data. Can be passed multiple times", action="append", metavar="TYPE")
         backendConfig.add_argument("--allow-all-constants-on-data", help=f"Enable referencing constants by data. Defaults to {self.ALLOW_ALL_CONSTANTS_ON_DATA}", action=Utils.BooleanOptionalAction)
 
 
         miscConfig = parser.add_argument_group("Disassembler misc options")
 
         miscConfig.add_argument("--asm-comments", help=f"Toggle the comments in generated assembly code. Defaults to {self.ASM_COMMENT}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-comment-vrom", help=f"Emit the vrom next to the file offset in the comment of each line. Defaults to {self.ASM_COMMENT_VROM}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--comment-offset-width", help=f"Sets the zeroes width padding for the file offset comment. Defaults to {self.ASM_COMMENT_OFFSET_WIDTH}")
+        miscConfig.add_argument("--asm-visibility-directives", help=f"Emit symbol visibility as assembler directives (`.weak`, `.hidden`, etc) instead of passing it to the label macro. Defaults to {self.ASM_VISIBILITY_DIRECTIVES}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--default-symbol-visibility", help="Visibility used for symbols which don't have one set, like `weak` or `local`. Defaults to global")
+        miscConfig.add_argument("--asm-aliases-as-set", help=f"Emit symbol aliases as `.set alias, sym` directives instead of extra labels. Defaults to {self.ASM_ALIASES_AS_SET}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--glabel-count", help=f"Toggle glabel count comment. Defaults to {self.GLABEL_ASM_COUNT}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-referencee-symbols", help=f"Toggle glabel count comment. Defaults to {self.ASM_REFERENCEE_SYMBOLS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-comment-frame-info", help=f"Emit a comment before each function summarizing its stack frame and register usage. Defaults to {self.ASM_COMMENT_FRAME_INFO}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-comment-duplicated-strings", help=f"Emit a comment before each string listing other strings with the same contents. Defaults to {self.ASM_COMMENT_DUPLICATED_STRINGS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-comment-data-xrefs", help=f"Emit a comment before each data, rodata and bss symbol listing up to this amount of functions and symbols referencing it. 0 disables it. Defaults to {self.ASM_COMMENT_DATA_XREFS}", type=int, metavar="count")
+        miscConfig.add_argument("--asm-comment-source-lines", help=f"Emit a comment with the source file and line before the instructions generated by each line, if that information is available. Defaults to {self.ASM_COMMENT_SOURCE_LINES}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-comment-array-indices", help=f"Emit a comment with the index of each element of symbols declared as arrays which have at least this amount of elements. 0 disables it. Defaults to {self.ASM_COMMENT_ARRAY_INDICES}", type=int, metavar="count")
+        miscConfig.add_argument("--asm-comment-tail-calls", help=f"Add an end-of-line comment to jumps and branches detected as tail calls. Defaults to {self.ASM_COMMENT_TAIL_CALLS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-comment-diagnostics", help=f"Emit the diagnostics found during analysis as comments before the affected symbol. Defaults to {self.ASM_COMMENT_DIAGNOSTICS}", action=Utils.BooleanOptionalAction)
 
         miscConfig.add_argument("--asm-indentation", help=f"Sets the indentation used for every instruction and data. Defaults to {self.ASM_INDENTATION}", type=int)
         miscConfig.add_argument("--asm-indentation-labels", help=f"Sets the indentation used for labels within functions. Defaults to {self.ASM_INDENTATION_LABELS}", type=int)
+        miscConfig.add_argument("--asm-use-tabs", help=f"Use tabs instead of spaces for indentation and column alignment. Defaults to {self.ASM_USE_TABS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-tab-width", help=f"The width of a tab, used to compute columns when tabs are used. Defaults to {self.ASM_TAB_WIDTH}", type=int)
+        miscConfig.add_argument("--asm-instruction-column", help=f"The column where instructions and data directives start. Zero means a fixed spacing after the line comment. Defaults to {self.ASM_INSTRUCTION_COLUMN}", type=int)
+        miscConfig.add_argument("--asm-directive-ljust", help=f"The minimal number of characters to left-align the name of data directives. Defaults to {self.ASM_DIRECTIVE_LJUST}", type=int)
+        miscConfig.add_argument("--asm-end-of-line-comment-column", help=f"The column where end-of-line comments start. Zero means a single space after the statement. Defaults to {self.ASM_END_OF_LINE_COMMENT_COLUMN}", type=int)
 
+        miscConfig.add_argument("--asm-label-conventions", help="Sets every label macro at once using a preset. The individual label options take precedence over the preset. Defaults to default", choices=asmLabelConventionsPresets)
         miscConfig.add_argument("--asm-text-label", help=f"Changes the label used to declare functions. Defaults to {self.ASM_TEXT_LABEL}")
         miscConfig.add_argument("--asm-text-alt-label", help=f"Changes the label used to declare symbols in the middle of functions. Defaults to {self.ASM_TEXT_ALT_LABEL}")
         miscConfig.add_argument("--asm-jtbl-label", help=f"Changes the label used to declare jumptable labels. Defaults to {self.ASM_JTBL_LABEL}")
@@ -399,13 +1218,19 @@ A C string must start at a 0x4-aligned region, which is '\\0' terminated and pad
         miscConfig.add_argument("--asm-use-symbol-label", help=f"Toggles the use of labels for symbols. Defaults to {self.ASM_USE_SYMBOL_LABEL}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-ent-label", help=f"Tells the disassembler to start using an ent label for functions")
         miscConfig.add_argument("--asm-end-label", help=f"Tells the disassembler to start using an end label for functions")
+        miscConfig.add_argument("--asm-data-end-label", help=f"Tells the disassembler to start using an end label for data symbols")
         miscConfig.add_argument("--asm-func-as-label", help=f"Toggle adding the function name as an additional label. Defaults to {self.ASM_TEXT_FUNC_AS_LABEL}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-data-as-label", help=f"Toggle adding the data symbol name as an additional label. Defaults to {self.ASM_DATA_SYM_AS_LABEL}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-emit-size-directive", help=f"Toggles emitting a size directive to generated symbols. Defaults to {self.ASM_EMIT_SIZE_DIRECTIVE}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-emit-bss-size-directive", help=f"Toggles emitting a size directive to bss symbols too. Has no effect if `--asm-emit-size-directive` is turned off. Defaults to {self.ASM_EMIT_BSS_SIZE_DIRECTIVE}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-emit-type-directive", help=f"Toggles emitting a `.type` directive (`@function` or `@object`) to generated symbols. Defaults to {self.ASM_EMIT_TYPE_DIRECTIVE}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-emit-alignment-from-access-type", help=f"Emit alignment directives for symbols which are accessed with instructions that require bigger alignment, like `ld` or `ldc1`. Defaults to {self.ASM_EMIT_ALIGNMENT_FROM_ACCESS_TYPE}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-use-prelude", help=f"Toggle use of the default prelude for asm files. Defaults to {self.ASM_USE_PRELUDE}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-prelude-use-includes", help=f"Toggle use of the asm includes on the default prelude. Has no effect if `--asm-use-prelude` is turned off. Defaults to {self.ASM_PRELUDE_USE_INCLUDES}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-prelude-use-instruction-directives", help=f"Toggle use of the instruction directives on the default prelude. Has no effect if `--asm-use-prelude` is turned off. Defaults to {self.ASM_PRELUDE_USE_INSTRUCTION_DIRECTIVES}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-prelude-use-section-start", help=f"Toggle use of the section start directive on the default prelude. Has no effect if `--asm-use-prelude` is turned off. Defaults to {self.ASM_PRELUDE_USE_SECTION_START}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--asm-assembler-modes-placement", help=f"Where to emit the `.set noat` and `.set noreorder` directives: once per text file, around every function or never. Defaults to {self.ASM_ASSEMBLER_MODES_PLACEMENT.value}", choices=[x.value for x in AsmAssemblerModesPlacement])
+        miscConfig.add_argument("--asm-reorder-equivalent-functions", help=f"Emit functions whose delay slots are all `nop`s in reorder mode, omitting those `nop`s. Requires `--asm-assembler-modes-placement function`. Defaults to {self.ASM_REORDER_EQUIVALENT_FUNCTIONS}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--asm-generated-by", help=f"Toggle comment indicating the tool and version used to generate the disassembly. Defaults to {self.ASM_GENERATED_BY}", action=Utils.BooleanOptionalAction)
 
         miscConfig.add_argument("--asm-globalize-text-labels-referenced-by-non-jumptable", help=f"""\
@@ -417,15 +1242,31 @@ jumptables is usually a symptom of something going wrong, like fake symbols
 fake references, or jumptables being disassembled as data instead of rodata.
 Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", action=Utils.BooleanOptionalAction)
 
+        miscConfig.add_argument("--fold-hi-lo-into-la", help=f"Emit a `la` pseudo instruction in place of `lui`/`addiu` pairs when it is known to reassemble into the same instructions. Defaults to {self.ASM_FOLD_HI_LO_INTO_LA}", action=Utils.BooleanOptionalAction)
+
+        miscConfig.add_argument("--pseudo-li", help=f"Emit a `li` pseudo instruction in place of `addiu`s and `ori`s which load an immediate into a register, when it is known to reassemble into the same instruction. Defaults to {self.ASM_PSEUDO_LI}", action=Utils.BooleanOptionalAction)
+
         miscConfig.add_argument("--print-new-file-boundaries", help=f"Print to stdout any new file boundary found. Defaults to {self.PRINT_NEW_FILE_BOUNDARIES}", action=Utils.BooleanOptionalAction)
 
+        miscConfig.add_argument("--disassembly-jobs", help=f"Amount of threads used to generate the disassembly of the symbols of each section. Defaults to {self.DISASSEMBLY_JOBS}", type=int)
+
         miscConfig.add_argument("--use-dot-byte", help=f"Disassemble symbols marked as bytes with .byte instead of .word. Defaults to {self.USE_DOT_BYTE}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--use-dot-short", help=f"Disassemble symbols marked as shorts with .short instead of .word. Defaults to {self.USE_DOT_SHORT}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--use-dot-dword", help=f"Disassemble symbols marked as 64 or 128 bits integers with .dword instead of .word. Defaults to {self.USE_DOT_DWORD}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--data-guess-dwords", help=f"Disassemble untyped data symbols as .dword if all their word pairs look like 64 bits integers. Only for MIPS III and newer. Defaults to {self.DATA_GUESS_DWORDS}", action=Utils.BooleanOptionalAction)
 
         miscConfig.add_argument("--panic-range-check", help=f"Produce a fatal error if a range check fails instead of just printing a warning. Defaults to {self.PANIC_RANGE_CHECK}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--panic-late-rodata-ratio", help=f"Produce a fatal error if the late rodata migrated to a function can't fit its text for asm-processor instead of just printing a warning. Defaults to {self.PANIC_LATE_RODATA_RATIO}", action=Utils.BooleanOptionalAction)
 
         miscConfig.add_argument("--create-data-pads", help=f"Create dummy and unreferenced data symbols after another symbol which has non-zero user-declared size.\nThe generated pad symbols may have non-zero data. Defaults to {self.CREATE_DATA_PADS}", action=Utils.BooleanOptionalAction)
         miscConfig.add_argument("--create-rodata-pads", help=f"Create dummy and unreferenced rodata symbols after another symbol which has non-zero user-declared size.\nThe generated pad symbols may have non-zero data. Defaults to {self.CREATE_RODATA_PADS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--create-bss-alignment-pads", help=f"Split the alignment gap after a bss symbol whose size can be inferred from its type into its own dummy pad symbol. Defaults to {self.CREATE_BSS_ALIGNMENT_PADS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--data-max-symbol-size", help=f"Split untyped and unreferenced data symbols bigger than this size into multiple symbols, starting at addresses aligned to this size. 0 means no limit. Defaults to {self.DATA_MAX_SYMBOL_SIZE}", type=int, metavar="size")
+        miscConfig.add_argument("--rodata-max-symbol-size", help=f"Same as `--data-max-symbol-size`, but for rodata symbols. Defaults to {self.RODATA_MAX_SYMBOL_SIZE}", type=int, metavar="size")
+        miscConfig.add_argument("--fold-autogenerated-pads", help=f"Emit unreferenced automatically generated pads as trailing padding of the previous symbol instead of as symbols on their own. Defaults to {self.FOLD_AUTOGENERATED_PADS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--truncate-symbols-at-split-boundary", help=f"Truncate the user-declared size of symbols which go past the end of their file split. Defaults to {self.TRUNCATE_SYMBOLS_AT_SPLIT_BOUNDARY}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--prune-unreferenced-symbols", help=f"Remove autogenerated data and rodata symbols which are not referenced by anything after the analysis. Defaults to {self.PRUNE_UNREFERENCED_SYMBOLS}", action=Utils.BooleanOptionalAction)
+        miscConfig.add_argument("--allow-context-cache", help=f"Allow saving and loading the analyzed context to and from a cache file. The cache uses pickle, so never load a cache file from an untrusted source because it can execute arbitrary code. Defaults to {self.ALLOW_CONTEXT_CACHE}", action=Utils.BooleanOptionalAction)
 
 
         verbosityConfig = parser.add_argument_group("Verbosity options")
@@ -472,6 +1313,18 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
                     Utils.eprint(f"Unrecognized compiler setting from environment 'SPIMDISASM_{attr.upper()}={environmentValue}'.")
                     continue
                 environmentValue = newComp
+            elif isinstance(currentValue, SharedRodataMigrationPolicy):
+                policy = SharedRodataMigrationPolicy.fromStr(environmentValue)
+                if policy is None:
+                    Utils.eprint(f"Unrecognized shared rodata migration policy from environment 'SPIMDISASM_{attr.upper()}={environmentValue}'.")
+                    continue
+                environmentValue = policy
+            elif isinstance(currentValue, RomByteOrder):
+                romByteOrder = RomByteOrder.fromStr(environmentValue)
+                if romByteOrder is None:
+                    Utils.eprint(f"Unrecognized rom byte order from environment 'SPIMDISASM_{attr.upper()}={environmentValue}'.")
+                    continue
+                environmentValue = romByteOrder
             elif isinstance(currentValue, InputEndian):
                 environmentValue = InputEndian.fromStr(environmentValue)
             elif isinstance(currentValue, Abi):
@@ -488,6 +1341,17 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
             setattr(self, attr, environmentValue)
 
     def parseArgs(self, args: argparse.Namespace) -> None:
+        from .SettingsBundle import SettingsBundle
+
+        if args.settings_preset is not None:
+            bundle = SettingsBundle.fromPreset(args.settings_preset)
+            if bundle is not None:
+                for error in bundle.apply(self):
+                    Utils.eprint(f"Warning: {error}")
+        if args.settings_file is not None:
+            for error in SettingsBundle.fromFile(args.settings_file).apply(self):
+                Utils.eprint(f"Warning: {error}")
+
         if args.disasm_unknown is not None:
             self.DISASSEMBLE_UNKNOWN_INSTRUCTIONS = args.disasm_unknown
 
@@ -495,17 +1359,57 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
             self.RODATA_STRING_ENCODING = args.rodata_string_encoding
         if args.data_string_encoding is not None:
             self.DATA_STRING_ENCODING = args.data_string_encoding
+        if args.rodata_charmap is not None:
+            self.RODATA_CHARMAP = Charmap.fromTblFile(args.rodata_charmap)
+        if args.data_charmap is not None:
+            self.DATA_CHARMAP = Charmap.fromTblFile(args.data_charmap)
 
         if args.rodata_string_guesser is not None:
             self.RODATA_STRING_GUESSER_LEVEL = args.rodata_string_guesser
         if args.data_string_guesser is not None:
             self.DATA_STRING_GUESSER_LEVEL = args.data_string_guesser
 
+        if args.rodata_float_guesser is not None:
+            self.RODATA_FLOAT_GUESSER_LEVEL = args.rodata_float_guesser
+        if args.rodata_strict_text_pointers is not None:
+            self.RODATA_STRICT_TEXT_POINTERS = args.rodata_strict_text_pointers
         if args.pascal_rodata_string_guesser is not None:
             self.PASCAL_RODATA_STRING_GUESSER_LEVEL = args.pascal_rodata_string_guesser
         if args.pascal_data_string_guesser is not None:
             self.PASCAL_DATA_STRING_GUESSER_LEVEL = args.pascal_data_string_guesser
 
+        if args.string_guesser_min_length is not None:
+            self.STRING_GUESSER_MIN_LENGTH = args.string_guesser_min_length
+        if args.string_guesser_max_length is not None:
+            self.STRING_GUESSER_MAX_LENGTH = args.string_guesser_max_length
+        if args.string_guesser_character_class is not None:
+            characterClass = StringGuesserCharacterClass.fromStr(args.string_guesser_character_class)
+            if characterClass is not None:
+                self.STRING_GUESSER_CHARACTER_CLASS = characterClass
+        if args.string_guesser_terminator_alignment is not None:
+            self.STRING_GUESSER_TERMINATOR_ALIGNMENT = args.string_guesser_terminator_alignment
+
+        if args.string_pool_analysis is not None:
+            self.STRING_POOL_ANALYSIS = args.string_pool_analysis
+        if args.minimal_data_analysis is not None:
+            self.MINIMAL_DATA_ANALYSIS = args.minimal_data_analysis
+        if args.jumptable_label_outside_function is not None:
+            jumptableLabelPolicy = JumptableLabelOutsideFunctionPolicy.fromStr(args.jumptable_label_outside_function)
+            if jumptableLabelPolicy is not None:
+                self.JUMPTABLE_LABEL_OUTSIDE_FUNCTION = jumptableLabelPolicy
+        if args.detect_relative_jumptables is not None:
+            self.DETECT_RELATIVE_JUMPTABLES = args.detect_relative_jumptables
+        if args.shiftable_output is not None:
+            self.SHIFTABLE_OUTPUT = args.shiftable_output
+        if args.detect_libultra_structs is not None:
+            self.DETECT_LIBULTRA_STRUCTS = args.detect_libultra_structs
+        if args.propagate_pointer_types is not None:
+            self.PROPAGATE_POINTER_TYPES = args.propagate_pointer_types
+        if args.infer_indirect_call_targets is not None:
+            self.INFER_INDIRECT_CALL_TARGETS = args.infer_indirect_call_targets
+        if args.resolve_display_references is not None:
+            self.RESOLVE_DISPLAY_REFERENCES = args.resolve_display_references
+
         if args.string_guesser is not None:
             self.STRING_GUESSER = args.string_guesser
         if args.aggressive_string_guesser is not None:
@@ -513,6 +1417,10 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
 
         if args.allow_migrating_const_variables is not None:
             self.ALLOW_MIGRATING_CONST_VARIABLES = args.allow_migrating_const_variables
+        if args.shared_rodata_migration_policy is not None:
+            policy = SharedRodataMigrationPolicy.fromStr(args.shared_rodata_migration_policy)
+            if policy is not None:
+                self.SHARED_RODATA_MIGRATION_POLICY = policy
 
         if args.name_vars_by_section is not None:
             self.AUTOGENERATED_NAMES_BASED_ON_SECTION_TYPE = args.name_vars_by_section
@@ -523,12 +1431,36 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
         if args.sequential_label_names is not None:
             self.SEQUENTIAL_LABEL_NAMES = args.sequential_label_names
 
+        if args.branch_label_style is not None:
+            branchLabelStyle = BranchLabelStyle.fromStr(args.branch_label_style)
+            if branchLabelStyle is not None:
+                self.BRANCH_LABEL_STYLE = branchLabelStyle
+
         if args.legacy_sym_addr_zero_padding is not None:
             self.LEGACY_SYM_ADDR_ZERO_PADDING = args.legacy_sym_addr_zero_padding
 
         if args.custom_suffix:
             self.CUSTOM_SUFFIX = args.custom_suffix
 
+        if args.autogenerated_function_prefix is not None:
+            self.AUTOGENERATED_NAMES_FUNCTION_PREFIX = args.autogenerated_function_prefix
+        if args.autogenerated_data_prefix is not None:
+            self.AUTOGENERATED_NAMES_DATA_PREFIX = args.autogenerated_data_prefix
+        if args.autogenerated_jumptable_prefix is not None:
+            self.AUTOGENERATED_NAMES_JUMPTABLE_PREFIX = args.autogenerated_jumptable_prefix
+        if args.autogenerated_unknown_segment_prefix is not None:
+            self.AUTOGENERATED_NAMES_UNKNOWN_SEGMENT_PREFIX = args.autogenerated_unknown_segment_prefix
+        if args.unknown_segment_max_inferred_size is not None:
+            self.UNKNOWN_SEGMENT_MAX_INFERRED_SIZE = int(args.unknown_segment_max_inferred_size, 16)
+        if args.autogenerated_address_padding is not None:
+            self.AUTOGENERATED_NAMES_ADDRESS_PADDING = args.autogenerated_address_padding
+        if args.name_vars_by_overlay_category is not None:
+            self.AUTOGENERATED_NAMES_INCLUDE_OVERLAY_CATEGORY = args.name_vars_by_overlay_category
+        if args.name_overlay_vars_by_rom is not None:
+            self.AUTOGENERATED_NAMES_BASED_ON_ROM_FOR_OVERLAYS = args.name_overlay_vars_by_rom
+        if args.autogenerated_name_end_suffix is not None:
+            self.AUTOGENERATED_NAMES_END_SUFFIX = args.autogenerated_name_end_suffix
+
         if args.compiler is not None:
             compiler = Compiler.fromStr(args.compiler)
             if compiler is not None:
@@ -539,19 +1471,41 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
 
         if args.detect_redundant_function_end is not None:
             self.DETECT_REDUNDANT_FUNCTION_END = args.detect_redundant_function_end
+        if args.detect_delay_slot_hazards is not None:
+            self.DETECT_DELAY_SLOT_HAZARDS = args.detect_delay_slot_hazards
+        if args.text_reachability_analysis is not None:
+            self.TEXT_REACHABILITY_ANALYSIS = args.text_reachability_analysis
 
         if args.endian is not None:
             self.ENDIAN = InputEndian.fromStr(args.endian)
+        if args.rom_byte_order is not None:
+            romByteOrder = RomByteOrder.fromStr(args.rom_byte_order)
+            if romByteOrder is None:
+                raise ValueError(f"Unrecognized rom byte order '{args.rom_byte_order}'. Expected one of: {', '.join(x.value for x in RomByteOrder)}")
+            self.ROM_BYTE_ORDER = romByteOrder
 
         if args.abi is not None:
             self.ABI = Abi.fromStr(args.abi)
 
-        arch_level = ArchLevel.fromValue(args.arch_level)
-        if arch_level is not None:
-            self.ARCHLEVEL = arch_level
+        if args.arch_level is not None:
+            self.ARCHLEVEL = ArchLevel[args.arch_level]
 
         if args.gp is not None:
             self.GP_VALUE = int(args.gp, 16)
+
+        if args.valid_pointer_range is not None:
+            for start, end in args.valid_pointer_range:
+                self.VALID_POINTER_RANGES.append((int(start, 16), int(end, 16)))
+
+        if args.segmented_address is not None:
+            for segment, vram in args.segmented_address:
+                self.SEGMENTED_ADDRESS_TABLE[int(segment, 16)] = int(vram, 16)
+        if args.segmented_address_macro is not None:
+            self.SEGMENTED_ADDRESS_MACRO = args.segmented_address_macro
+        if args.kseg1_as_kseg0_alias is not None:
+            self.KSEG1_AS_KSEG0_ALIAS = args.kseg1_as_kseg0_alias
+        if args.kseg1_alias_macro is not None:
+            self.KSEG1_ALIAS_MACRO = args.kseg1_alias_macro
         if args.pic is not None:
             self.PIC = args.pic
         if args.emit_cpload is not None:
@@ -568,22 +1522,55 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
             self.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_CONSTANTS = args.filtered_addresses_as_constants
         if args.filtered_addresses_as_hilo is not None:
             self.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_HILO = args.filtered_addresses_as_hilo
+        if args.unpaired_hi_policy is not None:
+            unpairedHiPolicy = UnpairedHiPolicy.fromStr(args.unpaired_hi_policy)
+            if unpairedHiPolicy is not None:
+                self.UNPAIRED_HI_POLICY = unpairedHiPolicy
 
         if args.allow_unksegment is not None:
             self.ALLOW_UNKSEGMENT = args.allow_unksegment
 
+        if args.inner_labels_for_code_references is not None:
+            self.INNER_LABELS_FOR_CODE_REFERENCES = args.inner_labels_for_code_references
+
         if args.allow_all_addends_on_data is not None:
             self.ALLOW_ALL_ADDENDS_ON_DATA = args.allow_all_addends_on_data
+        if args.data_addend_max is not None:
+            self.DATA_ADDEND_MAX = int(args.data_addend_max, 16)
+        if args.data_addend_forbidden_type is not None:
+            self.DATA_ADDEND_FORBIDDEN_TYPES |= set(args.data_addend_forbidden_type)
         if args.allow_all_constants_on_data is not None:
             self.ALLOW_ALL_CONSTANTS_ON_DATA = args.allow_all_constants_on_data
 
 
         if args.asm_comments is not None:
             self.ASM_COMMENT = args.asm_comments
+        if args.asm_comment_vrom is not None:
+            self.ASM_COMMENT_VROM = args.asm_comment_vrom
         if args.comment_offset_width is not None:
             self.ASM_COMMENT_OFFSET_WIDTH = args.comment_offset_width
+        if args.asm_comment_diagnostics is not None:
+            self.ASM_COMMENT_DIAGNOSTICS = args.asm_comment_diagnostics
+        if args.asm_comment_frame_info is not None:
+            self.ASM_COMMENT_FRAME_INFO = args.asm_comment_frame_info
+        if args.asm_comment_duplicated_strings is not None:
+            self.ASM_COMMENT_DUPLICATED_STRINGS = args.asm_comment_duplicated_strings
+        if args.asm_comment_data_xrefs is not None:
+            self.ASM_COMMENT_DATA_XREFS = args.asm_comment_data_xrefs
+        if args.asm_comment_source_lines is not None:
+            self.ASM_COMMENT_SOURCE_LINES = args.asm_comment_source_lines
+        if args.asm_comment_array_indices is not None:
+            self.ASM_COMMENT_ARRAY_INDICES = args.asm_comment_array_indices
+        if args.asm_comment_tail_calls is not None:
+            self.ASM_COMMENT_TAIL_CALLS = args.asm_comment_tail_calls
         if args.glabel_count is not None:
             self.GLABEL_ASM_COUNT = args.glabel_count
+        if args.asm_visibility_directives is not None:
+            self.ASM_VISIBILITY_DIRECTIVES = args.asm_visibility_directives
+        if args.default_symbol_visibility is not None:
+            self.DEFAULT_SYMBOL_VISIBILITY = args.default_symbol_visibility
+        if args.asm_aliases_as_set is not None:
+            self.ASM_ALIASES_AS_SET = args.asm_aliases_as_set
         if args.asm_referencee_symbols is not None:
             self.ASM_REFERENCEE_SYMBOLS = args.asm_referencee_symbols
 
@@ -591,7 +1578,21 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
             self.ASM_INDENTATION = args.asm_indentation
         if args.asm_indentation_labels is not None:
             self.ASM_INDENTATION_LABELS = args.asm_indentation_labels
-
+        if args.asm_use_tabs is not None:
+            self.ASM_USE_TABS = args.asm_use_tabs
+        if args.asm_tab_width is not None:
+            self.ASM_TAB_WIDTH = args.asm_tab_width
+        if args.asm_instruction_column is not None:
+            self.ASM_INSTRUCTION_COLUMN = args.asm_instruction_column
+        if args.asm_directive_ljust is not None:
+            self.ASM_DIRECTIVE_LJUST = args.asm_directive_ljust
+        if args.asm_end_of_line_comment_column is not None:
+            self.ASM_END_OF_LINE_COMMENT_COLUMN = args.asm_end_of_line_comment_column
+
+        if args.asm_label_conventions is not None:
+            conventions = AsmLabelConventions.fromPreset(args.asm_label_conventions)
+            if conventions is not None:
+                self.setAsmLabelConventions(conventions)
         if args.asm_text_label:
             self.ASM_TEXT_LABEL = args.asm_text_label
         if args.asm_text_alt_label:
@@ -608,12 +1609,20 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
             self.ASM_TEXT_ENT_LABEL = args.asm_ent_label
         if args.asm_end_label:
             self.ASM_TEXT_END_LABEL = args.asm_end_label
+        if args.asm_data_end_label:
+            self.ASM_DATA_END_LABEL = args.asm_data_end_label
         if args.asm_func_as_label is not None:
             self.ASM_TEXT_FUNC_AS_LABEL = args.asm_func_as_label
         if args.asm_data_as_label is not None:
             self.ASM_DATA_SYM_AS_LABEL = args.asm_data_as_label
         if args.asm_emit_size_directive is not None:
             self.ASM_EMIT_SIZE_DIRECTIVE = args.asm_emit_size_directive
+        if args.asm_emit_bss_size_directive is not None:
+            self.ASM_EMIT_BSS_SIZE_DIRECTIVE = args.asm_emit_bss_size_directive
+        if args.asm_emit_type_directive is not None:
+            self.ASM_EMIT_TYPE_DIRECTIVE = args.asm_emit_type_directive
+        if args.asm_emit_alignment_from_access_type is not None:
+            self.ASM_EMIT_ALIGNMENT_FROM_ACCESS_TYPE = args.asm_emit_alignment_from_access_type
         if args.asm_use_prelude is not None:
             self.ASM_USE_PRELUDE = args.asm_use_prelude
         if args.asm_prelude_use_includes is not None:
@@ -622,24 +1631,58 @@ Defaults to {self.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE}""", act
             self.ASM_PRELUDE_USE_INSTRUCTION_DIRECTIVES = args.asm_prelude_use_instruction_directives
         if args.asm_prelude_use_section_start is not None:
             self.ASM_PRELUDE_USE_SECTION_START = args.asm_prelude_use_section_start
+        if args.asm_assembler_modes_placement is not None:
+            modesPlacement = AsmAssemblerModesPlacement.fromStr(args.asm_assembler_modes_placement)
+            if modesPlacement is not None:
+                self.ASM_ASSEMBLER_MODES_PLACEMENT = modesPlacement
+        if args.asm_reorder_equivalent_functions is not None:
+            self.ASM_REORDER_EQUIVALENT_FUNCTIONS = args.asm_reorder_equivalent_functions
         if args.asm_generated_by is not None:
             self.ASM_GENERATED_BY = args.asm_generated_by
 
+        if args.fold_hi_lo_into_la is not None:
+            self.ASM_FOLD_HI_LO_INTO_LA = args.fold_hi_lo_into_la
+        if args.pseudo_li is not None:
+            self.ASM_PSEUDO_LI = args.pseudo_li
+
         if args.print_new_file_boundaries is not None:
             self.PRINT_NEW_FILE_BOUNDARIES = args.print_new_file_boundaries
 
+        if args.disassembly_jobs is not None:
+            self.DISASSEMBLY_JOBS = max(args.disassembly_jobs, 1)
+
         if args.use_dot_byte is not None:
             self.USE_DOT_BYTE = args.use_dot_byte
         if args.use_dot_short is not None:
             self.USE_DOT_SHORT = args.use_dot_short
+        if args.use_dot_dword is not None:
+            self.USE_DOT_DWORD = args.use_dot_dword
+        if args.data_guess_dwords is not None:
+            self.DATA_GUESS_DWORDS = args.data_guess_dwords
 
         if args.panic_range_check is not None:
             self.PANIC_RANGE_CHECK = args.panic_range_check
+        if args.panic_late_rodata_ratio is not None:
+            self.PANIC_LATE_RODATA_RATIO = args.panic_late_rodata_ratio
 
         if args.create_data_pads is not None:
             self.CREATE_DATA_PADS = args.create_data_pads
         if args.create_rodata_pads is not None:
             self.CREATE_RODATA_PADS = args.create_rodata_pads
+        if args.create_bss_alignment_pads is not None:
+            self.CREATE_BSS_ALIGNMENT_PADS = args.create_bss_alignment_pads
+        if args.data_max_symbol_size is not None:
+            self.DATA_MAX_SYMBOL_SIZE = args.data_max_symbol_size
+        if args.rodata_max_symbol_size is not None:
+            self.RODATA_MAX_SYMBOL_SIZE = args.rodata_max_symbol_size
+        if args.fold_autogenerated_pads is not None:
+            self.FOLD_AUTOGENERATED_PADS = args.fold_autogenerated_pads
+        if args.truncate_symbols_at_split_boundary is not None:
+            self.TRUNCATE_SYMBOLS_AT_SPLIT_BOUNDARY = args.truncate_symbols_at_split_boundary
+        if args.prune_unreferenced_symbols is not None:
+            self.PRUNE_UNREFERENCED_SYMBOLS = args.prune_unreferenced_symbols
+        if args.allow_context_cache is not None:
+            self.ALLOW_CONTEXT_CACHE = args.allow_context_cache
 
 
         if args.verbose is not None:
@@ -0,0 +1,38 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+
+from .Relocation import RelocationInfo
+
+
+@dataclasses.dataclass
+class InstructionOverride:
+    """
+    User override for the analysis of a single instruction, for the cases
+    where the analyzer heuristics get it wrong.
+
+    Overrides are applied right after the instruction analysis of each
+    function, so they are taken into account before any symbol is created.
+
+    Overriding the relocation or marking an instruction as a constant also
+    discards the symbol found for its %hi/%lo counterpart, so the counterpart
+    should usually be overridden too.
+
+    See `Context.addInstructionRelocOverride`,
+    `Context.addInstructionConstantOverride` and
+    `Context.addInstructionPairingOverride`.
+    """
+
+    reloc: RelocationInfo|None = None
+    "Force this relocation on the instruction"
+
+    isConstant: bool = False
+    "Don't symbolize this instruction, emit its raw immediate instead"
+
+    pairedHiVrom: int|None = None
+    "Pair this %lo instruction with the %hi instruction at the given vrom"
@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+
+from . import Utils
+
+
+@dataclasses.dataclass
+class OverlayTableLayout:
+    """
+    Describes the layout of each entry of an overlay table, a table in data
+    listing the vrom and vram ranges of every overlay of the same kind.
+
+    Every offset is relative to the start of the entry and must be word
+    aligned.
+    """
+
+    entrySize: int
+    vromStartOffset: int
+    vromEndOffset: int
+    vramStartOffset: int
+    vramEndOffset: int
+
+    @staticmethod
+    def fromPreset(name: str) -> OverlayTableLayout|None:
+        """
+        Returns the layout of a known overlay table. Available presets:
+
+        - `simple`: Only the vrom start, vrom end, vram start and vram end, in
+          that order.
+        - `zelda64_actor`: The actor overlay table of the Zelda 64 engine
+          (`ActorOverlay`). Also used for the effect soft sprites table
+          (`EffectSsOverlay`) if the entry size is adjusted.
+        - `zelda64_gamestate`: The game state overlay table of the Zelda 64
+          engine (`GameStateOverlay`).
+        - `zelda64_kaleido`: The kaleido manager overlay table of the Zelda 64
+          engine (`KaleidoMgrOverlay`).
+        """
+
+        if name == "simple":
+            return OverlayTableLayout(0x10, 0x0, 0x4, 0x8, 0xC)
+        if name == "zelda64_actor":
+            return OverlayTableLayout(0x20, 0x0, 0x4, 0x8, 0xC)
+        if name == "zelda64_gamestate":
+            return OverlayTableLayout(0x30, 0x4, 0x8, 0xC, 0x10)
+        if name == "zelda64_kaleido":
+            return OverlayTableLayout(0x1C, 0x4, 0x8, 0xC, 0x10)
+        return None
+
+
+@dataclasses.dataclass
+class OverlayTableEntry:
+    index: int
+    "Position of this entry in the table"
+    vromStart: int
+    vromEnd: int
+    vramStart: int
+    vramEnd: int
+
+
+def parseOverlayTable(arrayOfBytes: bytes, tableVromStart: int, tableVromEnd: int, layout: OverlayTableLayout) -> list[OverlayTableEntry]:
+    """
+    Parses every entry of the overlay table placed at the given vrom range of
+    `arrayOfBytes`, usually the whole rom.
+
+    Empty entries (the ones with an empty vrom range, like the ones for
+    actors placed on the main segment) are skipped.
+
+    The result can be registered with `Context.addOverlaySegmentsFromTable`.
+    """
+
+    entries: list[OverlayTableEntry] = []
+    entryCount = (tableVromEnd - tableVromStart) // layout.entrySize
+    for index in range(entryCount):
+        entryStart = tableVromStart + index * layout.entrySize
+        words = Utils.bytesToWords(arrayOfBytes, entryStart, entryStart + layout.entrySize)
+
+        vromStart = words[layout.vromStartOffset // 4]
+        vromEnd = words[layout.vromEndOffset // 4]
+        vramStart = words[layout.vramStartOffset // 4]
+        vramEnd = words[layout.vramEndOffset // 4]
+        if vromStart >= vromEnd or vramStart >= vramEnd:
+            continue
+
+        entries.append(OverlayTableEntry(index, vromStart, vromEnd, vramStart, vramEnd))
+    return entries
@@ -0,0 +1,35 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import enum
+from typing import Callable
+
+
+class ProgressPhase(enum.Enum):
+    CONTEXT_BUILD = enum.auto()
+    "Loading user provided symbols and information into the context"
+    SECTION_ANALYSIS = enum.auto()
+    "Analyzing the sections, looking for functions, symbols and references"
+
+
+@dataclasses.dataclass
+class ProgressEvent:
+    phase: ProgressPhase
+    percent: float
+    "How much of the current phase has been completed, from 0 to 100"
+    segmentName: str|None = None
+    "The overlay category of the segment being processed, or `None` for the global segment"
+    sectionName: str|None = None
+    "Name of the section (or file) being processed, if any"
+
+
+ProgressCallback = Callable[[ProgressEvent], None]
+"""
+Receives coarse progress events from the context and the analysis, mainly
+intended to give feedback to GUI frontends.
+"""
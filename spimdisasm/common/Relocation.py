@@ -28,6 +28,9 @@ class RelocType(enum.Enum):
     MIPS_CALL16         = 11 # 16 bit GOT entry for function
     MIPS_GPREL32        = 12 # GP relative 32 bit
 
+    MIPS_GOT_DISP       = 19 # New ABI: GOT entry of the symbol
+    MIPS_GOT_PAGE       = 20 # New ABI: GOT entry of the page of the symbol
+    MIPS_GOT_OFST       = 21 # New ABI: offset of the symbol from its page, paired with `MIPS_GOT_PAGE`
     MIPS_GOT_HI16       = 22
     MIPS_GOT_LO16       = 23
     MIPS_CALL_HI16      = 30
@@ -78,6 +81,12 @@ class RelocType(enum.Enum):
             return RelocType.MIPS_CALL16
         if value == "MIPS_GPREL32":
             return RelocType.MIPS_GPREL32
+        if value == "MIPS_GOT_DISP":
+            return RelocType.MIPS_GOT_DISP
+        if value == "MIPS_GOT_PAGE":
+            return RelocType.MIPS_GOT_PAGE
+        if value == "MIPS_GOT_OFST":
+            return RelocType.MIPS_GOT_OFST
         if value == "MIPS_GOT_HI16":
             return RelocType.MIPS_GOT_HI16
         if value == "MIPS_GOT_LO16":
@@ -108,6 +117,10 @@ _percentRel = {
     RelocType.MIPS_CALL16:     f"%call16",
     # RelocType.MIPS_GPREL32:    f"",
 
+    RelocType.MIPS_GOT_DISP:   f"%got_disp",
+    RelocType.MIPS_GOT_PAGE:   f"%got_page",
+    RelocType.MIPS_GOT_OFST:   f"%got_ofst",
+
     RelocType.MIPS_GOT_HI16:   f"%got_hi",
     RelocType.MIPS_GOT_LO16:   f"%got_lo",
     RelocType.MIPS_CALL_HI16:  f"%call_hi",
@@ -134,6 +147,82 @@ _wordRel = {
     # RelocType.MIPS_CALL_LO16:  f"",
 }
 
+_implicitAddendMasks = {
+    RelocType.MIPS_16:         0xFFFF,
+    RelocType.MIPS_32:         0xFFFFFFFF,
+    RelocType.MIPS_26:         0x03FFFFFF,
+    RelocType.MIPS_HI16:       0xFFFF,
+    RelocType.MIPS_LO16:       0xFFFF,
+    RelocType.MIPS_GPREL16:    0xFFFF,
+    RelocType.MIPS_PC16:       0xFFFF,
+    RelocType.MIPS_GPREL32:    0xFFFFFFFF,
+    RelocType.MIPS_GOT_DISP:   0xFFFF,
+    RelocType.MIPS_GOT_PAGE:   0xFFFF,
+    RelocType.MIPS_GOT_OFST:   0xFFFF,
+}
+
+
+def joinAddendHiLo(hi: int, lo: int) -> int:
+    """
+    Returns the signed 32 bits value built from a `%hi` immediate and a `%lo`
+    immediate, taking into account the `%lo` immediate gets sign extended.
+    """
+    lo &= 0xFFFF
+    if lo & 0x8000:
+        lo -= 0x10000
+    value = ((hi & 0xFFFF) << 16) + lo
+    value &= 0xFFFFFFFF
+    if value & 0x80000000:
+        value -= 0x100000000
+    return value
+
+def getImplicitAddend(relocType: RelocType, word: int, pairedWord: int|None=None) -> int:
+    """
+    Calculates the addend stored in the relocated field of a `.rel` (not
+    `.rela`) relocation, following the MIPS psABI rules.
+
+    `pairedWord` is the raw instruction holding the other half of a
+    `%hi`/`%lo` pair, if any. For a `R_MIPS_HI16` it should be the matching
+    `R_MIPS_LO16` and for the `R_MIPS_LO16` it should be the previous
+    `R_MIPS_HI16`.
+    """
+    mask = _implicitAddendMasks.get(relocType)
+    if mask is None:
+        return 0
+    field = word & mask
+
+    if relocType == RelocType.MIPS_HI16:
+        lo = 0 if pairedWord is None else pairedWord & 0xFFFF
+        return joinAddendHiLo(field, lo)
+    if relocType == RelocType.MIPS_LO16:
+        if pairedWord is None:
+            return joinAddendHiLo(0, field)
+        return joinAddendHiLo(pairedWord & 0xFFFF, field)
+    if relocType == RelocType.MIPS_26:
+        return field << 2
+    if relocType == RelocType.MIPS_PC16:
+        # The addend of a branch is relative to the delay slot, but the
+        # assembler already accounts for that when it sees a `b sym`
+        field = joinAddendHiLo(0, field)
+        return (field << 2) + 4
+    if mask == 0xFFFF:
+        return joinAddendHiLo(0, field)
+    if field & 0x80000000:
+        return field - 0x100000000
+    return field
+
+def getExplicitAddend(relocType: RelocType, addend: int) -> int:
+    """
+    Normalizes the addend of a `.rela` relocation so it matches the one
+    `getImplicitAddend` would return for the same relocation on a `.rel`
+    section.
+    """
+    if relocType == RelocType.MIPS_PC16:
+        # The addend of a branch is relative to the delay slot, see `getImplicitAddend`
+        return addend + 4
+    return addend
+
+
 _operationRel = {
     RelocType.CUSTOM_CONSTANT_HI:  f">> 16",
     RelocType.CUSTOM_CONSTANT_LO:  f"& 0xFFFF",
@@ -164,7 +253,7 @@ class RelocationInfo:
         if self.addend == 0:
             return name
 
-        if GlobalConfig.COMPILER.value.bigAddendWorkaroundForMigratedFunctions and isSplittedSymbol:
+        if GlobalConfig.getCompilerProperties().bigAddendWorkaroundForMigratedFunctions and isSplittedSymbol:
             if self.relocType == RelocType.MIPS_LO16:
                 if self.addend < -0x8000:
                     return f"{name} - (0x{-self.addend:X} & 0xFFFF)"
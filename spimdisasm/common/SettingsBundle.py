@@ -0,0 +1,308 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import enum
+import json
+from pathlib import Path
+from typing import Any
+
+from .CompilerConfig import Compiler
+from .GlobalConfig import GlobalConfigType, InputEndian, RomByteOrder, Abi, ArchLevel, SharedRodataMigrationPolicy, BranchLabelStyle, UnpairedHiPolicy, JumptableLabelOutsideFunctionPolicy, AsmAssemblerModesPlacement, StringGuesserCharacterClass
+
+
+settingsPresets: dict[str, dict[str, Any]] = {
+    "n64_ido": {
+        "COMPILER": "IDO",
+        "ENDIAN": "big",
+        "ABI": "O32",
+        "ARCHLEVEL": "MIPS3",
+        "RODATA_STRING_ENCODING": "EUC-JP",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+        "DETECT_REDUNDANT_FUNCTION_END": True,
+    },
+    "n64_kmc": {
+        "COMPILER": "KMC",
+        "ENDIAN": "big",
+        "ABI": "O32",
+        "ARCHLEVEL": "MIPS3",
+        "RODATA_STRING_ENCODING": "EUC-JP",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+    "n64_sn64": {
+        "COMPILER": "SN64",
+        "ENDIAN": "big",
+        "ABI": "O32",
+        "ARCHLEVEL": "MIPS3",
+        "RODATA_STRING_ENCODING": "EUC-JP",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+    "ique_egcs": {
+        "COMPILER": "EGCS",
+        "ENDIAN": "big",
+        "ABI": "O32",
+        "ARCHLEVEL": "MIPS3",
+        "RODATA_STRING_ENCODING": "EUC-JP",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+    "psx_psyq": {
+        "COMPILER": "PSYQ",
+        "ENDIAN": "little",
+        "ABI": "O32",
+        "ARCHLEVEL": "MIPS1",
+        "RODATA_STRING_ENCODING": "ASCII",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+    "psx_gcc": {
+        "COMPILER": "GCC",
+        "ENDIAN": "little",
+        "ABI": "O32",
+        "ARCHLEVEL": "MIPS1",
+        "RODATA_STRING_ENCODING": "ASCII",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+    "ps2_eegcc": {
+        "COMPILER": "EEGCC",
+        "ENDIAN": "little",
+        "ARCHLEVEL": "MIPS3",
+        "RODATA_STRING_ENCODING": "ASCII",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+    "ps2_mwcc": {
+        "COMPILER": "MWCCPS2",
+        "ENDIAN": "little",
+        "ARCHLEVEL": "MIPS3",
+        "RODATA_STRING_ENCODING": "ASCII",
+        "DATA_STRING_ENCODING": "ASCII",
+        "RODATA_STRING_GUESSER_LEVEL": 1,
+        "DATA_STRING_GUESSER_LEVEL": 2,
+    },
+}
+
+
+_enumTypes: dict[str, type[enum.Enum]] = {
+    x.__name__: x
+    for x in [
+        Compiler,
+        InputEndian,
+        RomByteOrder,
+        Abi,
+        ArchLevel,
+        SharedRodataMigrationPolicy,
+        BranchLabelStyle,
+        UnpairedHiPolicy,
+        JumptableLabelOutsideFunctionPolicy,
+        AsmAssemblerModesPlacement,
+        StringGuesserCharacterClass,
+    ]
+}
+
+def _getSettingType(field: dataclasses.Field[Any]) -> tuple[str, bool]|None:
+    annotation = field.type if isinstance(field.type, str) else getattr(field.type, "__name__", "")
+    parts = [part.strip() for part in annotation.split("|")]
+    allowsNone = "None" in parts
+    parts = [part for part in parts if part != "None"]
+    if len(parts) != 1:
+        return None
+    typeName = parts[0]
+    if typeName not in {"bool", "int", "str"} and typeName not in _enumTypes:
+        return None
+    return typeName, allowsNone
+
+def _settingFields() -> dict[str, tuple[str, bool]]:
+    settings: dict[str, tuple[str, bool]] = dict()
+    for field in dataclasses.fields(GlobalConfigType):
+        settingType = _getSettingType(field)
+        if settingType is not None:
+            settings[field.name] = settingType
+    return settings
+
+def _parseEnum(enumType: type[enum.Enum], raw: Any) -> enum.Enum|None:
+    for member in enumType:
+        if isinstance(raw, str) and member.name.upper() == raw.upper():
+            return member
+        if isinstance(member.value, (str, int)) and not isinstance(raw, bool) and member.value == raw:
+            return member
+    return None
+
+def _parseValue(typeName: str, allowsNone: bool, raw: Any) -> tuple[Any, bool]:
+    if raw is None:
+        return None, allowsNone
+    if typeName == "bool":
+        return raw, isinstance(raw, bool)
+    if typeName == "int":
+        if isinstance(raw, bool):
+            return None, False
+        if isinstance(raw, int):
+            return raw, True
+        if isinstance(raw, str):
+            try:
+                return int(raw, 0), True
+            except ValueError:
+                return None, False
+        return None, False
+    if typeName == "str":
+        return raw, isinstance(raw, str)
+    value = _parseEnum(_enumTypes[typeName], raw)
+    return value, value is not None
+
+def _serializeValue(value: Any) -> Any:
+    if isinstance(value, enum.Enum):
+        if isinstance(value.value, str):
+            return value.value
+        return value.name
+    return value
+
+
+@dataclasses.dataclass
+class SettingsBundle:
+    """
+    A serializable set of `GlobalConfig` settings, useful for sharing the
+    same configuration between different tools and projects.
+
+    Each key is the name of a `GlobalConfig` attribute, like `COMPILER` or
+    `RODATA_STRING_GUESSER_LEVEL`. Enums are stored by their value or name,
+    and integers may also be given as strings, like `"0x80000000"`.
+
+    Only boolean, integer, string and enum settings can be bundled.
+    """
+
+    settings: dict[str, Any] = dataclasses.field(default_factory=dict)
+
+    @staticmethod
+    def fromPreset(name: str) -> SettingsBundle|None:
+        """
+        Returns the settings of a known preset, bundling the compiler,
+        endianness, arch level, string encodings and guesser levels commonly
+        used by that kind of project. See `settingsPresets`.
+        """
+        preset = settingsPresets.get(name)
+        if preset is None:
+            return None
+        return SettingsBundle(dict(preset))
+
+    @staticmethod
+    def fromDict(values: dict[str, Any]) -> SettingsBundle:
+        """
+        Builds a bundle from a mapping of settings.
+
+        The special `preset` key may be used to name a preset whose settings
+        are used as the base of this bundle. The rest of the keys take
+        precedence over the ones from the preset.
+        """
+        settings: dict[str, Any] = dict()
+        presetName = values.get("preset")
+        if presetName is not None:
+            preset = settingsPresets.get(presetName)
+            if preset is None:
+                raise ValueError(f"Unknown settings preset '{presetName}'. Valid presets are: {', '.join(settingsPresets)}")
+            settings.update(preset)
+        for key, value in values.items():
+            if key == "preset":
+                continue
+            settings[key] = value
+        return SettingsBundle(settings)
+
+    @staticmethod
+    def fromFile(path: Path) -> SettingsBundle:
+        """
+        Reads a bundle from a `.json` or `.toml` project file.
+
+        Reading TOML files requires Python 3.11 or newer.
+        """
+        if path.suffix.lower() == ".toml":
+            try:
+                import tomllib
+            except ImportError:
+                raise RuntimeError(f"Reading '{path}' requires Python 3.11 or newer. Use a json settings file instead")
+            with path.open("rb") as f:
+                values = tomllib.load(f)
+        else:
+            with path.open() as f:
+                values = json.load(f)
+
+        if not isinstance(values, dict):
+            raise ValueError(f"Settings file '{path}' must contain a mapping of settings")
+        return SettingsBundle.fromDict(values)
+
+    @staticmethod
+    def fromGlobalConfig(config: GlobalConfigType, names: list[str]|None=None) -> SettingsBundle:
+        """
+        Snapshots the current value of the given settings of `config`. If
+        `names` is `None` then every setting which can be bundled is used.
+        """
+        fields = _settingFields()
+        if names is None:
+            names = list(fields)
+
+        settings: dict[str, Any] = dict()
+        for name in names:
+            if name not in fields:
+                raise ValueError(f"Setting '{name}' can't be bundled")
+            settings[name] = _serializeValue(getattr(config, name))
+        return SettingsBundle(settings)
+
+    def apply(self, config: GlobalConfigType) -> list[str]:
+        """
+        Sets every setting of this bundle on `config`.
+
+        Unknown settings and values of the wrong type are skipped.
+
+        Returns a description of every skipped setting.
+        """
+        fields = _settingFields()
+        errors: list[str] = []
+        for name, raw in self.settings.items():
+            settingType = fields.get(name)
+            if settingType is None:
+                errors.append(f"Unknown or unsupported setting '{name}'")
+                continue
+            typeName, allowsNone = settingType
+            value, ok = _parseValue(typeName, allowsNone, raw)
+            if not ok:
+                errors.append(f"Invalid value '{raw}' for setting '{name}' (expected {typeName})")
+                continue
+            setattr(config, name, value)
+        return errors
+
+    def toJson(self) -> str:
+        return json.dumps(self.settings, indent=4)
+
+    def toToml(self) -> str:
+        "`None` values are omitted, since TOML has no way to represent them"
+        output = ""
+        for name, value in self.settings.items():
+            if value is None:
+                continue
+            if isinstance(value, bool):
+                output += f"{name} = {str(value).lower()}\n"
+            else:
+                output += f"{name} = {json.dumps(value)}\n"
+        return output
+
+    def saveToFile(self, path: Path) -> None:
+        "Writes this bundle as TOML if `path` has a `.toml` suffix, otherwise as json"
+        with path.open("w") as f:
+            if path.suffix.lower() == ".toml":
+                f.write(self.toToml())
+            else:
+                f.write(self.toJson())
+                f.write("\n")
@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import enum
+from typing import Callable
+
+
+class SymbolCreationReason(enum.Enum):
+    BRANCH_TARGET = enum.auto()
+    "The target of a branch instruction"
+    FUNCTION_CALL = enum.auto()
+    "The target of a `jal` or similar instruction"
+    CODE_REFERENCE = enum.auto()
+    "An address built by code, like a `%hi`/`%lo` pair or a `$gp` access"
+    DATA_POINTER = enum.auto()
+    "A word in a data section which looks like a pointer"
+    JUMPTABLE = enum.auto()
+    "A jumptable referenced by a function"
+    JUMPTABLE_LABEL = enum.auto()
+    "A label referenced by a jumptable entry"
+
+
+SymbolCreationVetoCallback = Callable[[int, SymbolCreationReason], bool]
+"""
+Receives the vram of the symbol about to be created and the reason it is
+being created. Returning `True` prevents the symbol from being created.
+"""
@@ -5,23 +5,28 @@
 
 from __future__ import annotations
 
-from typing import TextIO, Generator, TYPE_CHECKING
+from typing import TextIO, Generator, Iterable, TYPE_CHECKING
 from pathlib import Path
 
 from . import Utils
 from .SortedDict import SortedDict
-from .GlobalConfig import GlobalConfig
+from .GlobalConfig import GlobalConfig, SharedRodataMigrationPolicy
 from .FileSectionType import FileSectionType
-from .ContextSymbols import SymbolSpecialType, ContextSymbol
+from .ContextSymbols import SymbolSpecialType, ContextSymbol, ReferencedAddress
+from .Errors import SegmentCreationError
+from .ProgressEvents import ProgressPhase
+from .AddressRange import PiecewiseRomVramMapping
 
 if TYPE_CHECKING:
     from .Context import Context
 
 class SymbolsSegment:
     def __init__(self, context: "Context", vromStart: int|None, vromEnd: int|None, vramStart: int, vramEnd: int, overlayCategory: str|None=None) -> None:
-        assert vramStart < vramEnd
+        if vramStart >= vramEnd:
+            raise SegmentCreationError("The vram start must be smaller than the vram end", vromStart, vromEnd, vramStart, vramEnd, overlayCategory)
         if vromStart is not None and vromEnd is not None:
-            assert vromStart <= vromEnd, f"0x{vromStart:06X} <= 0x{vromEnd:06X}"
+            if vromStart > vromEnd:
+                raise SegmentCreationError("The rom start is bigger than the rom end", vromStart, vromEnd, vramStart, vramEnd, overlayCategory)
 
         self.vromStart: int|None = vromStart
         self.vromEnd: int|None = vromEnd
@@ -42,9 +47,24 @@ class SymbolsSegment:
 
         self._isTheUnknownSegment: bool = False
 
+        self.romChunks: PiecewiseRomVramMapping|None = None
+        "For segments built from multiple rom chunks, see `setRomChunks`"
+
+        self.alternativeVramStarts: list[int] = list()
+        """
+        Other addresses this segment may be loaded at, for overlays that get
+        loaded at different addresses depending on the state of the program.
+
+        References to any of those addresses are resolved to the symbols of
+        this segment, using an addend to account for the difference.
+        """
+
 
     @property
     def vromSize(self) -> int|None:
+        if self.romChunks is not None:
+            # Don't count the gaps between the chunks
+            return sum(chunk.rom.size for chunk in self.romChunks.chunks)
         if self.vromStart is None:
             return None
         if self.vromEnd is None:
@@ -56,7 +76,21 @@ class SymbolsSegment:
         return self.vramEnd - self.vramStart
 
 
+    def isBssOnly(self) -> bool:
+        "This segment has no rom range, see `Context.addBssOnlyOverlaySegment`"
+        return self.vromStart is None and self.romChunks is None and not self._isTheUnknownSegment
+
+    def _hasUnknownOrigin(self) -> bool:
+        "Bss-only segments don't have a rom range either, but their symbols do belong to them"
+        if self._isTheUnknownSegment:
+            return True
+        if self.isBssOnly():
+            return False
+        return self.vromStart is None or self.vromEnd is None
+
     def isVromInRange(self, vrom: int) -> bool:
+        if self.romChunks is not None:
+            return self.romChunks.isRomInRange(vrom)
         if self.vromStart is None:
             return False
         if self.vromEnd is None:
@@ -64,11 +98,42 @@ class SymbolsSegment:
         return self.vromStart <= vrom < self.vromEnd
 
     def isVramInRange(self, vram: int) -> bool:
-        return self.vramStart <= vram < self.vramEnd
+        if self.vramStart <= vram < self.vramEnd:
+            return True
+        for vramStart in self.alternativeVramStarts:
+            if vramStart <= vram < vramStart + self.vramSize:
+                return True
+        return False
+
+    def addAlternativeVramStart(self, vramStart: int) -> None:
+        if vramStart == self.vramStart or vramStart in self.alternativeVramStarts:
+            return
+        self.alternativeVramStarts.append(vramStart)
+
+    def translateVram(self, vram: int) -> int:
+        """
+        Translates an address from any of the alternative load addresses of
+        this segment to its main vram.
+
+        Addresses outside of the alternative ranges are returned unchanged.
+        """
+
+        if self.vramStart <= vram < self.vramEnd:
+            return vram
+        for vramStart in self.alternativeVramStarts:
+            if vramStart <= vram < vramStart + self.vramSize:
+                return vram - vramStart + self.vramStart
+        return vram
+
+    def translateVramToBase(self, vram: int, vramStart: int) -> int:
+        "Translates an address of this segment into the address it would have if the segment was loaded at `vramStart`"
+        return self.translateVram(vram) - self.vramStart + vramStart
 
     def changeRanges(self, vromStart: int, vromEnd: int, vramStart: int, vramEnd: int) -> None:
-        assert vromStart <= vromEnd, f"0x{vromStart:06X} <= 0x{vromEnd:06X}"
-        assert vramStart <= vramEnd, f"0x{vramStart:08X} <= 0x{vramEnd:08X}"
+        if vromStart > vromEnd:
+            raise SegmentCreationError("The rom start is bigger than the rom end", vromStart, vromEnd, vramStart, vramEnd, self.overlayCategory)
+        if vramStart > vramEnd:
+            raise SegmentCreationError("The vram start is bigger than the vram end", vromStart, vromEnd, vramStart, vramEnd, self.overlayCategory)
 
         self.vromStart = vromStart
         self.vromEnd = vromEnd
@@ -78,12 +143,34 @@ class SymbolsSegment:
 
 
     def vromToVram(self, vrom: int) -> int|None:
+        if self.romChunks is not None:
+            return self.romChunks.vromToVram(vrom)
         if self.vromStart is None:
             return None
         return vrom - self.vromStart + self.vramStart
 
+    def setRomChunks(self, romChunks: PiecewiseRomVramMapping) -> None:
+        """
+        Declares this segment is built from multiple physically separated rom
+        chunks loaded contiguously in vram, so rom addresses (like the ones of
+        user symbols and relocations) are translated per chunk.
+
+        The rom range of the segment is updated to cover every chunk.
+        """
+        self.romChunks = romChunks
+        self.vromStart = romChunks.romStart
+        self.vromEnd = romChunks.romEnd
+
 
     def addSymbol(self, address: int, sectionType: FileSectionType=FileSectionType.Unknown, isAutogenerated: bool=False, vromAddress: int|None=None, allowAddendInstead: bool=False) -> ContextSymbol:
+        address = self.translateVram(address)
+        if allowAddendInstead and GlobalConfig.STRING_POOL_ANALYSIS:
+            stringEntry = self.context.getStringFromPool(address, self.overlayCategory)
+            if stringEntry is not None and stringEntry.vram != address and address not in self.symbols:
+                if stringEntry.vromAddress is None or self.vromStart is None or self.isVromInRange(stringEntry.vromAddress):
+                    # Reference to the middle of a known string, use the string itself instead of splitting it
+                    address = stringEntry.vram
+                    vromAddress = stringEntry.vromAddress
         contextSym = self.getSymbol(address, tryPlusOffset=allowAddendInstead, checkUpperLimit=True)
         if contextSym is None:
             contextSym = ContextSymbol(address)
@@ -98,7 +185,7 @@ class SymbolsSegment:
         if contextSym.vromAddress is None and vromAddress is not None:
             contextSym.vromAddress = vromAddress
 
-        if self.vromStart is None or self.vromEnd is None or self._isTheUnknownSegment:
+        if self._hasUnknownOrigin():
             contextSym.unknownSegment = True
 
         return contextSym
@@ -110,6 +197,47 @@ class SymbolsSegment:
         contextSym.sectionType = FileSectionType.Text
         return contextSym
 
+    def addKnownFunctions(self, functions: Iterable[tuple[int, int, str|None]]) -> list[ContextSymbol]:
+        """
+        Registers in bulk functions whose address and size are already known,
+        for example from the map of another tool. Each element is a
+        `(vram, size, name)` tuple, where `name` may be `None` to use an
+        automatically generated name.
+
+        The functions are registered as user declared, and the text is split
+        exactly at their boundaries even where the function analysis
+        heuristics disagree (see `ContextSymbol.hasTrustedBoundaries`).
+
+        This is faster than registering each function one by one.
+
+        Returns the registered symbols.
+        """
+        registered: list[ContextSymbol] = []
+        newSymbols: dict[int, ContextSymbol] = dict()
+        for vram, size, name in functions:
+            address = self.translateVram(vram)
+            contextSym = self.symbols.get(address)
+            if contextSym is None:
+                contextSym = newSymbols.get(address)
+            if contextSym is None:
+                contextSym = ContextSymbol(address)
+                contextSym.overlayCategory = self.overlayCategory
+                if self._hasUnknownOrigin():
+                    contextSym.unknownSegment = True
+                newSymbols[address] = contextSym
+
+            contextSym.setTypeSpecial(SymbolSpecialType.function, False)
+            contextSym.sectionType = FileSectionType.Text
+            contextSym.isUserDeclared = True
+            contextSym.userDeclaredSize = size
+            contextSym.hasTrustedBoundaries = True
+            if name is not None:
+                contextSym.name = name
+            registered.append(contextSym)
+
+        self.symbols.addMany(newSymbols.items())
+        return registered
+
     def addBranchLabel(self, address: int, isAutogenerated: bool=False, vromAddress: int|None=None) -> ContextSymbol:
         contextSym = self.addSymbol(address, sectionType=FileSectionType.Text, isAutogenerated=isAutogenerated, vromAddress=vromAddress)
         currentType = contextSym.getTypeSpecial()
@@ -142,11 +270,113 @@ class SymbolsSegment:
 
 
     def removeSymbol(self, address: int) -> None:
+        address = self.translateVram(address)
         if address not in self.symbols:
             return
 
         self.symbols.remove(address)
 
+    def deleteAutogeneratedSymbol(self, address: int, force: bool=False, detachLinks: bool=True) -> bool:
+        """
+        Removes a wrongly autodetected symbol from this segment.
+
+        User declared symbols are not removed unless `force` is `True`.
+
+        Other symbols which reference the removed one (as their parent function,
+        the symbol containing their label or the symbol which generated them as
+        a pad) are detached from it. Detaching requires walking every symbol of
+        the context, so when removing many symbols pass `False` to
+        `detachLinks` and call `detachDeletedSymbols` once afterwards instead.
+
+        Returns `True` if the symbol was removed.
+        """
+
+        address = self.translateVram(address)
+        contextSym = self.symbols.get(address)
+        if contextSym is None:
+            return False
+        if contextSym.isUserDeclared and not force:
+            return False
+
+        self.symbols.remove(address)
+        if detachLinks:
+            self._retargetSymbolLinks({id(contextSym): None})
+        return True
+
+    def detachDeletedSymbols(self, deletedSymbols: list[ContextSymbol]) -> None:
+        "Detaches every symbol of the context from the given symbols, which were removed with `deleteAutogeneratedSymbol`"
+        if len(deletedSymbols) == 0:
+            return
+        self._retargetSymbolLinks({id(contextSym): None for contextSym in deletedSymbols})
+
+    def mergeAutogeneratedSymbol(self, address: int, survivorAddress: int, force: bool=False) -> ContextSymbol|None:
+        """
+        Removes a wrongly autodetected symbol from this segment, moving every
+        reference to it to the symbol at `survivorAddress` (usually the symbol
+        containing the removed one).
+
+        User declared symbols are not merged unless `force` is `True`.
+
+        Returns the surviving symbol, or `None` if nothing was merged.
+        """
+
+        address = self.translateVram(address)
+        survivorAddress = self.translateVram(survivorAddress)
+        if address == survivorAddress:
+            return None
+        contextSym = self.symbols.get(address)
+        survivor = self.symbols.get(survivorAddress)
+        if contextSym is None or survivor is None:
+            return None
+        if contextSym.isUserDeclared and not force:
+            return None
+
+        survivor.referenceCounter += contextSym.referenceCounter
+        survivor.referenceFunctions |= contextSym.referenceFunctions
+        survivor.referenceSymbols |= contextSym.referenceSymbols
+        survivor.referenceLocations.extend(contextSym.referenceLocations)
+
+        self.symbols.remove(address)
+        self._retargetSymbolLinks({id(contextSym): survivor})
+        return survivor
+
+    def _retargetSymbolLinks(self, remap: dict[int, ContextSymbol|None]) -> None:
+        """
+        Moves every link to the removed symbols (keyed by their `id`) to their
+        replacement, or drops the link if there's none.
+
+        Symbols of any segment may link to the removed ones, since references
+        can cross segments.
+        """
+
+        def remapSym(sym: ContextSymbol|None) -> ContextSymbol|None:
+            if sym is None:
+                return None
+            return remap.get(id(sym), sym)
+
+        for segment in self.context.getSegmentsSorted():
+            for otherSym in segment.symbols.values():
+                otherSym.parentFunction = remapSym(otherSym.parentFunction)
+                otherSym.innerLabelOf = remapSym(otherSym.innerLabelOf)
+                otherSym.autoCreatedPadMainSymbol = remapSym(otherSym.autoCreatedPadMainSymbol)
+                otherSym.jumpTableBase = remapSym(otherSym.jumpTableBase)
+                if any(id(x) in remap for x in otherSym.referenceFunctions):
+                    otherSym.referenceFunctions = {newSym for newSym in map(remapSym, otherSym.referenceFunctions) if newSym is not None}
+                if any(id(x) in remap for x in otherSym.referenceSymbols):
+                    otherSym.referenceSymbols = {newSym for newSym in map(remapSym, otherSym.referenceSymbols) if newSym is not None}
+                for location in list(otherSym.referenceLocations):
+                    if id(location.referencer) in remap:
+                        newSym = remap[id(location.referencer)]
+                        if newSym is not None:
+                            location.referencer = newSym
+                        else:
+                            otherSym.referenceLocations.remove(location)
+                # The labels are keyed by their own address, so the removed ones can't be moved to their replacement
+                for labels in (otherSym.branchLabels, otherSym.jumpTables, otherSym.exceptTableLabels):
+                    for vram, label in list(labels.items()):
+                        if id(label) in remap:
+                            labels.remove(vram)
+
 
     def addConstant(self, constantValue: int, name: str, isAutogenerated: bool=False) -> ContextSymbol:
         if constantValue not in self.constants:
@@ -160,6 +390,7 @@ class SymbolsSegment:
 
     def getSymbol(self, address: int, tryPlusOffset: bool = True, checkUpperLimit: bool = True) -> ContextSymbol|None:
         "Searches symbol or a symbol with an addend if `tryPlusOffset` is True"
+        address = self.translateVram(address)
         if GlobalConfig.PRODUCE_SYMBOLS_PLUS_OFFSET and tryPlusOffset:
             pair = self.symbols.getKeyRight(address, inclusive=True)
             if pair is None:
@@ -172,9 +403,50 @@ class SymbolsSegment:
 
         return self.symbols.get(address, None)
 
+    def getSymbolByName(self, name: str) -> ContextSymbol|None:
+        "Searches a symbol by its name or any of its aliases. Linear on the amount of symbols"
+        for contextSym in self.symbols.values():
+            if contextSym.hasName(name):
+                return contextSym
+        return None
+
+    def getFunctionContaining(self, address: int) -> ContextSymbol|None:
+        "Searches the function whose range contains the given address, skipping over any label or other symbol inside the function"
+        address = self.translateVram(address)
+        index = self.symbols.partitionPoint(address, inclusive=True)
+        while index > 0:
+            index -= 1
+            symVram, contextSym = self.symbols.getPairAtIndex(index)
+            if contextSym.getTypeSpecial() == SymbolSpecialType.function:
+                if address < symVram + contextSym.getSize():
+                    return contextSym
+                return None
+        return None
+
     def getSymbolsRange(self, addressStart: int, addressEnd: int) -> Generator[tuple[int, ContextSymbol], None, None]:
         return self.symbols.getRange(addressStart, addressEnd, startInclusive=True, endInclusive=False)
 
+    def getReferencedAddresses(self, addressStart: int|None=None, addressEnd: int|None=None) -> list[ReferencedAddress]:
+        """
+        Returns a read-only snapshot of every symbol gathered so far on this
+        segment, optionally limited to the [`addressStart`, `addressEnd`)
+        range, sorted by address.
+
+        Useful to inspect what is going to be symbolized before committing to
+        a full analysis. See `getPendingPointersInData` too.
+        """
+        if addressStart is None and addressEnd is None:
+            return [contextSym.toReferencedAddress() for contextSym in self.symbols.values()]
+        if addressStart is None:
+            addressStart = 0
+        if addressEnd is None:
+            addressEnd = self.symbols.sortedKeys[-1] + 1 if len(self.symbols) > 0 else 0
+        return [contextSym.toReferencedAddress() for _, contextSym in self.getSymbolsRange(addressStart, addressEnd)]
+
+    def getPendingPointersInData(self) -> list[int]:
+        "Addresses found on data words which are going to be symbolized by the section containing them, sorted"
+        return list(self.newPointersInData)
+
     def getConstant(self, constantValue: int) -> ContextSymbol|None:
         return self.constants.get(constantValue, None)
 
@@ -194,7 +466,11 @@ class SymbolsSegment:
         f.write(f"category,{ContextSymbol.getCsvHeader()}\n")
 
         for address in self.symbols:
-            f.write(f"symbol,{self.symbols[address].toCsv()}\n")
+            contextSym = self.symbols[address]
+            if contextSym.isFoldedPad():
+                # Folded pads are part of the previous symbol on the generated output
+                continue
+            f.write(f"symbol,{contextSym.toCsv()}\n")
 
         for address, constant in self.constants.items():
             f.write(f"constants,{constant.toCsv()}\n")
@@ -203,6 +479,15 @@ class SymbolsSegment:
             f.write(f"new_pointer_in_data,0x{address:08X}\n")
 
 
+    def saveCHeaderToFile(self, f: TextIO, typeMapping: dict[str, str]|None=None) -> None:
+        for address in self.symbols:
+            if self.symbols[address].isFoldedPad():
+                continue
+            declaration = self.symbols[address].getCDeclaration(typeMapping)
+            if declaration is not None:
+                f.write(f"{declaration}\n")
+
+
     N64LibultraSyms: dict[int, tuple[str, str, int]] = {
         0x800001A0: ("leoBootID",      "u32", 0x4),
         0x80000300: ("osTvType",       "u32", 0x4),
@@ -437,36 +722,54 @@ class SymbolsSegment:
                 lowestVram = vram
         self.context.totalVramRange.addSpecialRange(lowestVram, highestVram)
 
-    def fillHardwareRegs(self, useRealNames: bool=False) -> None:
-        lowestVram = 0xFFFFFFFF
-        highestVram = 0x00000000
-        for vram, name in self.N64HardwareRegs.items():
+    def addHardwareRegs(self, registers: dict[int, str], useRealNames: bool=True, size: int=4) -> list[ContextSymbol]:
+        """
+        Registers memory mapped IO registers, like the ones of a console or a
+        custom table supplied by the user. Those registers only exist on
+        memory, so the symbols don't have a rom address.
+
+        If `useRealNames` is `True` then the registers are also registered as
+        constants, so code building their addresses (`lui`/`ori` pairs) gets
+        symbolized with their names.
+
+        Returns the symbol of each register.
+        """
+        if len(registers) == 0:
+            return []
+
+        contextSyms: list[ContextSymbol] = []
+        for vram, name in registers.items():
             nameToUse = None
             if useRealNames:
                 nameToUse = name
             contextSym = self.addSymbol(vram)
             contextSym.name = nameToUse
             contextSym.setTypeSpecial(SymbolSpecialType.hardwarereg, isAutogenerated=False)
-            contextSym.userDeclaredSize = 4
+            contextSym.userDeclaredSize = size
             contextSym.isDefined = True
             contextSym.isUserDeclared = True
+            contextSyms.append(contextSym)
 
             if useRealNames:
                 contextSym = self.addConstant(vram, name, isAutogenerated=False)
                 contextSym.setTypeSpecial(SymbolSpecialType.hardwarereg, isAutogenerated=False)
-                contextSym.userDeclaredSize = 4
+                contextSym.userDeclaredSize = size
                 contextSym.isDefined = True
                 contextSym.isUserDeclared = True
-            if vram > highestVram:
-                highestVram = vram
-            if vram < lowestVram:
-                lowestVram = vram
-        self.context.totalVramRange.addSpecialRange(lowestVram, highestVram)
+        self.context.totalVramRange.addSpecialRange(min(registers), max(registers) + size)
+        return contextSyms
+
+    def fillHardwareRegs(self, useRealNames: bool=False) -> None:
+        self.addHardwareRegs(self.N64HardwareRegs, useRealNames)
+
 
+    def _reportContextBuildProgress(self, filepath: Path, percent: float) -> None:
+        self.context.reportProgress(ProgressPhase.CONTEXT_BUILD, percent, segmentName=self.overlayCategory, sectionName=filepath.name)
 
     def readVariablesCsv(self, filepath: Path) -> None:
         if not filepath.exists():
             return
+        self._reportContextBuildProgress(filepath, 0.0)
 
         variables_file = Utils.readCsv(filepath)
         for row in variables_file:
@@ -510,9 +813,36 @@ class SymbolsSegment:
             contextSym.userDeclaredSize = varSize
             contextSym.isUserDeclared = True
 
+        self._reportContextBuildProgress(filepath, 100.0)
+
+    def readHardwareRegsCsv(self, filepath: Path) -> None:
+        "Reads a csv of `address,name` pairs of IO registers. See `addHardwareRegs`"
+        if not filepath.exists():
+            return
+        self._reportContextBuildProgress(filepath, 0.0)
+
+        registers: dict[int, str] = dict()
+        for row in Utils.readCsv(filepath):
+            if len(row) < 2:
+                continue
+
+            vramStr, regName = row[0].strip(), row[1].strip()
+            if vramStr == "-":
+                continue
+            try:
+                vram = int(vramStr, 16)
+            except ValueError:
+                Utils.eprint(f"Warning: Skipping row with invalid address '{vramStr}' on '{filepath}'")
+                continue
+            registers[vram] = regName
+        self.addHardwareRegs(registers)
+
+        self._reportContextBuildProgress(filepath, 100.0)
+
     def readFunctionsCsv(self, filepath: Path) -> None:
         if not filepath.exists():
             return
+        self._reportContextBuildProgress(filepath, 0.0)
 
         functions_file = Utils.readCsv(filepath)
         for row in functions_file:
@@ -528,9 +858,12 @@ class SymbolsSegment:
             contextSym.name = funcName
             contextSym.isUserDeclared = True
 
+        self._reportContextBuildProgress(filepath, 100.0)
+
     def readConstantsCsv(self, filepath: Path) -> None:
         if not filepath.exists():
             return
+        self._reportContextBuildProgress(filepath, 0.0)
 
         constants_file = Utils.readCsv(filepath)
         for row in constants_file:
@@ -545,9 +878,12 @@ class SymbolsSegment:
             contextSym = self.addConstant(constantValue, constantName)
             contextSym.isUserDeclared = True
 
+        self._reportContextBuildProgress(filepath, 100.0)
+
     def readSplatSymbolAddrs(self, filepath: Path) -> None:
         if not filepath.exists():
             return
+        self._reportContextBuildProgress(filepath, 0.0)
 
         with filepath.open() as f:
             for line in f:
@@ -593,6 +929,10 @@ class SymbolsSegment:
                 contextSym.isUserDeclared = True
                 contextSym.nameEnd = pairs.get("name_end")
                 contextSym.userDeclaredSize = Utils.getMaybeIntFromMaybeStr(pairs.get("size"))
+                contextSym.arrayElementSize = Utils.getMaybeIntFromMaybeStr(pairs.get("element_size"))
+                contextSym.arrayElementCount = Utils.getMaybeIntFromMaybeStr(pairs.get("element_count"))
+                if contextSym.userDeclaredSize is None and contextSym.arrayElementSize is not None and contextSym.arrayElementCount is not None:
+                    contextSym.userDeclaredSize = contextSym.arrayElementSize * contextSym.arrayElementCount
 
                 defined = Utils.getMaybeBooleyFromMaybeStr(pairs.get("defined"))
                 if defined is not None:
@@ -605,6 +945,9 @@ class SymbolsSegment:
                 if forceNotMigration is not None:
                     contextSym.forceNotMigration = forceNotMigration
                 contextSym.functionOwnerForMigration = pairs.get("function_owner")
+                sharedMigration = pairs.get("shared_migration")
+                if sharedMigration is not None:
+                    contextSym.sharedRodataMigrationPolicy = SharedRodataMigrationPolicy.fromStr(sharedMigration)
 
                 allowAddend = Utils.getMaybeBooleyFromMaybeStr(pairs.get("allow_addend"))
                 if allowAddend is not None:
@@ -622,3 +965,19 @@ class SymbolsSegment:
                     contextSym.allowedToBeReferenced = allowBeReferenced
 
                 contextSym.visibility = pairs.get("visibility")
+                alignment = Utils.getMaybeIntFromMaybeStr(pairs.get("align"))
+                if alignment is not None and (alignment <= 0 or alignment & (alignment - 1) != 0):
+                    Utils.eprint(f"Warning: Ignoring alignment 0x{alignment:X} of symbol '{contextSym.getName()}' because it is not a power of two")
+                    alignment = None
+                contextSym.userDeclaredAlignment = alignment
+
+                isCommon = Utils.getMaybeBooleyFromMaybeStr(pairs.get("common"))
+                if isCommon is not None:
+                    contextSym.isCommon = isCommon
+                contextSym.commonAlignment = Utils.getMaybeIntFromMaybeStr(pairs.get("common_alignment"))
+
+                forceFunctionContinuation = Utils.getMaybeBooleyFromMaybeStr(pairs.get("force_function_continuation"))
+                if forceFunctionContinuation is not None:
+                    contextSym.forceFunctionContinuation = forceFunctionContinuation
+
+        self._reportContextBuildProgress(filepath, 100.0)
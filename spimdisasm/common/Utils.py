@@ -15,7 +15,7 @@ import subprocess
 import sys
 from typing import Any
 
-from .GlobalConfig import GlobalConfig, InputEndian
+from .GlobalConfig import GlobalConfig, InputEndian, RomByteOrder
 
 
 def eprint(*args: Any, **kwargs: Any) -> None:
@@ -119,6 +119,58 @@ def wordsToBytes(words_list: list[int]) -> bytes:
 #! deprecated
 beWordsToBytes = wordsToBytes
 
+def swapBytePairs(array_of_bytes: bytes) -> bytes:
+    "Swaps every pair of bytes. A trailing odd byte is left as is"
+    newBytes = bytearray(array_of_bytes)
+    evenEnd = len(newBytes) & ~1
+    newBytes[0:evenEnd:2], newBytes[1:evenEnd:2] = newBytes[1:evenEnd:2], newBytes[0:evenEnd:2]
+    return bytes(newBytes)
+
+def swapWordBytes(array_of_bytes: bytes) -> bytes:
+    "Reverses the bytes of every 32-bit word. Trailing bytes which don't fill a whole word are left as is"
+    newBytes = bytearray(array_of_bytes)
+    wordsEnd = len(newBytes) & ~3
+    for i in range(4):
+        newBytes[i:wordsEnd:4] = array_of_bytes[3-i:wordsEnd:4]
+    return bytes(newBytes)
+
+_romMagics: dict[bytes, RomByteOrder] = {
+    b"\x80\x37\x12\x40": RomByteOrder.NATIVE,
+    b"\x37\x80\x40\x12": RomByteOrder.BYTESWAPPED,
+    b"\x40\x12\x37\x80": RomByteOrder.WORDSWAPPED,
+}
+
+def detectRomByteOrder(array_of_bytes: bytes) -> RomByteOrder|None:
+    """
+    Detects the byte order of an N64 ROM image by looking at the magic at the
+    start of its header.
+
+    Returns `None` if the magic is not recognized.
+    """
+    return _romMagics.get(bytes(array_of_bytes[0:4]))
+
+def normalizeRomBytes(array_of_bytes: bytes, byteOrder: RomByteOrder|None=None) -> bytearray:
+    """
+    Converts a byteswapped (`.v64`) or wordswapped (`.n64`) ROM image to the
+    native byte order (`.z64`).
+
+    If `byteOrder` is `None` then `GlobalConfig.ROM_BYTE_ORDER` is used. If it
+    is `RomByteOrder.AUTO` then the byte order is detected from the ROM header
+    magic, and the image is returned unchanged if it can't be detected.
+    """
+    if byteOrder is None:
+        byteOrder = GlobalConfig.ROM_BYTE_ORDER
+
+    if byteOrder == RomByteOrder.AUTO:
+        detected = detectRomByteOrder(array_of_bytes)
+        byteOrder = detected if detected is not None else RomByteOrder.NATIVE
+
+    if byteOrder == RomByteOrder.BYTESWAPPED:
+        return bytearray(swapBytePairs(array_of_bytes))
+    if byteOrder == RomByteOrder.WORDSWAPPED:
+        return bytearray(swapWordBytes(array_of_bytes))
+    return bytearray(array_of_bytes)
+
 def wordToFloat(word: int) -> float:
     b = struct.pack('>I', word)
     return float(struct.unpack('>f', b)[0])
@@ -430,6 +482,42 @@ def decodeString(buf: bytes, offset: int, stringEncoding: str) -> tuple[list[str
     return result, i
 
 
+def asmIndentation(amount: int) -> str:
+    """
+    Returns the whitespace used to indent a line by `amount` columns.
+
+    If `ASM_USE_TABS` is enabled the indentation is rounded up to whole tabs.
+    """
+    if amount <= 0:
+        return ""
+    if GlobalConfig.ASM_USE_TABS:
+        return "\t" * ((amount + GlobalConfig.ASM_TAB_WIDTH - 1) // GlobalConfig.ASM_TAB_WIDTH)
+    return " " * amount
+
+def asmLineWidth(text: str) -> int:
+    "The width of the last line of `text`, expanding tabs to `ASM_TAB_WIDTH`"
+    lastLine = text.rsplit("\n", 1)[-1]
+    return len(lastLine.expandtabs(GlobalConfig.ASM_TAB_WIDTH))
+
+def asmPadToColumn(text: str, column: int, minSpacing: int=1) -> str:
+    """
+    Appends whitespace to `text` so whatever gets appended afterwards starts
+    at the given column of the last line.
+
+    At least `minSpacing` columns of whitespace are appended, even if `text`
+    already goes past the given column.
+    """
+    width = asmLineWidth(text)
+    target = max(column, width + minSpacing)
+    if GlobalConfig.ASM_USE_TABS:
+        tabWidth = GlobalConfig.ASM_TAB_WIDTH
+        while width < target:
+            text += "\t"
+            width = (width // tabWidth + 1) * tabWidth
+        return text
+    return text + " " * (target - width)
+
+
 # Copied from argparse.py to be able to use it on Python versions < 3.9
 class BooleanOptionalAction(argparse.Action):
     def __init__(self, # type: ignore[no-untyped-def]
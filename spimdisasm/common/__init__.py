@@ -7,24 +7,71 @@ from .SortedDict import SortedDict as SortedDict
 from .CompilerConfig import CompilerProperties as CompilerProperties
 from .CompilerConfig import Compiler as Compiler
 from .CompilerConfig import compilerOptions as compilerOptions
+from .Charmap import Charmap as Charmap
 from .GlobalConfig import GlobalConfig as GlobalConfig
 from .GlobalConfig import InputEndian as InputEndian
+from .GlobalConfig import RomByteOrder as RomByteOrder
 from .GlobalConfig import Abi as Abi
 from .GlobalConfig import ArchLevel as ArchLevel
 from .GlobalConfig import InputFileType as InputFileType
+from .GlobalConfig import SharedRodataMigrationPolicy as SharedRodataMigrationPolicy
+from .GlobalConfig import BranchLabelStyle as BranchLabelStyle
+from .GlobalConfig import UnpairedHiPolicy as UnpairedHiPolicy
+from .GlobalConfig import JumptableLabelOutsideFunctionPolicy as JumptableLabelOutsideFunctionPolicy
+from .GlobalConfig import StringGuesserCharacterClass as StringGuesserCharacterClass
+from .GlobalConfig import AsmAssemblerModesPlacement as AsmAssemblerModesPlacement
+from .GlobalConfig import AsmLabelConventions as AsmLabelConventions
+from .SettingsBundle import SettingsBundle as SettingsBundle
+from .SettingsBundle import settingsPresets as settingsPresets
 from .FileSectionType import FileSectionType as FileSectionType
 from .FileSectionType import FileSections_ListBasic as FileSections_ListBasic
 from .FileSectionType import FileSections_ListAll as FileSections_ListAll
+from .Errors import SectionCreationError as SectionCreationError
+from .Errors import SegmentCreationError as SegmentCreationError
+from .Errors import SegmentOverlapError as SegmentOverlapError
 from .ContextSymbols import SymbolSpecialType as SymbolSpecialType
 from .ContextSymbols import ContextSymbol as ContextSymbol
+from .ContextSymbols import SymbolReference as SymbolReference
+from .ContextSymbols import ReferencedAddress as ReferencedAddress
 from .ContextSymbols import gKnownTypes as gKnownTypes
+from .ContextSymbols import gStructSizes as gStructSizes
 from .SymbolsSegment import SymbolsSegment as SymbolsSegment
 from .Context import Context as Context
+from .AddressRange import AddressRange as AddressRange
+from .AddressRange import RomVramMapping as RomVramMapping
+from .AddressRange import PiecewiseRomVramMapping as PiecewiseRomVramMapping
+from .Context import StringPoolEntry as StringPoolEntry
+from .Context import SourceLocation as SourceLocation
+from .Context import FunctionContinuation as FunctionContinuation
+from .Context import AddressInfo as AddressInfo
+from .Context import UnknownSegmentPlaceholder as UnknownSegmentPlaceholder
+from .Context import UnreferencedFunction as UnreferencedFunction
+from .Context import ContextMergeConflict as ContextMergeConflict
+from .Diagnostics import DiagnosticKind as DiagnosticKind
+from .Diagnostics import Diagnostic as Diagnostic
 from .FileSplitFormat import FileSplitFormat as FileSplitFormat
 from .FileSplitFormat import FileSplitEntry as FileSplitEntry
+from .OverlayTable import OverlayTableLayout as OverlayTableLayout
+from .OverlayTable import OverlayTableEntry as OverlayTableEntry
+from .OverlayTable import parseOverlayTable as parseOverlayTable
 from .ElementBase import ElementBase as ElementBase
 from .GpAccesses import GlobalOffsetTable as GlobalOffsetTable
 from .OrderedEnum import OrderedEnum as OrderedEnum
 from .Relocation import RelocType as RelocType
 from .Relocation import RelocationInfo as RelocationInfo
 from .Relocation import RelocationStaticReference as RelocationStaticReference
+from .Relocation import joinAddendHiLo as joinAddendHiLo
+from .Relocation import getImplicitAddend as getImplicitAddend
+from .Relocation import getExplicitAddend as getExplicitAddend
+from .InstructionOverride import InstructionOverride as InstructionOverride
+from .SymbolCreationVeto import SymbolCreationReason as SymbolCreationReason
+from .SymbolCreationVeto import SymbolCreationVetoCallback as SymbolCreationVetoCallback
+from .ProgressEvents import ProgressPhase as ProgressPhase
+from .ProgressEvents import ProgressEvent as ProgressEvent
+from .ProgressEvents import ProgressCallback as ProgressCallback
+from .DeterminismAudit import ContextSnapshot as ContextSnapshot
+from .DeterminismAudit import DeterminismDivergence as DeterminismDivergence
+from .DeterminismAudit import snapshotContext as snapshotContext
+from .DeterminismAudit import compareSnapshots as compareSnapshots
+from .DeterminismAudit import auditDeterminism as auditDeterminism
+from .DeterminismAudit import auditDeterminismInSubprocesses as auditDeterminismInSubprocesses
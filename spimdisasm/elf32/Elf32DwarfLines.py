@@ -0,0 +1,200 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import struct
+
+from .. import common
+
+
+@dataclasses.dataclass
+class Elf32DwarfLineRow:
+    vram: int
+    fileName: str
+    line: int
+    endSequence: bool
+    "Marks the first address after the end of a sequence of instructions"
+
+
+def _readUleb128(array_of_bytes: bytes, offset: int) -> tuple[int, int]:
+    result = 0
+    shift = 0
+    while True:
+        value = array_of_bytes[offset]
+        offset += 1
+        result |= (value & 0x7F) << shift
+        shift += 7
+        if value & 0x80 == 0:
+            return result, offset
+
+def _readSleb128(array_of_bytes: bytes, offset: int) -> tuple[int, int]:
+    result = 0
+    shift = 0
+    while True:
+        value = array_of_bytes[offset]
+        offset += 1
+        result |= (value & 0x7F) << shift
+        shift += 7
+        if value & 0x80 == 0:
+            if value & 0x40:
+                result -= 1 << shift
+            return result, offset
+
+def _readString(array_of_bytes: bytes, offset: int) -> tuple[str, int]:
+    end = array_of_bytes.index(b"\0", offset)
+    return array_of_bytes[offset:end].decode("utf-8", errors="replace"), end + 1
+
+
+class Elf32DwarfLines:
+    """
+    Reads the line number programs of a DWARF `.debug_line` section.
+
+    Only DWARF versions 2 to 4 using the 32-bit format are supported, other
+    units are skipped.
+
+    Relocations are not applied, so the addresses of relocatable objects are
+    usually relative to the start of their section.
+    """
+
+    # Standard opcodes
+    DW_LNS_copy = 1
+    DW_LNS_advance_pc = 2
+    DW_LNS_advance_line = 3
+    DW_LNS_set_file = 4
+    DW_LNS_const_add_pc = 8
+    DW_LNS_fixed_advance_pc = 9
+
+    # Extended opcodes
+    DW_LNE_end_sequence = 1
+    DW_LNE_set_address = 2
+    DW_LNE_define_file = 3
+
+    def __init__(self, array_of_bytes: bytes, offset: int, rawSize: int) -> None:
+        self.rows: list[Elf32DwarfLineRow] = list()
+        self.unsupportedVersions: set[int] = set()
+        "Versions of the skipped units"
+
+        self.endian = common.GlobalConfig.ENDIAN.toFormatString()
+
+        sectionEnd = offset + rawSize
+        unitOffset = offset
+        while unitOffset + 4 <= sectionEnd:
+            unitLength = struct.unpack_from(self.endian + "I", array_of_bytes, unitOffset)[0]
+            if unitLength >= 0xFFFFFFF0:
+                common.Utils.eprint("Warning: 64-bit DWARF line tables are not supported")
+                break
+            unitEnd = unitOffset + 4 + unitLength
+            self._parseUnit(array_of_bytes, unitOffset + 4, unitEnd)
+            unitOffset = unitEnd
+
+        if len(self.unsupportedVersions) > 0:
+            versions = ", ".join(str(x) for x in sorted(self.unsupportedVersions))
+            common.Utils.eprint(f"Warning: Skipped DWARF line tables of unsupported versions: {versions}")
+
+    def _parseUnit(self, array_of_bytes: bytes, offset: int, unitEnd: int) -> None:
+        version = struct.unpack_from(self.endian + "H", array_of_bytes, offset)[0]
+        if version < 2 or version > 4:
+            self.unsupportedVersions.add(version)
+            return
+        headerLength = struct.unpack_from(self.endian + "I", array_of_bytes, offset + 2)[0]
+        programStart = offset + 6 + headerLength
+        offset += 6
+
+        minInstrLength = array_of_bytes[offset]
+        offset += 1
+        if version >= 4:
+            # maximum_operations_per_instruction, only meaningful for VLIW
+            offset += 1
+        # default_is_stmt is skipped, every row is used regardless of being a recommended breakpoint location
+        lineBase = struct.unpack_from("b", array_of_bytes, offset + 1)[0]
+        lineRange = array_of_bytes[offset + 2]
+        opcodeBase = array_of_bytes[offset + 3]
+        offset += 4
+        standardOpcodeLengths = list(array_of_bytes[offset:offset + opcodeBase - 1])
+        offset += opcodeBase - 1
+
+        directories = [""]
+        while array_of_bytes[offset] != 0:
+            directory, offset = _readString(array_of_bytes, offset)
+            directories.append(directory)
+        offset += 1
+
+        files = [""]
+        while array_of_bytes[offset] != 0:
+            fileName, offset = _readString(array_of_bytes, offset)
+            directoryIndex, offset = _readUleb128(array_of_bytes, offset)
+            _, offset = _readUleb128(array_of_bytes, offset)
+            _, offset = _readUleb128(array_of_bytes, offset)
+            files.append(self._joinPath(directories, directoryIndex, fileName))
+
+        offset = programStart
+        address = 0
+        fileIndex = 1
+        line = 1
+
+        def emitRow(endSequence: bool) -> None:
+            fileName = files[fileIndex] if fileIndex < len(files) else f"<file {fileIndex}>"
+            self.rows.append(Elf32DwarfLineRow(address, fileName, line, endSequence))
+
+        while offset < unitEnd:
+            opcode = array_of_bytes[offset]
+            offset += 1
+
+            if opcode >= opcodeBase:
+                # Special opcode
+                adjusted = opcode - opcodeBase
+                address += (adjusted // lineRange) * minInstrLength
+                line += lineBase + adjusted % lineRange
+                emitRow(False)
+            elif opcode == 0:
+                length, offset = _readUleb128(array_of_bytes, offset)
+                extendedEnd = offset + length
+                extendedOpcode = array_of_bytes[offset]
+                if extendedOpcode == self.DW_LNE_end_sequence:
+                    emitRow(True)
+                    address = 0
+                    fileIndex = 1
+                    line = 1
+                elif extendedOpcode == self.DW_LNE_set_address:
+                    address = struct.unpack_from(self.endian + "I", array_of_bytes, offset + 1)[0]
+                elif extendedOpcode == self.DW_LNE_define_file:
+                    fileName, fileOffset = _readString(array_of_bytes, offset + 1)
+                    directoryIndex, _ = _readUleb128(array_of_bytes, fileOffset)
+                    files.append(self._joinPath(directories, directoryIndex, fileName))
+                offset = extendedEnd
+            elif opcode == self.DW_LNS_copy:
+                emitRow(False)
+            elif opcode == self.DW_LNS_advance_pc:
+                value, offset = _readUleb128(array_of_bytes, offset)
+                address += value * minInstrLength
+            elif opcode == self.DW_LNS_advance_line:
+                value, offset = _readSleb128(array_of_bytes, offset)
+                line += value
+            elif opcode == self.DW_LNS_set_file:
+                fileIndex, offset = _readUleb128(array_of_bytes, offset)
+            elif opcode == self.DW_LNS_const_add_pc:
+                address += ((255 - opcodeBase) // lineRange) * minInstrLength
+            elif opcode == self.DW_LNS_fixed_advance_pc:
+                address += struct.unpack_from(self.endian + "H", array_of_bytes, offset)[0]
+                offset += 2
+            else:
+                # Skip the operands of the opcodes which don't affect the address nor the line
+                for _ in range(standardOpcodeLengths[opcode - 1]):
+                    _, offset = _readUleb128(array_of_bytes, offset)
+
+    @staticmethod
+    def _joinPath(directories: list[str], directoryIndex: int, fileName: str) -> str:
+        if fileName.startswith("/") or directoryIndex == 0 or directoryIndex >= len(directories):
+            return fileName
+        return f"{directories[directoryIndex]}/{fileName}"
+
+    def insertIntoContext(self, context: common.Context) -> None:
+        "Registers the source line of every row of the line tables"
+        for row in self.rows:
+            if row.endSequence:
+                continue
+            context.addSourceLine(row.vram, row.fileName, row.line)
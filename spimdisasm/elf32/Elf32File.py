@@ -5,6 +5,7 @@
 
 from __future__ import annotations
 
+import struct
 from typing import Callable
 
 from .. import common
@@ -18,6 +19,8 @@ from .Elf32SectionHeaders import Elf32SectionHeaders, Elf32SectionHeaderEntry
 from .Elf32StringTable import Elf32StringTable
 from .Elf32Syms import Elf32Syms
 from .Elf32Rels import Elf32Rels
+from .Elf32Mdebug import Elf32Mdebug
+from .Elf32DwarfLines import Elf32DwarfLines
 
 
 class Elf32File:
@@ -67,6 +70,11 @@ class Elf32File:
 
         self.reginfo: Elf32RegInfo | None = None
 
+        self.mdebugSection: Elf32SectionHeaderEntry | None = None
+        "Only parsed on demand by `parseMdebug`"
+        self.debugLineSection: Elf32SectionHeaderEntry | None = None
+        "Only parsed on demand by `parseDebugLine`"
+
         self.sectionHeaders = Elf32SectionHeaders(array_of_bytes, self.header.shoff, self.header.shnum)
 
         shstrtabSectionEntry = self.sectionHeaders.sections[self.header.shstrndx]
@@ -169,6 +177,27 @@ class Elf32File:
             common.GlobalConfig.ARCHLEVEL = common.ArchLevel.MIPS64R2
 
 
+    def parseMdebug(self, array_of_bytes: bytes) -> Elf32Mdebug|None:
+        "Parses the `.mdebug` section, if any. Returns `None` and prints a warning if it can't be parsed"
+        if self.mdebugSection is None:
+            return None
+        try:
+            return Elf32Mdebug(array_of_bytes, self.mdebugSection.offset, self.mdebugSection.size)
+        except (IndexError, ValueError, struct.error) as e:
+            common.Utils.eprint(f"Warning: Unable to parse the .mdebug section, ignoring it ({e})")
+            return None
+
+    def parseDebugLine(self, array_of_bytes: bytes) -> Elf32DwarfLines|None:
+        "Parses the `.debug_line` section, if any. Returns `None` and prints a warning if it can't be parsed"
+        if self.debugLineSection is None:
+            return None
+        try:
+            return Elf32DwarfLines(array_of_bytes, self.debugLineSection.offset, self.debugLineSection.size)
+        except (IndexError, ValueError, struct.error) as e:
+            common.Utils.eprint(f"Warning: Unable to parse the .debug_line section, ignoring it ({e})")
+            return None
+
+
     def _processSection_NULL(self, array_of_bytes: bytes, entry: Elf32SectionHeaderEntry, sectionEntryName: str) -> None:
         pass
 
@@ -224,6 +253,11 @@ class Elf32File:
         elif sectionEntryName == ".pdr":
             # Debugging section, dunno what it actually has
             pass
+        elif sectionEntryName == ".debug_line":
+            self.debugLineSection = entry
+        elif sectionEntryName.startswith(".debug_"):
+            # Other DWARF sections
+            common.Utils.printVerbose(f"Unhandled PROGBITS found: '{sectionEntryName}'")
         elif not common.GlobalConfig.QUIET:
             common.Utils.eprint(f"Unhandled PROGBITS found: '{sectionEntryName}', flags: {flags}, unknownFlags: {unknownFlags}\n")
 
@@ -244,8 +278,11 @@ class Elf32File:
             common.Utils.eprint("Unhandled STRTAB found: ", sectionEntryName, entry, "\n")
 
     def _processSection_RELA(self, array_of_bytes: bytes, entry: Elf32SectionHeaderEntry, sectionEntryName: str) -> None:
-        # ?
-        pass
+        if sectionEntryName.startswith(".rela"):
+            sectName = sectionEntryName[5:]
+            self.relPerName[sectName] = Elf32Rels(sectionEntryName, array_of_bytes, entry.offset, entry.size, isRela=True)
+        else:
+            common.Utils.eprint("Unhandled RELA found: ", sectionEntryName, entry, "\n")
 
     def _processSection_HASH(self, array_of_bytes: bytes, entry: Elf32SectionHeaderEntry, sectionEntryName: str) -> None:
         # ?
@@ -317,8 +354,10 @@ class Elf32File:
         pass
 
     def _processSection_MIPS_DEBUG(self, array_of_bytes: bytes, entry: Elf32SectionHeaderEntry, sectionEntryName: str) -> None:
-        # ?
-        pass
+        if sectionEntryName == ".mdebug":
+            self.mdebugSection = entry
+        elif common.GlobalConfig.VERBOSE:
+            common.Utils.eprint("Unhandled MIPS_DEBUG found: ", sectionEntryName, entry, "\n")
 
     def _processSection_MIPS_REGINFO(self, array_of_bytes: bytes, entry: Elf32SectionHeaderEntry, sectionEntryName: str) -> None:
         if sectionEntryName == ".reginfo":
@@ -344,7 +383,7 @@ class Elf32File:
         Elf32SectionHeaderType.PROGBITS.value: _processSection_PROGBITS,
         Elf32SectionHeaderType.SYMTAB.value: _processSection_SYMTAB,
         Elf32SectionHeaderType.STRTAB.value: _processSection_STRTAB,
-        # Elf32SectionHeaderType.RELA.value: _processSection_RELA,
+        Elf32SectionHeaderType.RELA.value: _processSection_RELA,
         Elf32SectionHeaderType.HASH.value: _processSection_HASH,
         Elf32SectionHeaderType.DYNAMIC.value: _processSection_DYNAMIC,
         # Elf32SectionHeaderType.NOTE.value: _processSection_NOTE,
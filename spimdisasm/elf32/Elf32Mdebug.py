@@ -0,0 +1,150 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import struct
+
+from .. import common
+
+
+MDEBUG_MAGIC = 0x7009
+
+
+@dataclasses.dataclass
+class Elf32MdebugProcedure:
+    name: str
+    vram: int
+    size: int
+    "Size in bytes, computed from the amount of instructions described by the line numbers"
+    fileName: str
+    lines: list[tuple[int, int]]
+    "Pairs of (vram, line) for every instruction which starts a new line"
+
+
+class Elf32Mdebug:
+    """
+    Reads the procedures and line numbers from the ECOFF symbolic debugging
+    information (`.mdebug`), as emitted by IDO and other IRIX compilers.
+
+    The offsets of the symbolic header are relative to the start of the file.
+    """
+
+    def __init__(self, array_of_bytes: bytes, offset: int, rawSize: int) -> None:
+        self.procedures: list[Elf32MdebugProcedure] = list()
+
+        endian = common.GlobalConfig.ENDIAN.toFormatString()
+
+        # HDRR (symbolic header)
+        magic, _vstamp = struct.unpack_from(endian + "2H", array_of_bytes, offset)
+        if magic != MDEBUG_MAGIC:
+            common.Utils.eprint(f"Warning: Unknown .mdebug magic 0x{magic:04X}")
+            return
+
+        (
+            _ilineMax, _cbLine, cbLineOffset,
+            _idnMax, _cbDnOffset,
+            _ipdMax, cbPdOffset,
+            _isymMax, cbSymOffset,
+            _ioptMax, _cbOptOffset,
+            _iauxMax, _cbAuxOffset,
+            _issMax, cbSsOffset,
+            _issExtMax, _cbSsExtOffset,
+            ifdMax, cbFdOffset,
+        ) = struct.unpack_from(endian + "19i", array_of_bytes, offset + 0x04)
+
+        for fdrIndex in range(ifdMax):
+            # FDR (file descriptor)
+            fdrOffset = cbFdOffset + fdrIndex * 0x48
+            fdrAdr, rss, issBase, _cbSs, isymBase = struct.unpack_from(endian + "I4i", array_of_bytes, fdrOffset)
+            ipdFirst, cpd = struct.unpack_from(endian + "2H", array_of_bytes, fdrOffset + 0x28)
+            fdrCbLineOffset, fdrCbLine = struct.unpack_from(endian + "2i", array_of_bytes, fdrOffset + 0x40)
+
+            fileName = self._readString(array_of_bytes, cbSsOffset + issBase + rss)
+
+            pdrs: list[tuple[int, int, int, int, int]] = []
+            for pdrIndex in range(ipdFirst, ipdFirst + cpd):
+                # PDR (procedure descriptor)
+                pdrOffset = cbPdOffset + pdrIndex * 0x34
+                pdrAdr, isym, iline = struct.unpack_from(endian + "I2i", array_of_bytes, pdrOffset)
+                lnLow, _lnHigh, pdrCbLineOffset = struct.unpack_from(endian + "3i", array_of_bytes, pdrOffset + 0x28)
+                pdrs.append((pdrAdr, isym, iline, lnLow, pdrCbLineOffset))
+
+            if len(pdrs) == 0:
+                continue
+
+            # The address of each procedure may be either absolute or relative to the file, so use the lowest one as the base
+            lowestPdrAdr = min(pdr[0] for pdr in pdrs)
+
+            for i, (pdrAdr, isym, iline, lnLow, pdrCbLineOffset) in enumerate(pdrs):
+                # SYMR (local symbol)
+                iss = struct.unpack_from(endian + "i", array_of_bytes, cbSymOffset + (isymBase + isym) * 0x0C)[0]
+                name = self._readString(array_of_bytes, cbSsOffset + issBase + iss)
+
+                vram = fdrAdr + pdrAdr - lowestPdrAdr
+                lines: list[tuple[int, int]] = []
+                size = 0
+                if iline != -1 and lnLow != -1:
+                    lineStart = cbLineOffset + fdrCbLineOffset + pdrCbLineOffset
+                    if i + 1 < len(pdrs):
+                        lineEnd = cbLineOffset + fdrCbLineOffset + pdrs[i+1][4]
+                    else:
+                        lineEnd = cbLineOffset + fdrCbLineOffset + fdrCbLine
+                    lines, size = self._decodeLines(array_of_bytes, lineStart, lineEnd, vram, lnLow)
+
+                self.procedures.append(Elf32MdebugProcedure(name, vram, size, fileName, lines))
+
+    @staticmethod
+    def _readString(array_of_bytes: bytes, offset: int) -> str:
+        end = array_of_bytes.find(b"\0", offset)
+        if end < 0:
+            end = len(array_of_bytes)
+        return array_of_bytes[offset:end].decode("ascii", errors="replace")
+
+    @staticmethod
+    def _decodeLines(array_of_bytes: bytes, start: int, end: int, vram: int, lnLow: int) -> tuple[list[tuple[int, int]], int]:
+        """
+        Each byte has the line delta on its upper nibble and the amount of
+        instructions minus one on its lower nibble. A line delta of -8 means
+        the actual delta is stored on the next two bytes.
+        """
+        lines: list[tuple[int, int]] = []
+        line = lnLow
+        instructionCount = 0
+        i = start
+        while i < end:
+            value = array_of_bytes[i]
+            i += 1
+            count = (value & 0xF) + 1
+            delta = value >> 4
+            if delta >= 8:
+                delta -= 16
+            if delta == -8:
+                delta = struct.unpack_from(">h", array_of_bytes, i)[0]
+                i += 2
+            line += delta
+
+            address = vram + instructionCount * 4
+            if len(lines) == 0 or lines[-1][1] != line:
+                lines.append((address, line))
+            instructionCount += count
+        return lines, instructionCount * 4
+
+    def insertIntoContext(self, context: common.Context) -> None:
+        """
+        Names the functions described by this debug information and sets their
+        sizes, unless they were already declared, and registers the source line
+        of their instructions.
+        """
+        for procedure in self.procedures:
+            contextSym = context.globalSegment.addFunction(procedure.vram)
+            if contextSym.name is None and procedure.name != "":
+                contextSym.name = procedure.name
+                contextSym.isUserDeclared = True
+            contextSym.setSizeIfUnset(procedure.size)
+
+            for vram, line in procedure.lines:
+                context.addSourceLine(vram, procedure.fileName, line)
@@ -17,6 +17,9 @@ class Elf32RelEntry:
     offset: int  # address  # 0x00
     info:   int  # word     # 0x04
                             # 0x08
+    # Only present in `.rela` sections. `None` means the addend is implicit and stored in the relocated field
+    addend: int|None = None  # sword  # 0x08
+                                      # 0x0C
 
     @property
     def rSym(self) -> int:
@@ -33,16 +36,28 @@ class Elf32RelEntry:
 
         return Elf32RelEntry(*unpacked)
 
+    @staticmethod
+    def fromBytearrayRela(array_of_bytes: bytes, offset: int = 0) -> Elf32RelEntry:
+        entryFormat = common.GlobalConfig.ENDIAN.toFormatString() + "IIi"
+        unpacked = struct.unpack_from(entryFormat, array_of_bytes, offset)
+
+        return Elf32RelEntry(*unpacked)
+
 
 class Elf32Rels:
-    def __init__(self, sectionName: str, array_of_bytes: bytes, offset: int, rawSize: int) -> None:
+    def __init__(self, sectionName: str, array_of_bytes: bytes, offset: int, rawSize: int, isRela: bool=False) -> None:
         self.sectionName = sectionName
         self.relocations: list[Elf32RelEntry] = list()
         self.offset: int = offset
         self.rawSize: int = rawSize
+        self.isRela: bool = isRela
 
-        for i in range(rawSize // 0x08):
-            entry = Elf32RelEntry.fromBytearray(array_of_bytes, offset + i*0x08)
+        entrySize = 0x0C if isRela else 0x08
+        for i in range(rawSize // entrySize):
+            if isRela:
+                entry = Elf32RelEntry.fromBytearrayRela(array_of_bytes, offset + i*entrySize)
+            else:
+                entry = Elf32RelEntry.fromBytearray(array_of_bytes, offset + i*entrySize)
             self.relocations.append(entry)
 
     def __iter__(self) -> Generator[Elf32RelEntry, None, None]:
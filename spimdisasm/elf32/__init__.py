@@ -27,6 +27,10 @@ from .Elf32Syms import Elf32Syms as Elf32Syms
 from .Elf32Syms import Elf32SymEntry as Elf32SymEntry
 from .Elf32Rels import Elf32Rels as Elf32Rels
 from .Elf32Rels import Elf32RelEntry as Elf32RelEntry
+from .Elf32Mdebug import Elf32Mdebug as Elf32Mdebug
+from .Elf32Mdebug import Elf32MdebugProcedure as Elf32MdebugProcedure
+from .Elf32DwarfLines import Elf32DwarfLines as Elf32DwarfLines
+from .Elf32DwarfLines import Elf32DwarfLineRow as Elf32DwarfLineRow
 
 from .Elf32File import Elf32File as Elf32File
 
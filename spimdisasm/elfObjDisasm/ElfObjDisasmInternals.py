@@ -37,6 +37,8 @@ def addOptionsToParser(parser: argparse.ArgumentParser) -> argparse.ArgumentPars
     parser.add_argument("--instr-category", help="The instruction category to use when disassembling every passed instruction. Defaults to 'cpu'", choices=["cpu", "rsp", "r3000gte", "r4000allegrex", "r5900"])
 
     parser.add_argument("--function-info", help="Specifies a path where to output a csvs sumary file of every analyzed function", metavar="PATH")
+    parser.add_argument("--debug-info", help="Use the debug information of the elf (.mdebug or DWARF line tables), if present, to name functions, set their sizes and emit comments with the source line of the instructions", action="store_true")
+    parser.add_argument("--json-ir", help="Specifies a path where to output a JSON representation of every analyzed section, including its symbols, relocations, instructions and data words", metavar="PATH")
 
 
     readelfOptions = parser.add_argument_group("readelf-like flags")
@@ -138,6 +140,14 @@ def processSection(
         sectionType: common.FileSectionType,
         sectionClass: type[mips.sections.SectionText]|type[mips.sections.SectionData]|type[mips.sections.SectionRodata]|type[mips.sections.SectionBss]
     ) -> None:
+    if common.FileSectionType.fromSmallStr(sectionName) != common.FileSectionType.Invalid:
+        if sectionClass == mips.sections.SectionData:
+            sectionClass = mips.sections.SectionSdata
+        elif sectionClass == mips.sections.SectionRodata:
+            sectionClass = mips.sections.SectionSrodata
+        elif sectionClass == mips.sections.SectionBss:
+            sectionClass = mips.sections.SectionSbss
+
     outputFilePath = getOutputPath(inputPath, textOutput, dataOutput, sectionType, sectionName)
 
     vromStart = sectionEntry.offset
@@ -278,6 +288,14 @@ def insertSymtabIntoContext(context: common.Context, symbolTable: elf32.Elf32Sym
         if symEntry.shndx == 0:
             continue
 
+        if symEntry.shndx == elf32.Elf32SectionHeaderNumber.MIPS_ACOMMON.value and elfFile.header.type != elf32.Elf32ObjectFileType.REL.value:
+            # Allocated common symbols have their final address as their value
+            if symEntry.value != 0:
+                contextSym = addContextSymFromSymEntry(context, symEntry, symEntry.value, symName)
+                if contextSym is not None:
+                    contextSym.isCommon = True
+            continue
+
         sectHeaderEntry = elfFile.sectionHeaders[symEntry.shndx]
         if sectHeaderEntry is None:
             continue
@@ -326,13 +344,42 @@ def insertGotIntoContext(context: common.Context, got: elf32.Elf32GlobalOffsetTa
         gotIndex += 1
 
 
+def getImplicitRelocAddend(relocs: list[elf32.Elf32RelEntry], relIndex: int, subSegment: mips.sections.SectionBase) -> int:
+    rel = relocs[relIndex]
+    relocType = common.RelocType.fromValue(rel.rType)
+    if relocType is None:
+        return 0
+
+    wordIndex = rel.offset // 4
+    if wordIndex >= len(subSegment.words):
+        return 0
+    word = subSegment.words[wordIndex]
+
+    # The addend of a %hi/%lo pair is split among both instructions, so we need to look for the other half
+    pairedWord: int|None = None
+    if relocType == common.RelocType.MIPS_HI16:
+        for other in relocs[relIndex+1:]:
+            if other.rType == common.RelocType.MIPS_LO16.value and other.rSym == rel.rSym:
+                if other.offset // 4 < len(subSegment.words):
+                    pairedWord = subSegment.words[other.offset // 4]
+                break
+    elif relocType == common.RelocType.MIPS_LO16:
+        for other in reversed(relocs[:relIndex]):
+            if other.rType == common.RelocType.MIPS_HI16.value and other.rSym == rel.rSym:
+                if other.offset // 4 < len(subSegment.words):
+                    pairedWord = subSegment.words[other.offset // 4]
+                break
+
+    return common.getImplicitAddend(relocType, word, pairedWord)
+
+
 def injectAllElfSymbols(context: common.Context, elfFile: elf32.Elf32File, processedSegments: dict[common.FileSectionType, list[mips.sections.SectionBase]], sectionsPerName: dict[str, mips.sections.SectionBase]) -> None:
     if elfFile.symtab is not None and elfFile.strtab is not None:
         # Inject symbols from the reloc table referenced in each section
         if elfFile.header.type == elf32.Elf32ObjectFileType.REL.value:
             for sectionName, relocs in elfFile.relPerName.items():
                 subSegment = sectionsPerName.get(sectionName, None)
-                for rel in relocs:
+                for relIndex, rel in enumerate(relocs.relocations):
                     symbolEntry = elfFile.symtab[rel.rSym]
                     symbolName = elfFile.strtab[symbolEntry.name]
 
@@ -345,7 +392,15 @@ def injectAllElfSymbols(context: common.Context, elfFile: elf32.Elf32File, proce
                         continue
 
                     relocVrom = subSegment.vromStart + rel.offset
-                    relocInfo = context.addGlobalReloc(relocVrom, common.RelocType(rel.rType), symbolName)
+                    relocType = common.RelocType(rel.rType)
+                    if rel.addend is not None:
+                        addend = common.getExplicitAddend(relocType, rel.addend)
+                    else:
+                        addend = 0
+                        if symbolEntry.stType != elf32.Elf32SymbolTableType.SECTION.value:
+                            # Static references already read the offset from the relocated word itself
+                            addend = getImplicitRelocAddend(relocs.relocations, relIndex, subSegment)
+                    relocInfo = context.addGlobalReloc(relocVrom, relocType, symbolName, addend)
                     if symbolEntry.stType == elf32.Elf32SymbolTableType.SECTION.value:
                         sectionEntry = elfFile.sectionHeaders[symbolEntry.shndx]
                         assert sectionEntry is not None, rel
@@ -366,6 +421,15 @@ def injectAllElfSymbols(context: common.Context, elfFile: elf32.Elf32File, proce
         insertGotIntoContext(context, elfFile.got, elfFile.dynstr)
     return
 
+def insertDebugInfoIntoContext(context: common.Context, elfFile: elf32.Elf32File, array_of_bytes: bytes) -> None:
+    mdebug = elfFile.parseMdebug(array_of_bytes)
+    if mdebug is not None:
+        mdebug.insertIntoContext(context)
+    debugLine = elfFile.parseDebugLine(array_of_bytes)
+    if debugLine is not None:
+        debugLine.insertIntoContext(context)
+
+
 def processGlobalOffsetTable(context: common.Context, elfFile: elf32.Elf32File) -> None:
     if elfFile.dynamic is not None:
         common.GlobalConfig.GP_VALUE = elfFile.dynamic.getGpValue()
@@ -444,6 +508,10 @@ def processArguments(args: argparse.Namespace) -> int:
     common.Utils.printQuietless(f"{PROGNAME} {inputPath}: Injecting elf symbols...")
     injectAllElfSymbols(context, elfFile, processedSegments, sectionsPerName)
 
+    if args.debug_info:
+        common.Utils.printQuietless(f"{PROGNAME} {inputPath}: Reading debug information...")
+        insertDebugInfoIntoContext(context, elfFile, array_of_bytes)
+
     processedFilesCount = 0
     for sect in processedSegments.values():
         processedFilesCount += len(sect)
@@ -468,9 +536,28 @@ def processArguments(args: argparse.Namespace) -> int:
         contextPath.parent.mkdir(parents=True, exist_ok=True)
         context.saveContextToFile(contextPath)
 
+    if args.save_c_header is not None:
+        common.Utils.printQuietless(f"{PROGNAME} {inputPath}: Writing C header...")
+        headerPath = Path(args.save_c_header)
+        headerPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveCHeaderToFile(headerPath, common.Context.parseCHeaderTypeMappingArgs(args))
+
+    if args.save_unknown_segment_report is not None:
+        reportPath = Path(args.save_unknown_segment_report)
+        reportPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveUnknownSegmentReportToFile(reportPath)
+
+    if args.save_unreferenced_functions_report is not None:
+        reportPath = Path(args.save_unreferenced_functions_report)
+        reportPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveUnreferencedFunctionsReportToFile(reportPath)
+
     if args.function_info is not None:
         fec.FrontendUtilities.writeFunctionInfoCsv(processedSegments, Path(args.function_info))
 
+    if args.json_ir is not None:
+        mips.writeSectionsIRToFile([x for sectionFiles in processedSegments.values() for x in sectionFiles], Path(args.json_ir))
+
     common.Utils.printQuietless(f"{PROGNAME} {inputPath}: Done!")
 
     return 0
@@ -17,6 +17,7 @@ from .ElfObjDisasmInternals import changeGlobalSegmentRanges as changeGlobalSegm
 from .ElfObjDisasmInternals import insertSymtabIntoContext as insertSymtabIntoContext
 from .ElfObjDisasmInternals import insertDynsymIntoContext as insertDynsymIntoContext
 from .ElfObjDisasmInternals import injectAllElfSymbols as injectAllElfSymbols
+from .ElfObjDisasmInternals import insertDebugInfoIntoContext as insertDebugInfoIntoContext
 from .ElfObjDisasmInternals import processGlobalOffsetTable as processGlobalOffsetTable
 from .ElfObjDisasmInternals import processArguments as processArguments
 from .ElfObjDisasmInternals import addSubparser as addSubparser
@@ -7,6 +7,7 @@ from __future__ import annotations
 
 import argparse
 from pathlib import Path
+import subprocess
 from typing import Callable
 
 import spimdisasm
@@ -30,6 +31,8 @@ def getSplittedSections(context: common.Context, splits: common.FileSplitFormat,
         common.FileSectionType.Data: [],
         common.FileSectionType.Rodata: [],
         common.FileSectionType.Bss: [],
+        common.FileSectionType.Bin: [],
+        common.FileSectionType.Custom: [],
     }
     processedFilesOutputPaths: dict[common.FileSectionType, list[Path]] = {k: [] for k in processedFiles}
 
@@ -44,6 +47,10 @@ def getSplittedSections(context: common.Context, splits: common.FileSplitFormat,
             outputPath = dataOutput
         elif row.section == common.FileSectionType.Reloc:
             outputPath = dataOutput
+        elif row.section == common.FileSectionType.Bin:
+            outputPath = dataOutput
+        elif row.section == common.FileSectionType.Custom:
+            outputPath = dataOutput
         elif row.section == common.FileSectionType.Dummy:
             # Ignore dummy sections
             continue
@@ -93,6 +100,11 @@ def configureProcessedFiles(processedFiles: dict[common.FileSectionType, list[mi
 
 
 def analyzeProcessedFiles(processedFiles: dict[common.FileSectionType, list[mips.sections.SectionBase]], processedFilesOutputPaths: dict[common.FileSectionType, list[Path]], processedFilesCount: int, progressCallback: ProgressCallbackType|None=None) -> None:
+    # The string pool has to be filled before analyzing any function
+    for sectionType in (common.FileSectionType.Data, common.FileSectionType.Rodata):
+        for f in processedFiles.get(sectionType, []):
+            f.registerStringsInPool()
+
     i = 0
     for sectionType, filesInSection in sorted(processedFiles.items()):
         pathLists = processedFilesOutputPaths[sectionType]
@@ -100,10 +112,35 @@ def analyzeProcessedFiles(processedFiles: dict[common.FileSectionType, list[mips
             if progressCallback is not None:
                 filePath = pathLists[fileIndex]
                 progressCallback(i, str(filePath), processedFilesCount)
+            f.context.reportProgress(common.ProgressPhase.SECTION_ANALYSIS, i * 100 / processedFilesCount, segmentName=f.overlayCategory, sectionName=f.getName())
             f.analyze()
             f.printAnalyzisResults()
 
             i += 1
+
+    if common.GlobalConfig.PRUNE_UNREFERENCED_SYMBOLS:
+        for filesInSection in processedFiles.values():
+            for f in filesInSection:
+                f.pruneUnreferencedSymbols()
+
+    if common.GlobalConfig.DETECT_LIBULTRA_STRUCTS:
+        mips.detectLibultraStructs([f for filesInSection in processedFiles.values() for f in filesInSection])
+    if common.GlobalConfig.PROPAGATE_POINTER_TYPES:
+        mips.propagatePointerTypes([f for filesInSection in processedFiles.values() for f in filesInSection])
+    if common.GlobalConfig.INFER_INDIRECT_CALL_TARGETS:
+        mips.inferIndirectCallTargets([f for filesInSection in processedFiles.values() for f in filesInSection])
+    if common.GlobalConfig.SHIFTABLE_OUTPUT:
+        diagnostics = mips.checkShiftability([f for filesInSection in processedFiles.values() for f in filesInSection])
+        for diagnostic in diagnostics:
+            common.Utils.eprint(str(diagnostic))
+        if len(diagnostics) > 0:
+            common.Utils.eprint(f"Found {len(diagnostics)} places which would break if the code is shifted")
+    for textFile in processedFiles.get(common.FileSectionType.Text, []):
+        if isinstance(textFile, mips.sections.SectionText):
+            # Other sections may have added labels to these functions
+            textFile.defineLabels()
+            if common.GlobalConfig.RESOLVE_DISPLAY_REFERENCES:
+                textFile.resolveDisplayReferences()
     return
 
 def progressCallback_analyzeProcessedFiles(i: int, filePath: str, processedFilesCount: int) -> None:
@@ -185,7 +222,15 @@ def migrateFunctions(processedFiles: dict[common.FileSectionType, list[mips.sect
             if progressCallback is not None:
                 progressCallback(i, func.getName(), funcTotal)
 
-            assert isinstance(func, mips.symbols.SymbolFunction)
+            if not isinstance(func, mips.symbols.SymbolFunction):
+                # Data pool, it has no rodata to migrate
+                dataPoolPath = filePath / (func.getName()+ ".s")
+                common.Utils.printVerbose(f"Writing data pool {dataPoolPath}")
+                with dataPoolPath.open("w") as f:
+                    f.write(func.disassemble(isSplittedSymbol=True))
+                i += 1
+                continue
+
             entry = mips.FunctionRodataEntry.getEntryForFuncFromPossibleRodataSections(func, rodataFileList)
 
             for sym in entry.iterRodataSyms():
@@ -227,7 +272,8 @@ def writeFunctionInfoCsv(processedFiles: dict[common.FileSectionType, list[mips.
         f.write("vrom,address,name,file,length,hash of top bits of words,functions called by this function,non-jal function calls,referenced functions\n")
         for textFile in processedFiles.get(common.FileSectionType.Text, []):
             for func in textFile.symbolList:
-                assert isinstance(func, mips.symbols.SymbolFunction)
+                if not isinstance(func, mips.symbols.SymbolFunction):
+                    continue
                 f.write(f"0x{func.vromStart:06X},0x{func.vram:08X},{func.getName()},{textFile.getName()},0x{func.sizew*4:X},")
 
                 bitswordlist = []
@@ -278,6 +324,36 @@ def writeFunctionInfoCsv(processedFiles: dict[common.FileSectionType, list[mips.
             # f.write("\n")
 
 
+def determinismAuditMain(args: argparse.Namespace) -> int:
+    arguments: list[str] = args.arguments
+    if len(arguments) > 0 and arguments[0] == "--":
+        arguments = arguments[1:]
+    if len(arguments) == 0:
+        common.Utils.eprint("ERROR: Missing the cli utility to audit")
+        return 2
+
+    try:
+        divergences = common.auditDeterminismInSubprocesses(arguments, args.hash_seeds)
+    except subprocess.CalledProcessError as e:
+        common.Utils.eprint(f"ERROR: Audited run failed with exit code {e.returncode}")
+        return e.returncode
+    for divergence in divergences:
+        common.Utils.eprint(divergence)
+    if len(divergences) != 0:
+        common.Utils.eprint(f"Found {len(divergences)} nondeterministic divergences")
+        return 1
+    common.Utils.printQuietless(f"No divergences found after {len(args.hash_seeds)} runs")
+    return 0
+
+def addDeterminismAuditSubparser(subparser: argparse._SubParsersAction[argparse.ArgumentParser]) -> None:
+    parser = subparser.add_parser("determinismAudit", help="Runs another cli utility multiple times with different hash seeds and reports any divergence between the generated symbol tables")
+
+    parser.add_argument("--hash-seeds", help="The PYTHONHASHSEED used by each run. Defaults to 0 1", nargs="+", type=int, default=[0, 1], metavar="SEED")
+    parser.add_argument("arguments", help="The cli utility to run followed by its arguments, like `singleFileDisasm rom.z64 asm`", nargs=argparse.REMAINDER)
+
+    parser.set_defaults(func=determinismAuditMain)
+
+
 def cliMain() -> int:
     parser = argparse.ArgumentParser(description="Interface to call any of the spimdisasm's CLI utilities", prog="spimdisasm")
 
@@ -289,6 +365,7 @@ def cliMain() -> int:
     spimdisasm.singleFileDisasm.addSubparser(subparsers)
     spimdisasm.elfObjDisasm.addSubparser(subparsers)
     spimdisasm.rspDisasm.addSubparser(subparsers)
+    addDeterminismAuditSubparser(subparsers)
 
     args = parser.parse_args()
     return int(args.func(args))
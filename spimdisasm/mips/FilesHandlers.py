@@ -51,6 +51,14 @@ def createSectionFromSplitEntry(splitEntry: common.FileSplitEntry, array_of_byte
         f = sections.SectionBss(context, sectionStart, sectionEnd, splitEntry.vram, bssVramEnd, splitEntry.fileName, 0, None)
     elif splitEntry.section == common.FileSectionType.Reloc:
         f = sections.SectionRelocZ64(context, sectionStart, sectionEnd, vram, splitEntry.fileName, array_of_bytes, 0, None)
+    elif splitEntry.section == common.FileSectionType.Bin:
+        f = sections.SectionBin(context, sectionStart, sectionEnd, vram, splitEntry.fileName, array_of_bytes, 0, None)
+    elif splitEntry.section == common.FileSectionType.Custom and splitEntry.customKind is not None:
+        factory = sections.getCustomSectionKindFactory(splitEntry.customKind)
+        if factory is None:
+            common.Utils.eprint(f"Error! Unregistered custom section kind '{splitEntry.customKind}'")
+            exit(-1)
+        f = factory(context, sectionStart, sectionEnd, vram, splitEntry.fileName, array_of_bytes, 0, None, splitEntry.customKind)
     else:
         common.Utils.eprint("Error! Section not set!")
         exit(-1)
@@ -88,6 +96,7 @@ def getRdataAndLateRodataForFunction(func: symbols.SymbolFunction, rodataFileLis
 #! @deprecated: Use the static methods from FunctionRodataEntry instead
 def writeFunctionRodataToFile(f: TextIO, func: symbols.SymbolFunction, rdataList: list[symbols.SymbolBase], lateRodataList: list[symbols.SymbolBase], lateRodataSize: int=0) -> None:
     entry = FunctionRodataEntry(func, rdataList, lateRodataList)
+    entry.checkLateRodataRatio()
     entry.writeToFile(f, writeFunction=False)
 
 #! @deprecated: Use the static methods from FunctionRodataEntry instead
@@ -130,7 +139,8 @@ def writeMigratedFunctionsList(processedSegments: dict[common.FileSectionType, l
     funcs: list[symbols.SymbolFunction] = []
     for section in processedSegments.get(common.FileSectionType.Text, []):
         for func in section.symbolList:
-            assert isinstance(func, symbols.SymbolFunction)
+            if not isinstance(func, symbols.SymbolFunction):
+                continue
             funcs.append(func)
 
             referencedRodata = rodataSymbolsVrams & func.instrAnalyzer.referencedVrams
@@ -39,6 +39,12 @@ class FunctionRodataEntry:
     function: symbols.SymbolFunction | None = None
     rodataSyms: list[symbols.SymbolBase] = dataclasses.field(default_factory=list)
     lateRodataSyms: list[symbols.SymbolBase] = dataclasses.field(default_factory=list)
+    weakRodataRefs: list[symbols.SymbolBase] = dataclasses.field(default_factory=list)
+    """
+    Shared rodata symbols referenced by the function but migrated to another
+    one, which get declared as `.weak` references. See
+    `SharedRodataMigrationPolicy.DUPLICATE_WEAK`.
+    """
     sectionText: str = ".text"
     sectionRodata: str = ".rodata"
     sectionLateRodata: str = ".late_rodata"
@@ -75,15 +81,11 @@ class FunctionRodataEntry:
             # Write the late_rodata
             f.write(f".section {self.sectionLateRodata}{common.GlobalConfig.LINE_ENDS}")
 
-            lateRodataSize = 0
-            for sym in self.lateRodataSyms:
-                lateRodataSize += sym.sizew
+            if not self.lateRodataFitsText() and common.GlobalConfig.ASM_COMMENT:
+                f.write(f"/* Warning: {self._getLateRodataRatioMessage()} */{common.GlobalConfig.LINE_ENDS}")
 
-            if lateRodataSize / len(self.function.instructions) > 1/3:
-                align = 4
-                firstLateRodataVram = self.lateRodataSyms[0].vram
-                if firstLateRodataVram is not None and firstLateRodataVram % 8 == 0:
-                    align = 8
+            align = self.getLateRodataAlignment()
+            if align is not None:
                 f.write(f".late_rodata_alignment {align}{common.GlobalConfig.LINE_ENDS}")
             for sym in self.lateRodataSyms:
                 f.write(sym.disassemble(migrate=True, useGlobalLabel=True, isSplittedSymbol=True))
@@ -94,9 +96,88 @@ class FunctionRodataEntry:
                 f.write(f"{common.GlobalConfig.LINE_ENDS}.section {self.sectionText}{common.GlobalConfig.LINE_ENDS}")
 
             if writeFunction:
+                for sym in self.weakRodataRefs:
+                    f.write(f".weak {sym.getName()}{common.GlobalConfig.LINE_ENDS}")
                 # Write the function itself
                 f.write(disassembledFunction)
 
+    def getLateRodataSize(self) -> int:
+        "Size of the late rodata of this entry, in words"
+        return sum(sym.sizew for sym in self.lateRodataSyms)
+
+    def getLateRodataTextRatio(self) -> float|None:
+        """
+        Returns the ratio between the words of late rodata and the
+        instructions of the function of this entry, or `None` if this entry
+        has no late rodata.
+
+        asm-processor generates the late rodata by emitting 3 instructions
+        per word, so this ratio can't be bigger than 1/3. Emitting a
+        `.late_rodata_alignment` directive allows it to use doubles instead,
+        which doubles the allowed ratio.
+        """
+        if len(self.lateRodataSyms) == 0 or self.function is None:
+            return None
+        instructionsCount = len(self.function.instructions)
+        if instructionsCount == 0:
+            return float("inf")
+        return self.getLateRodataSize() / instructionsCount
+
+    def getLateRodataAlignment(self) -> int|None:
+        """
+        Returns the value of the `.late_rodata_alignment` directive required
+        by asm-processor to fit the late rodata of this entry, or `None` if no
+        directive is needed.
+        """
+        ratio = self.getLateRodataTextRatio()
+        if ratio is None or ratio <= 1/3:
+            return None
+
+        firstLateRodataVram = self.lateRodataSyms[0].vram
+        if firstLateRodataVram % 8 == 0:
+            return 8
+        return 4
+
+    def lateRodataFitsText(self) -> bool:
+        """
+        Returns `False` if the function of this entry is too small for
+        asm-processor to generate its late rodata, even with the
+        `.late_rodata_alignment` directive.
+        """
+        ratio = self.getLateRodataTextRatio()
+        return ratio is None or ratio <= 2/3
+
+    def _getLateRodataRatioMessage(self) -> str:
+        assert self.function is not None
+        return f"late rodata to text ratio is too high ({self.getLateRodataSize()} / {len(self.function.instructions)}), it must be <= 2/3 even with `.late_rodata_alignment`"
+
+    def checkLateRodataRatio(self) -> None:
+        """
+        Reports a `LATE_RODATA_RATIO` diagnostic if the late rodata of this
+        entry can't fit its function (see `lateRodataFitsText`).
+
+        Each function is reported only once, even if its entry is built more
+        than once. See `GlobalConfig.PANIC_LATE_RODATA_RATIO`.
+        """
+        if self.function is None or self.lateRodataFitsText():
+            return
+
+        context = self.function.context
+        for diagnostic in context.getDiagnostics(common.DiagnosticKind.LATE_RODATA_RATIO):
+            if diagnostic.vram == self.function.vram and diagnostic.vromAddress == self.function.vromStart:
+                return
+
+        message = self._getLateRodataRatioMessage()
+        context.addDiagnostic(common.DiagnosticKind.LATE_RODATA_RATIO, message, self.function.vram, self.function.vromStart, self.function.getName())
+
+        warningMessage = f"""
+Late rodata check triggered: function {self.function.getName()} (address: 0x{self.function.vram:08X}):
+    The {message}.
+    asm-processor won't be able to build this function, consider not migrating its late rodata"""
+        if common.GlobalConfig.PANIC_LATE_RODATA_RATIO:
+            raise RuntimeError(warningMessage)
+        common.Utils.eprint(f"\n{warningMessage}\n")
+
     def getName(self) -> str:
         assert self.function is not None or self.hasRodataSyms()
 
@@ -130,6 +211,10 @@ class FunctionRodataEntry:
         if not rodataSym.shouldMigrate():
             return False
 
+        owner = rodataSym.contextSym.getSharedRodataMigrationOwner()
+        if owner is not None and owner.getName() != funcName:
+            return False
+
         return True
 
     @staticmethod
@@ -190,7 +275,7 @@ class FunctionRodataEntry:
         lateRodataMigratedSomewhereElse: bool = False
         for rodataSym in rodataSection.symbolList:
             if rodataMigratedSomewhereElse:
-                if not common.GlobalConfig.COMPILER.value.hasLateRodata:
+                if not common.GlobalConfig.getCompilerProperties().hasLateRodata:
                     break
                 if lateRodataMigratedSomewhereElse:
                     break
@@ -200,13 +285,18 @@ class FunctionRodataEntry:
             else:
                 rodataMigratedSomewhereElse = FunctionRodataEntry._updateMigrableSymbolsSets(rodataSym, intersection, funcName, migrableRodataSyms, maybeMigrableRodataSyms, rodataMigratedSomewhereElse)
 
+        weakRodataRefs: list[symbols.SymbolBase] = []
         for rodataSym in rodataSection.symbolList:
             if rodataSym.vram in migrableLateRodataSyms:
                 lateRodataList.append(rodataSym)
             elif rodataSym.vram in migrableRodataSyms:
                 rodataList.append(rodataSym)
+            elif rodataSym.vram in intersection and rodataSym.contextSym.isSharedRodataDuplicatedOnMigration() and rodataSym.shouldMigrate():
+                weakRodataRefs.append(rodataSym)
 
-        return FunctionRodataEntry(func, rodataList, lateRodataList)
+        entry = FunctionRodataEntry(func, rodataList, lateRodataList, weakRodataRefs)
+        entry.checkLateRodataRatio()
+        return entry
 
     @staticmethod
     def getEntryForFuncFromPossibleRodataSections(func: symbols.SymbolFunction, rodataFileList: list[sections.SectionBase]) -> FunctionRodataEntry:
@@ -221,7 +311,7 @@ class FunctionRodataEntry:
             assert isinstance(rodataSection, sections.SectionRodata)
 
             entry = FunctionRodataEntry.getEntryForFuncFromSection(func, rodataSection)
-            if entry.hasRodataSyms():
+            if entry.hasRodataSyms() or len(entry.weakRodataRefs) > 0:
                 return entry
 
         return FunctionRodataEntry(func)
@@ -240,6 +330,9 @@ class FunctionRodataEntry:
         cases an entry containing the specific symbol is created and inserted
         in corresponding place of the list so it preserves the ordering of both
         sections.
+
+        Data pools from the text section (see `ContextSymbol.isTextDataPool`)
+        are not included on the returned list.
         """
 
         # The simplest way to know which symbols has not been migrated yet and
@@ -256,7 +349,9 @@ class FunctionRodataEntry:
 
         textSymbols = textSection.symbolList if textSection is not None else []
         for func in textSymbols:
-            assert isinstance(func, symbols.SymbolFunction)
+            if not isinstance(func, symbols.SymbolFunction):
+                # Data pools don't get paired with rodata
+                continue
 
             entry = FunctionRodataEntry.getEntryForFuncFromSection(func, rodataSection)
 
@@ -0,0 +1,123 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+def _getTableTargets(dataSym: symbols.SymbolBase, pointerVram: int) -> list[symbols.analysis.IndirectCallTarget]:
+    if dataSym.sizew == 1:
+        confidence = symbols.analysis.IndirectCallConfidence.MEDIUM
+    else:
+        confidence = symbols.analysis.IndirectCallConfidence.LOW
+
+    targets: list[symbols.analysis.IndirectCallTarget] = []
+    seen: set[int] = set()
+    for i, word in enumerate(dataSym.words[:dataSym.sizew]):
+        if dataSym.getReloc(i*4, None) is not None:
+            return []
+        if word == 0 or word in seen:
+            # NULL entries are allowed, like terminators
+            continue
+        pointee = dataSym.getSymbol(word, tryPlusOffset=False)
+        if pointee is None or pointee.vram != word or pointee.getTypeSpecial() != common.SymbolSpecialType.function:
+            # Not a function pointer table
+            return []
+        seen.add(word)
+        targets.append(symbols.analysis.IndirectCallTarget(word, confidence, pointerVram))
+    return targets
+
+def _inferTargets(func: symbols.SymbolFunction, regOffset: int, dataSymbols: dict[int, symbols.SymbolBase]) -> list[symbols.analysis.IndirectCallTarget]:
+    analyzer = func.instrAnalyzer
+
+    if regOffset in analyzer.gotAccessAddresses:
+        # Global GOT entries point to the function itself
+        gotAddress = analyzer.symbolInstrOffset.get(regOffset)
+        if gotAddress is None:
+            return []
+        return [symbols.analysis.IndirectCallTarget(gotAddress, symbols.analysis.IndirectCallConfidence.HIGH)]
+
+    address = analyzer.indirectFunctionCallOffsets.get(regOffset)
+    if address is None:
+        return []
+
+    if not func.instructions[regOffset//4].doesLoad():
+        # The register holds the address of the function itself
+        return [symbols.analysis.IndirectCallTarget(address, symbols.analysis.IndirectCallConfidence.HIGH)]
+
+    # Resolve the address from the point of view of the function, so overlays sharing the same vram don't get mixed up
+    contextSym = func.getSymbol(address, tryPlusOffset=False)
+    if contextSym is None:
+        return []
+    dataSym = dataSymbols.get(id(contextSym))
+    if dataSym is None:
+        return []
+    return _getTableTargets(dataSym, address)
+
+def inferIndirectCallTargets(sections: list[FileBase]) -> int:
+    """
+    Infers the possible targets of every indirect function call (`jalr`) on
+    the passed text sections whose called register was set from:
+
+    - The address of a function, either by a `%hi`/`%lo` pair or through the
+      GOT (`IndirectCallConfidence.HIGH`).
+    - A data or rodata variable holding a single function pointer. Its
+      initial value is used as the target (`IndirectCallConfidence.MEDIUM`).
+    - A data or rodata table of function pointers. Every entry of the table
+      is a possible target (`IndirectCallConfidence.LOW`).
+
+    The inferred targets are stored per call site and can be queried with
+    `SymbolFunction.getAnalysisResults`. Targets read from variables and
+    tables are counted as references from the calling function.
+
+    Must be called after every passed section has been analyzed. Call sites
+    which already have inferred targets are skipped, so calling this again
+    doesn't count the same references twice.
+
+    Returns the amount of call sites which got at least one target inferred.
+    """
+
+    # Keyed by the identity of their context symbol
+    dataSymbols: dict[int, symbols.SymbolBase] = dict()
+    for section in sections:
+        if section.sectionType not in {common.FileSectionType.Data, common.FileSectionType.Rodata}:
+            continue
+        for sym in section.symbolList:
+            dataSymbols[id(sym.contextSym)] = sym
+
+    count = 0
+    for section in sections:
+        if section.sectionType != common.FileSectionType.Text:
+            continue
+
+        for func in section.symbolList:
+            if not isinstance(func, symbols.SymbolFunction):
+                continue
+
+            for jalrOffset, regOffset in func.instrAnalyzer.indirectFunctionCallRegOffsets.items():
+                if jalrOffset in func.instrAnalyzer.indirectCallTargets:
+                    continue
+                targets = _inferTargets(func, regOffset, dataSymbols)
+                if len(targets) == 0:
+                    continue
+
+                func.instrAnalyzer.indirectCallTargets[jalrOffset] = targets
+                count += 1
+
+                for target in targets:
+                    if target.pointerVram is None:
+                        # Already referenced by the instructions setting the register
+                        continue
+                    targetSym = func.getSymbol(target.vram, tryPlusOffset=False)
+                    if targetSym is None:
+                        continue
+                    targetSym.referenceCounter += 1
+                    targetSym.referenceFunctions.add(func.contextSym)
+                    targetSym.addReferenceLocation(func.contextSym, func.getVromOffset(jalrOffset), isFromFunction=True)
+    return count
@@ -33,6 +33,13 @@ class InstructionConfig:
         miscOpts = parser.add_argument_group("MIPS misc instructions options")
 
         miscOpts.add_argument("--pseudo-instr", help=f"Toggles producing pseudo instructions. Defaults to {rabbitizer.config.pseudos_enablePseudos}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-move", help=f"Toggles producing the `move` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableMove}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-b", help=f"Toggles producing the `b` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableB}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-beqz", help=f"Toggles producing the `beqz` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableBeqz}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-bnez", help=f"Toggles producing the `bnez` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableBnez}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-not", help=f"Toggles producing the `not` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableNot}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-negu", help=f"Toggles producing the `negu` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableNegu}", action=Utils.BooleanOptionalAction)
+        miscOpts.add_argument("--pseudo-bal", help=f"Toggles producing the `bal` pseudo instruction. Has no effect if pseudo instructions are disabled. Defaults to {rabbitizer.config.pseudos_enableBal}", action=Utils.BooleanOptionalAction)
 
         miscOpts.add_argument("--j-branch", help=f"Treat J instructions as unconditional branches. {rabbitizer.config.toolchainTweaks_treatJAsUnconditionalBranch}", action=Utils.BooleanOptionalAction)
 
@@ -70,6 +77,20 @@ class InstructionConfig:
 
         if args.pseudo_instr is not None:
             rabbitizer.config.pseudos_enablePseudos = args.pseudo_instr
+        if args.pseudo_move is not None:
+            rabbitizer.config.pseudos_enableMove = args.pseudo_move
+        if args.pseudo_b is not None:
+            rabbitizer.config.pseudos_enableB = args.pseudo_b
+        if args.pseudo_beqz is not None:
+            rabbitizer.config.pseudos_enableBeqz = args.pseudo_beqz
+        if args.pseudo_bnez is not None:
+            rabbitizer.config.pseudos_enableBnez = args.pseudo_bnez
+        if args.pseudo_not is not None:
+            rabbitizer.config.pseudos_enableNot = args.pseudo_not
+        if args.pseudo_negu is not None:
+            rabbitizer.config.pseudos_enableNegu = args.pseudo_negu
+        if args.pseudo_bal is not None:
+            rabbitizer.config.pseudos_enableBal = args.pseudo_bal
 
         if args.j_branch is not None:
             rabbitizer.config.toolchainTweaks_treatJAsUnconditionalBranch = args.j_branch
@@ -0,0 +1,114 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+_OSTHREAD_SIZE = common.gStructSizes["OSThread"]
+_OSMESGQUEUE_SIZE = common.gStructSizes["OSMesgQueue"]
+_OSVIMODE_SIZE = common.gStructSizes["OSViMode"]
+
+_threadFunctions = {
+    "osCreateThread", "osStartThread", "osStopThread", "osDestroyThread",
+    "osSetThreadPri", "osGetThreadPri", "osGetThreadId",
+}
+_mesgQueueFunctions = {
+    "osCreateMesgQueue", "osSendMesg", "osRecvMesg", "osJamMesg",
+    "osSetEventMesg", "osViSetEvent", "osPiStartDma", "osEPiStartDma",
+    "osSpTaskYield", "osContInit", "osContStartReadData", "osAiSetNextBuffer",
+}
+
+
+def _isViModeEntry(words: list[int]) -> bool:
+    typeWord = words[0]
+    # `u8 type` followed by padding
+    if typeWord & 0x00FFFFFF != 0:
+        return False
+    # There are 56 VI modes in libultra
+    if (typeWord >> 24) >= 56:
+        return False
+    ctrl = words[1]
+    # Pixel size must be either 16 or 32 bits
+    if ctrl & 0x3 not in {2, 3}:
+        return False
+    return True
+
+def _isViModeTable(sym: symbols.SymbolBase) -> bool:
+    size = sym.sizew * 4
+    if size == 0 or size % _OSVIMODE_SIZE != 0:
+        return False
+    entryWords = _OSVIMODE_SIZE // 4
+    for i in range(0, sym.sizew, entryWords):
+        if not _isViModeEntry(sym.words[i:i+entryWords]):
+            return False
+    return True
+
+def _getCalledFunctionNames(textSections: list[FileBase]) -> dict[common.ContextSymbol, set[str]]:
+    calledNames: dict[common.ContextSymbol, set[str]] = dict()
+    for section in textSections:
+        for func in section.symbolList:
+            if not isinstance(func, symbols.SymbolFunction):
+                continue
+            names: set[str] = set()
+            for targetVram in func.instrAnalyzer.funcCallInstrOffsets.values():
+                targetSym = func.getSymbol(targetVram, tryPlusOffset=False)
+                if targetSym is not None:
+                    names.add(targetSym.getName())
+            calledNames[func.contextSym] = names
+    return calledNames
+
+def _isReferencedByCallerOf(contextSym: common.ContextSymbol, calledNames: dict[common.ContextSymbol, set[str]], functionNames: set[str]) -> bool:
+    for funcSym in contextSym.referenceFunctions:
+        if not calledNames.get(funcSym, set()).isdisjoint(functionNames):
+            return True
+    return False
+
+def detectLibultraStructs(sections: list[FileBase]) -> int:
+    """
+    Tries to recognize common libultra structures (`OSThread`, `OSMesgQueue`
+    and `OSViMode` tables) by their size and contents, and sets them as the
+    autodetected type of the symbols. Symbols with a type already set are not
+    touched.
+
+    Threads and message queues are only recognized if they are referenced by a
+    function which calls a libultra function that uses those structures, so the
+    libultra functions must be named (i.e. by the user or by signatures).
+
+    Must be called after every passed section has been analyzed.
+
+    Returns the amount of symbols which got a type assigned.
+    """
+
+    textSections = [x for x in sections if x.sectionType == common.FileSectionType.Text]
+    calledNames = _getCalledFunctionNames(textSections)
+
+    count = 0
+    for section in sections:
+        if section.sectionType not in {common.FileSectionType.Data, common.FileSectionType.Rodata, common.FileSectionType.Bss}:
+            continue
+
+        for sym in section.symbolList:
+            contextSym = sym.contextSym
+            if not contextSym.hasNoType():
+                continue
+
+            size = sym.sizew * 4
+            structType: str|None = None
+            if size == _OSTHREAD_SIZE and _isReferencedByCallerOf(contextSym, calledNames, _threadFunctions):
+                structType = "OSThread"
+            elif size == _OSMESGQUEUE_SIZE and _isReferencedByCallerOf(contextSym, calledNames, _mesgQueueFunctions):
+                structType = "OSMesgQueue"
+            elif not isinstance(sym, symbols.SymbolBss) and _isViModeTable(sym):
+                structType = "OSViMode"
+
+            if structType is not None:
+                contextSym.autodetectedType = structType
+                count += 1
+    return count
@@ -5,6 +5,8 @@
 
 from __future__ import annotations
 
+from concurrent.futures import ThreadPoolExecutor
+import copy
 import sys
 from typing import TextIO
 from pathlib import Path
@@ -27,7 +29,7 @@ class FileBase(common.ElementBase):
         self.fileBoundaries: list[int] = list()
 
         self.symbolsVRams: set[int] = set()
-        "addresses of symbols in this section"
+        "addresses of symbols in this section. Use `getSymbolsVramsSorted` if a deterministic order is needed"
 
         self.bytes: bytes = common.Utils.wordsToBytes(self.words)
 
@@ -83,6 +85,9 @@ class FileBase(common.ElementBase):
 
         return output
 
+    def getSymbolsVramsSorted(self) -> list[int]:
+        return sorted(self.symbolsVRams)
+
     def getHash(self) -> str:
         buffer = common.Utils.wordsToBytes(self.words)
         return common.Utils.getStrHash(buffer)
@@ -123,6 +128,65 @@ class FileBase(common.ElementBase):
         self.printNewFileBoundaries()
 
 
+    def getFileBoundariesAddresses(self) -> list[tuple[int, int]]:
+        """
+        Returns the vram and vrom of each file boundary detected by the
+        analysis of this section (see `fileBoundaries`), sorted.
+        """
+
+        result: list[tuple[int, int]] = []
+        for inFileOffset in sorted(set(self.fileBoundaries)):
+            localOffset = inFileOffset - self.inFileOffset
+            result.append((self.getVramOffset(localOffset), self.getVromOffset(localOffset)))
+        return result
+
+    def splitByFileBoundaries(self, names: list[str]|None=None) -> list[FileBase]:
+        """
+        Splits this section into multiple child sections, one per each of the
+        detected file boundaries (see `fileBoundaries`).
+
+        The already analyzed symbols are shared with the child sections, so
+        neither the context nor the symbols need to be rebuilt.
+
+        If `names` is passed then it must contain one name per child section.
+        Otherwise the child sections are named after the name of this section
+        and their vram.
+        """
+
+        boundaries = sorted({0} | {x - self.inFileOffset for x in self.fileBoundaries if 0 < x - self.inFileOffset < self.sizew*4})
+        boundaries.append(self.sizew*4)
+
+        if names is not None:
+            assert len(names) == len(boundaries) - 1, f"Expected {len(boundaries) - 1} names, got {len(names)}"
+
+        children: list[FileBase] = []
+        for i in range(len(boundaries)-1):
+            start = boundaries[i]
+            end = boundaries[i+1]
+
+            child = copy.copy(self)
+            child.name = names[i] if names is not None else f"{self.name}_{self.getVramOffset(start):08X}"
+            child.vromStart = self.getVromOffset(start)
+            child.vromEnd = self.getVromOffset(end)
+            child.vram = self.getVramOffset(start)
+            child.inFileOffset = self.inFileOffset + start
+            child.words = self.words[start//4:end//4]
+            child.bytes = common.Utils.wordsToBytes(child.words)
+            child.symbolList = []
+            for sym in self.symbolList:
+                if start <= sym.inFileOffset - self.inFileOffset < end:
+                    # Copied so the alignment of the symbols is computed against the child instead of this file
+                    childSym = copy.copy(sym)
+                    childSym.parent = child
+                    child.symbolList.append(childSym)
+            child.symbolsVRams = {sym.vram for sym in child.symbolList}
+            child.fileBoundaries = list()
+            child.parent = self
+            children.append(child)
+
+        return children
+
+
     def compareToFile(self, other_file: FileBase) -> dict:
         hash_one = self.getHash()
         hash_two = other_file.getHash()
@@ -170,16 +234,31 @@ class FileBase(common.ElementBase):
         return False
 
 
+    def disassembleSymbols(self, migrate: bool=False, useGlobalLabel: bool=True, jobs: int|None=None) -> list[str]:
+        """
+        Returns the disassembly of every symbol of this section, in the same
+        order as `symbolList`.
+
+        If `jobs` is bigger than 1 then the symbols are disassembled in
+        parallel using that many threads. Defaults to
+        `GlobalConfig.DISASSEMBLY_JOBS`.
+        """
+        if jobs is None:
+            jobs = common.GlobalConfig.DISASSEMBLY_JOBS
+
+        if jobs <= 1 or len(self.symbolList) <= 1:
+            return [sym.disassemble(migrate=migrate, useGlobalLabel=useGlobalLabel, isSplittedSymbol=False) for sym in self.symbolList]
+
+        with ThreadPoolExecutor(max_workers=jobs) as executor:
+            return list(executor.map(lambda sym: sym.disassemble(migrate=migrate, useGlobalLabel=useGlobalLabel, isSplittedSymbol=False), self.symbolList))
+
     def disassemble(self, migrate: bool=False, useGlobalLabel: bool=True) -> str:
         output = ""
 
         if not migrate:
             output += self.getSpimdisasmVersionString()
 
-        for i, sym in enumerate(self.symbolList):
-            output += sym.disassemble(migrate=migrate, useGlobalLabel=useGlobalLabel, isSplittedSymbol=False)
-            if i + 1 < len(self.symbolList):
-                output += common.GlobalConfig.LINE_ENDS
+        output += common.GlobalConfig.LINE_ENDS.join(self.disassembleSymbols(migrate=migrate, useGlobalLabel=useGlobalLabel))
         return output
 
     def disassembleToFile(self, f: TextIO) -> None:
@@ -114,9 +114,24 @@ class FileSplits(FileBase):
                 for subFile in self.sectionsDict[sectionType].values():
                     subFile.pointersOffsets.add(entry.offset)
 
+        sectionsCount = sum(len(sectDict) for sectDict in self.sectionsDict.values())
+        analyzedCount = 0
         for sectDict in self.sectionsDict.values():
             for section in sectDict.values():
+                self.context.reportProgress(common.ProgressPhase.SECTION_ANALYSIS, analyzedCount * 100 / sectionsCount, segmentName=self.overlayCategory, sectionName=section.getName())
                 section.analyze()
+                analyzedCount += 1
+
+        self.context.reportProgress(common.ProgressPhase.SECTION_ANALYSIS, 100.0, segmentName=self.overlayCategory)
+
+        if common.GlobalConfig.PRUNE_UNREFERENCED_SYMBOLS:
+            for sectDict in self.sectionsDict.values():
+                for section in sectDict.values():
+                    section.pruneUnreferencedSymbols()
+
+        for section in self.sectionsDict[common.FileSectionType.Text].values():
+            if isinstance(section, sections.SectionText):
+                section.defineLabels()
 
     def compareToFile(self, other_file: FileBase) -> dict:
         if isinstance(other_file, FileSplits):
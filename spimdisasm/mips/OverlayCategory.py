@@ -0,0 +1,119 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+
+from .. import common
+
+from . import sections
+
+
+@dataclasses.dataclass
+class OverlaySectionEntry:
+    """
+    A single section of an overlay, used by `analyzeOverlayCategory`.
+
+    `arrayOfBytes` is the buffer the vrom addresses index into, usually the
+    whole rom. It is not used for bss sections.
+
+    Only `.text`, `.data`, `.rodata`, `.bss` and bin sections are supported.
+    """
+
+    name: str
+    sectionType: common.FileSectionType
+    arrayOfBytes: bytes
+    vromStart: int
+    vromEnd: int
+    vram: int
+    segmentVromStart: int
+    "Vrom of the overlay this section belongs to. Every section of the same overlay must use the same value"
+    bssSize: int = 0
+    "Only used for bss sections, since their vrom range is empty"
+
+    @property
+    def vramEnd(self) -> int:
+        if self.sectionType == common.FileSectionType.Bss:
+            return self.vram + self.bssSize
+        return self.vram + self.vromEnd - self.vromStart
+
+    def createSection(self, context: common.Context, overlayCategory: str) -> sections.SectionBase:
+        if self.sectionType == common.FileSectionType.Text:
+            return sections.SectionText(context, self.vromStart, self.vromEnd, self.vram, self.name, self.arrayOfBytes, self.segmentVromStart, overlayCategory)
+        if self.sectionType == common.FileSectionType.Data:
+            return sections.SectionData(context, self.vromStart, self.vromEnd, self.vram, self.name, self.arrayOfBytes, self.segmentVromStart, overlayCategory)
+        if self.sectionType == common.FileSectionType.Rodata:
+            return sections.SectionRodata(context, self.vromStart, self.vromEnd, self.vram, self.name, self.arrayOfBytes, self.segmentVromStart, overlayCategory)
+        if self.sectionType == common.FileSectionType.Bss:
+            return sections.SectionBss(context, self.vromStart, self.vromEnd, self.vram, self.vramEnd, self.name, self.segmentVromStart, overlayCategory)
+        if self.sectionType == common.FileSectionType.Bin:
+            return sections.SectionBin(context, self.vromStart, self.vromEnd, self.vram, self.name, self.arrayOfBytes, self.segmentVromStart, overlayCategory)
+        raise ValueError(f"Section '{self.name}' of overlay category '{overlayCategory}' has unsupported section type {self.sectionType.name}")
+
+
+def _registerOverlaySegments(context: common.Context, overlayCategory: str, entries: list[OverlaySectionEntry]) -> None:
+    ranges: dict[int, tuple[int, int, int, int]] = dict()
+    bssOnly: dict[int, bool] = dict()
+    for entry in entries:
+        bssOnly[entry.segmentVromStart] = bssOnly.get(entry.segmentVromStart, True) and entry.sectionType == common.FileSectionType.Bss
+        current = ranges.get(entry.segmentVromStart)
+        if current is None:
+            ranges[entry.segmentVromStart] = (entry.vromStart, entry.vromEnd, entry.vram, entry.vramEnd)
+        else:
+            ranges[entry.segmentVromStart] = (min(current[0], entry.vromStart), max(current[1], entry.vromEnd), min(current[2], entry.vram), max(current[3], entry.vramEnd))
+
+    existingSegments = context.overlaySegments.get(overlayCategory, dict())
+    for segmentVromStart, (vromStart, vromEnd, vramStart, vramEnd) in sorted(ranges.items()):
+        if segmentVromStart in existingSegments:
+            continue
+        if bssOnly[segmentVromStart]:
+            context.addBssOnlyOverlaySegment(overlayCategory, segmentVromStart, vramStart, vramEnd)
+        else:
+            context.addOverlaySegment(overlayCategory, min(segmentVromStart, vromStart), vromEnd, vramStart, vramEnd)
+
+def analyzeOverlayCategory(context: common.Context, overlayCategory: str, entries: list[OverlaySectionEntry]) -> list[sections.SectionBase]:
+    """
+    Registers a segment to the context for each overlay of the given category
+    (unless it already exists), creates every section described by `entries`
+    and analyzes them.
+
+    The sections are analyzed in batches by section type (every `.text`
+    first, then `.data`, `.rodata` and finally `.bss`), since the analysis of
+    data sections depends on what was found on the code.
+
+    Returns the analyzed sections, in the same order as `entries`.
+    """
+
+    _registerOverlaySegments(context, overlayCategory, entries)
+
+    createdSections = [entry.createSection(context, overlayCategory) for entry in entries]
+
+    batches: dict[common.FileSectionType, list[sections.SectionBase]] = dict()
+    for section in createdSections:
+        batches.setdefault(section.sectionType, []).append(section)
+
+    # The string pool has to be filled before analyzing any function
+    for section in createdSections:
+        section.registerStringsInPool()
+
+    analyzedCount = 0
+    for sectionType in sorted(batches.keys()):
+        for section in batches[sectionType]:
+            context.reportProgress(common.ProgressPhase.SECTION_ANALYSIS, analyzedCount * 100 / len(createdSections), segmentName=overlayCategory, sectionName=section.getName())
+            section.analyze()
+            analyzedCount += 1
+
+    context.reportProgress(common.ProgressPhase.SECTION_ANALYSIS, 100.0, segmentName=overlayCategory)
+
+    if common.GlobalConfig.PRUNE_UNREFERENCED_SYMBOLS:
+        for section in createdSections:
+            section.pruneUnreferencedSymbols()
+
+    for section in createdSections:
+        if isinstance(section, sections.SectionText):
+            section.defineLabels()
+
+    return createdSections
@@ -0,0 +1,81 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+def _getPointeeType(sym: symbols.SymbolBase, word: int) -> str|None:
+    pointee = sym.getSymbol(word, tryPlusOffset=False)
+    if pointee is None or pointee.vram != word:
+        return None
+    if pointee.getTypeSpecial() == common.SymbolSpecialType.function:
+        return common.ContextSymbol.POINTEE_FUNCTION
+    if pointee.isString():
+        return common.ContextSymbol.POINTEE_STRING
+    return None
+
+def _guessPointeeType(sym: symbols.SymbolBase) -> str|None:
+    if sym.sizew == 0 or sym.vram % 4 != 0:
+        return None
+
+    pointeeType: str|None = None
+    for i, word in enumerate(sym.words[:sym.sizew]):
+        if sym.getReloc(i*4, None) is not None:
+            return None
+        if word == 0:
+            # NULL entries are allowed, like terminators
+            continue
+        wordPointee = _getPointeeType(sym, word)
+        if wordPointee is None:
+            return None
+        if pointeeType is not None and pointeeType != wordPointee:
+            return None
+        pointeeType = wordPointee
+    return pointeeType
+
+def propagatePointerTypes(sections: list[FileBase]) -> int:
+    """
+    Types untyped data and rodata symbols whose words are all pointers to the
+    same kind of symbol: functions (function pointer tables) or strings
+    (string tables). `NULL` entries are allowed.
+
+    The detected kind is set to `ContextSymbol.pointeeType`, which is used
+    when generating C declarations (see `ContextSymbol.getCDeclaration`). If
+    `GlobalConfig.ASM_COMMENT` is enabled then every word of the table gets
+    an end-of-line comment with the pointee type.
+
+    Must be called after every passed section has been analyzed.
+
+    Returns the amount of symbols which got a pointee type assigned.
+    """
+
+    count = 0
+    for section in sections:
+        if section.sectionType not in {common.FileSectionType.Data, common.FileSectionType.Rodata}:
+            continue
+
+        for sym in section.symbolList:
+            contextSym = sym.contextSym
+            if not contextSym.hasNoType() or contextSym.pointeeType is not None:
+                continue
+            if sym.isString() or sym.isPascalString():
+                continue
+
+            pointeeType = _guessPointeeType(sym)
+            if pointeeType is None:
+                continue
+
+            contextSym.pointeeType = pointeeType
+            if common.GlobalConfig.ASM_COMMENT:
+                for i, word in enumerate(sym.words[:sym.sizew]):
+                    if word != 0 and sym.endOfLineComment.get(i) is None:
+                        sym.endOfLineComment[i] = f" /* {pointeeType} */"
+            count += 1
+    return count
@@ -0,0 +1,542 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import re
+import struct
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+@dataclasses.dataclass
+class RoundTripMismatch:
+    vram: int
+    "Address of the first byte produced by the offending line"
+    vromAddress: int|None
+    symbolName: str
+    line: str
+    "The offending line of the generated assembly, without comments"
+    expected: bytes
+    "The original bytes"
+    assembled: bytes
+    "The bytes produced by reassembling the line. Empty if the line could not be assembled"
+    reason: str
+
+    def __str__(self) -> str:
+        return f"0x{self.vram:08X} in {self.symbolName}: {self.reason}: `{self.line}` (expected {self.expected.hex().upper()}, got {self.assembled.hex().upper()})"
+
+@dataclasses.dataclass
+class RoundTripResult:
+    mismatches: list[RoundTripMismatch] = dataclasses.field(default_factory=list)
+    verifiedBytes: int = 0
+    "Amount of bytes which were reassembled and compared"
+    unverifiedBytes: int = 0
+    "Amount of bytes which could not be checked, like instructions or `.incbin`s"
+
+    def isMatching(self) -> bool:
+        return len(self.mismatches) == 0
+
+    def extend(self, other: RoundTripResult) -> None:
+        self.mismatches.extend(other.mismatches)
+        self.verifiedBytes += other.verifiedBytes
+        self.unverifiedBytes += other.unverifiedBytes
+
+
+class _AssemblerError(Exception):
+    pass
+
+class _UnverifiableError(Exception):
+    pass
+
+
+_tokenRegex = re.compile(r"\s*(?:(0[xX][0-9a-fA-F]+|\d+)|([A-Za-z_.$][A-Za-z0-9_.$]*)|(<<|>>|[-+*/%&|^~()]))")
+
+class _ExpressionEvaluator:
+    """
+    Evaluates the constant expressions emitted by spimdisasm on data
+    directives, like `sym + 0x10`, `.L80001234 - jtbl` or `0x1234 & 0xFFFF`.
+
+    Follows the GNU as precedence rules, where `*`, `/`, `%`, `<<` and `>>`
+    bind tighter than `|`, `&` and `^`, which bind tighter than `+` and `-`.
+    """
+
+    def __init__(self, expr: str, symbolsValues: dict[str, int], currentAddress: int) -> None:
+        self.tokens: list[str] = []
+        self.symbolsValues = symbolsValues
+        self.currentAddress = currentAddress
+        self.pos = 0
+
+        i = 0
+        expr = expr.strip()
+        while i < len(expr):
+            match = _tokenRegex.match(expr, i)
+            if match is None or match.end() == i:
+                raise _AssemblerError(f"Unexpected character '{expr[i]}' in expression '{expr}'")
+            self.tokens.append(match.group(match.lastindex or 0))
+            i = match.end()
+            while i < len(expr) and expr[i].isspace():
+                i += 1
+
+    def evaluate(self) -> int:
+        value = self._parseAdd()
+        if self.pos != len(self.tokens):
+            raise _AssemblerError(f"Unexpected token '{self.tokens[self.pos]}'")
+        return value
+
+    def _peek(self) -> str|None:
+        if self.pos < len(self.tokens):
+            return self.tokens[self.pos]
+        return None
+
+    def _next(self) -> str:
+        token = self._peek()
+        if token is None:
+            raise _AssemblerError("Unexpected end of expression")
+        self.pos += 1
+        return token
+
+    def _parseAdd(self) -> int:
+        value = self._parseBitwise()
+        while self._peek() in {"+", "-"}:
+            op = self._next()
+            rhs = self._parseBitwise()
+            value = value + rhs if op == "+" else value - rhs
+        return value
+
+    def _parseBitwise(self) -> int:
+        value = self._parseMul()
+        while self._peek() in {"&", "|", "^"}:
+            op = self._next()
+            rhs = self._parseMul()
+            if op == "&":
+                value &= rhs
+            elif op == "|":
+                value |= rhs
+            else:
+                value ^= rhs
+        return value
+
+    def _parseMul(self) -> int:
+        value = self._parseUnary()
+        while self._peek() in {"*", "/", "%", "<<", ">>"}:
+            op = self._next()
+            rhs = self._parseUnary()
+            if op == "*":
+                value *= rhs
+            elif op == "<<":
+                value <<= rhs
+            elif op == ">>":
+                value >>= rhs
+            elif rhs == 0:
+                raise _AssemblerError("Division by zero")
+            elif op == "/":
+                value //= rhs
+            else:
+                value %= rhs
+        return value
+
+    def _parseUnary(self) -> int:
+        token = self._peek()
+        if token == "-":
+            self._next()
+            return -self._parseUnary()
+        if token == "+":
+            self._next()
+            return self._parseUnary()
+        if token == "~":
+            self._next()
+            return ~self._parseUnary()
+        return self._parsePrimary()
+
+    def _parsePrimary(self) -> int:
+        token = self._next()
+        if token == "(":
+            value = self._parseAdd()
+            if self._next() != ")":
+                raise _AssemblerError("Missing closing parenthesis")
+            return value
+        if token[0].isdigit():
+            return int(token, 0)
+        if token == ".":
+            return self.currentAddress
+        if self._peek() == "(":
+            raise _AssemblerError(f"Unknown macro '{token}'")
+        value = self.symbolsValues.get(token)
+        if value is None:
+            raise _AssemblerError(f"Undefined symbol '{token}'")
+        return value
+
+
+def _stripComments(line: str) -> str:
+    result = ""
+    inString = False
+    i = 0
+    while i < len(line):
+        char = line[i]
+        if inString:
+            result += char
+            if char == "\\" and i + 1 < len(line):
+                result += line[i+1]
+                i += 1
+            elif char == '"':
+                inString = False
+        elif char == '"':
+            inString = True
+            result += char
+        elif line.startswith("/*", i):
+            end = line.find("*/", i+2)
+            if end < 0:
+                break
+            i = end + 2
+            continue
+        elif char == "#":
+            break
+        else:
+            result += char
+        i += 1
+    return result.strip()
+
+def _splitOperands(operands: str) -> list[str]:
+    "Splits a list of comma-separated operands, ignoring the commas inside strings and parenthesis"
+    result: list[str] = []
+    current = ""
+    depth = 0
+    inString = False
+    i = 0
+    while i < len(operands):
+        char = operands[i]
+        if inString:
+            current += char
+            if char == "\\" and i + 1 < len(operands):
+                current += operands[i+1]
+                i += 1
+            elif char == '"':
+                inString = False
+        elif char == '"':
+            inString = True
+            current += char
+        elif char == "(":
+            depth += 1
+            current += char
+        elif char == ")":
+            depth -= 1
+            current += char
+        elif char == "," and depth == 0:
+            result.append(current.strip())
+            current = ""
+        else:
+            current += char
+        i += 1
+    if current.strip() != "":
+        result.append(current.strip())
+    return result
+
+_simpleEscapes = {
+    "n": 0x0A,
+    "t": 0x09,
+    "r": 0x0D,
+    "a": 0x07,
+    "b": 0x08,
+    "f": 0x0C,
+    "v": 0x0B,
+    "\\": 0x5C,
+    '"': 0x22,
+    "'": 0x27,
+}
+
+def _unescapeString(literal: str, stringEncoding: str) -> bytes:
+    if len(literal) < 2 or literal[0] != '"' or literal[-1] != '"':
+        raise _AssemblerError(f"Malformed string literal {literal}")
+    text = literal[1:-1]
+
+    result = bytearray()
+    pending = ""
+    i = 0
+    while i < len(text):
+        char = text[i]
+        if char != "\\":
+            pending += char
+            i += 1
+            continue
+
+        result += pending.encode(stringEncoding)
+        pending = ""
+        i += 1
+        if i >= len(text):
+            raise _AssemblerError("Dangling backslash in string literal")
+        escape = text[i]
+        if escape in _simpleEscapes:
+            result.append(_simpleEscapes[escape])
+            i += 1
+        elif escape in "xX":
+            # Like GNU as, consume every following hex digit
+            i += 1
+            start = i
+            while i < len(text) and text[i] in "0123456789abcdefABCDEF":
+                i += 1
+            if start == i:
+                raise _AssemblerError("Empty hex escape in string literal")
+            result.append(int(text[start:i], 16) & 0xFF)
+        elif escape in "01234567":
+            start = i
+            while i < len(text) and i - start < 3 and text[i] in "01234567":
+                i += 1
+            result.append(int(text[start:i], 8) & 0xFF)
+        else:
+            raise _AssemblerError(f"Unknown escape sequence '\\{escape}'")
+    result += pending.encode(stringEncoding)
+    return bytes(result)
+
+
+class _DataAssembler:
+    """
+    A tiny assembler which only understands the data directives emitted by
+    spimdisasm.
+    """
+
+    def __init__(self, symbolsValues: dict[str, int], stringEncoding: str) -> None:
+        self.symbolsValues = symbolsValues
+        self.stringEncoding = stringEncoding
+        self.endianFormat = common.GlobalConfig.ENDIAN.toFormatString()
+
+        self.labelMacros = {
+            common.GlobalConfig.ASM_TEXT_LABEL,
+            common.GlobalConfig.ASM_TEXT_ALT_LABEL,
+            common.GlobalConfig.ASM_TEXT_END_LABEL,
+            common.GlobalConfig.ASM_TEXT_ENT_LABEL,
+            common.GlobalConfig.ASM_JTBL_LABEL,
+            common.GlobalConfig.ASM_DATA_LABEL,
+            common.GlobalConfig.ASM_DATA_END_LABEL,
+            common.GlobalConfig.ASM_EHTBL_LABEL,
+        } - {""}
+
+    def _evaluate(self, expr: str, currentAddress: int) -> int:
+        return _ExpressionEvaluator(expr, self.symbolsValues, currentAddress).evaluate()
+
+    def _packInts(self, operands: list[str], fmt: str, size: int, currentAddress: int) -> bytes:
+        result = b""
+        for operand in operands:
+            value = self._evaluate(operand, currentAddress + len(result))
+            mask = (1 << (size * 8)) - 1
+            result += struct.pack(self.endianFormat + fmt, value & mask)
+        return result
+
+    def assembleLine(self, line: str, currentAddress: int) -> bytes:
+        """
+        Returns the bytes produced by a single line, which must have its
+        comments already removed.
+
+        Raises `_UnverifiableError` for lines which can't be assembled by this
+        class, like instructions.
+        """
+
+        # Leading labels
+        while True:
+            match = re.match(r"([A-Za-z_.$][A-Za-z0-9_.$]*):\s*", line)
+            if match is None:
+                break
+            self.symbolsValues.setdefault(match.group(1), currentAddress)
+            line = line[match.end():]
+        if line == "":
+            return b""
+
+        parts = line.split(None, 1)
+        directive = parts[0]
+        operands = _splitOperands(parts[1]) if len(parts) > 1 else []
+
+        if not directive.startswith("."):
+            if directive in self.labelMacros:
+                return b""
+            raise _UnverifiableError(f"Unknown statement '{directive}'")
+
+        if directive in {".word", ".4byte", ".long", ".int"}:
+            return self._packInts(operands, "I", 4, currentAddress)
+        if directive == ".gpword":
+            if common.GlobalConfig.GP_VALUE is None:
+                raise _UnverifiableError("`.gpword` requires a gp value")
+            return self._packInts([f"({x}) - 0x{common.GlobalConfig.GP_VALUE:X}" for x in operands], "I", 4, currentAddress)
+        if directive in {".short", ".half", ".hword", ".2byte"}:
+            return self._packInts(operands, "H", 2, currentAddress)
+        if directive == ".byte":
+            return self._packInts(operands, "B", 1, currentAddress)
+        if directive in {".dword", ".quad", ".8byte"}:
+            return self._packInts(operands, "Q", 8, currentAddress)
+        if directive in {".float", ".single"}:
+            return b"".join(struct.pack(self.endianFormat + "f", float(x)) for x in operands)
+        if directive == ".double":
+            result = b""
+            for x in operands:
+                raw = struct.pack(">d", float(x))
+                hi, lo = struct.unpack(">II", raw)
+                # Doubles are stored as two words, with the most significant one first
+                result += struct.pack(self.endianFormat + "II", hi, lo) if self.endianFormat == ">" else struct.pack(self.endianFormat + "II", lo, hi)
+            return result
+        if directive == ".ascii":
+            return b"".join(_unescapeString(x, self.stringEncoding) for x in operands)
+        if directive in {".asciz", ".string"}:
+            return b"".join(_unescapeString(x, self.stringEncoding) + b"\0" for x in operands)
+        if directive in {".space", ".skip"}:
+            size = self._evaluate(operands[0], currentAddress)
+            fill = self._evaluate(operands[1], currentAddress) & 0xFF if len(operands) > 1 else 0
+            return bytes([fill]) * size
+        if directive in {".align", ".balign"}:
+            alignment = self._evaluate(operands[0], currentAddress)
+            if directive == ".align":
+                alignment = 1 << alignment
+            if alignment <= 0:
+                return b""
+            return bytes((-currentAddress) % alignment)
+        if directive == ".incbin":
+            raise _UnverifiableError("`.incbin` can't be verified")
+
+        # Any other directive does not emit data
+        return b""
+
+
+def _gatherSymbolsValues(context: common.Context) -> dict[str, int]:
+    symbolsValues: dict[str, int] = dict()
+    for segment in context.getSegmentsSorted():
+        for contextSym in segment.symbols.values():
+            symbolsValues.setdefault(contextSym.getName(), contextSym.vram)
+            for alias in contextSym.aliases:
+                symbolsValues.setdefault(alias, contextSym.vram)
+            nameEnd = contextSym.getNameEnd()
+            if nameEnd is not None:
+                symbolsValues.setdefault(nameEnd, contextSym.vram + contextSym.getSize())
+        for constant in segment.constants.values():
+            symbolsValues.setdefault(constant.getName(), constant.vram)
+    return symbolsValues
+
+def _getSymbolEndian(sym: symbols.SymbolBase) -> common.InputEndian:
+    "The endianness used to read the words of the given symbol"
+    if sym.sectionType == common.FileSectionType.Data and common.GlobalConfig.ENDIAN_DATA is not None:
+        return common.GlobalConfig.ENDIAN_DATA
+    if sym.sectionType == common.FileSectionType.Rodata and common.GlobalConfig.ENDIAN_RODATA is not None:
+        return common.GlobalConfig.ENDIAN_RODATA
+    return common.GlobalConfig.ENDIAN
+
+def _verifySymbolText(sym: symbols.SymbolBase, asmText: str, assembler: _DataAssembler) -> RoundTripResult:
+    result = RoundTripResult()
+
+    endian = _getSymbolEndian(sym)
+    if isinstance(sym, symbols.SymbolBin):
+        # Raw blobs don't split their contents into words
+        expectedBytes = sym.rawBytes
+    else:
+        expectedBytes = common.Utils.endianessWordsToBytes(endian, sym.words)
+    assembler.stringEncoding = sym.stringEncoding
+    assembler.endianFormat = endian.toFormatString()
+
+    offset = 0
+    for rawLine in asmText.splitlines():
+        line = _stripComments(rawLine)
+        if line == "":
+            continue
+
+        currentVram = sym.getVramOffset(offset)
+        try:
+            assembled = assembler.assembleLine(line, currentVram)
+        except _UnverifiableError as e:
+            result.mismatches.append(RoundTripMismatch(currentVram, sym.getVromOffset(offset), sym.getName(), line, b"", b"", str(e)))
+            # We can't know how many bytes this line would produce, so stop here
+            result.unverifiedBytes += max(len(expectedBytes) - offset, 0)
+            return result
+        except (_AssemblerError, ValueError, struct.error, UnicodeEncodeError) as e:
+            result.mismatches.append(RoundTripMismatch(currentVram, sym.getVromOffset(offset), sym.getName(), line, b"", b"", str(e)))
+            result.unverifiedBytes += max(len(expectedBytes) - offset, 0)
+            return result
+
+        if len(assembled) == 0:
+            continue
+
+        expected = expectedBytes[offset:offset+len(assembled)]
+        if assembled != expected:
+            reason = "Bytes differ" if len(expected) == len(assembled) else "Goes past the end of the symbol"
+            result.mismatches.append(RoundTripMismatch(currentVram, sym.getVromOffset(offset), sym.getName(), line, expected, assembled, reason))
+        else:
+            result.verifiedBytes += len(assembled)
+        offset += len(assembled)
+
+    if offset < len(expectedBytes):
+        currentVram = sym.getVramOffset(offset)
+        result.mismatches.append(RoundTripMismatch(currentVram, sym.getVromOffset(offset), sym.getName(), "", expectedBytes[offset:], b"", "Missing bytes at the end of the symbol"))
+
+    return result
+
+def _getUnverifiableResult(sym: symbols.SymbolBase) -> RoundTripResult|None:
+    if isinstance(sym, symbols.SymbolBss):
+        # Nothing to compare against
+        return RoundTripResult()
+    if isinstance(sym, symbols.SymbolFunction):
+        return RoundTripResult(unverifiedBytes=sym.sizew * 4)
+    if isinstance(sym, symbols.SymbolBin) and sym.incbinPath is not None:
+        return RoundTripResult(unverifiedBytes=len(sym.rawBytes))
+    return None
+
+def _disassembleWithoutDiagnostics(sym: symbols.SymbolBase) -> str:
+    # The diagnostics found while disassembling were already registered when the output was generated
+    diagnosticsCount = len(sym.context.diagnostics)
+    asmText = sym.disassemble()
+    del sym.context.diagnostics[diagnosticsCount:]
+    return asmText
+
+def verifySymbol(sym: symbols.SymbolBase, asmText: str|None=None, *, symbolsValues: dict[str, int]|None=None) -> RoundTripResult:
+    """
+    Reassembles the generated assembly of the given symbol in-memory and
+    compares it against the original bytes.
+
+    Only data directives are understood (`.word`, `.short`, `.byte`,
+    `.float`, `.double`, `.dword`, `.ascii`, `.asciz`, `.space`, `.align`,
+    etc). Symbol references are resolved using the names known by the
+    context. Since rabbitizer doesn't provide an instruction encoder the
+    instructions of functions are counted as unverified bytes instead.
+
+    If `asmText` is `None` then the symbol is disassembled again, discarding
+    the diagnostics registered while doing so.
+
+    Must be called after the symbol has been analyzed.
+    """
+
+    result = _getUnverifiableResult(sym)
+    if result is not None:
+        return result
+
+    if asmText is None:
+        asmText = _disassembleWithoutDiagnostics(sym)
+    if symbolsValues is None:
+        symbolsValues = _gatherSymbolsValues(sym.context)
+    return _verifySymbolText(sym, asmText, _DataAssembler(dict(symbolsValues), sym.stringEncoding))
+
+def verifySections(sections: list[FileBase], asmTexts: dict[int, str]|None=None) -> RoundTripResult:
+    """
+    Runs `verifySymbol` on every symbol of the passed sections and merges
+    the results.
+
+    `asmTexts` maps the vrom of each symbol to its already generated
+    assembly. Symbols missing from it are disassembled again, discarding the
+    diagnostics registered while doing so.
+
+    Must be called after every passed section has been analyzed.
+    """
+
+    result = RoundTripResult()
+    assembler: _DataAssembler|None = None
+    for section in sections:
+        for sym in section.symbolList:
+            symResult = _getUnverifiableResult(sym)
+            if symResult is None:
+                if assembler is None:
+                    assembler = _DataAssembler(_gatherSymbolsValues(section.context), sym.stringEncoding)
+                asmText = asmTexts.get(sym.vromStart) if asmTexts is not None else None
+                if asmText is None:
+                    asmText = _disassembleWithoutDiagnostics(sym)
+                symResult = _verifySymbolText(sym, asmText, assembler)
+            result.extend(symResult)
+    return result
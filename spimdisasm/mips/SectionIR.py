@@ -0,0 +1,284 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import json
+from pathlib import Path
+from typing import Any
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+IR_FORMAT_VERSION = 1
+"""
+Version of the JSON IR format.
+
+Bumped every time a field is removed or its meaning changes. Adding new fields
+does not bump the version.
+"""
+
+
+@dataclasses.dataclass
+class RelocationIR:
+    """
+    A relocation applied to a single word of a symbol.
+
+    JSON representation:
+    ```json
+    {"offset": int, "type": str, "symbol": str, "addend": int}
+    ```
+
+    `offset` is relative to the start of the symbol, `type` is the name of the
+    relocation (`R_MIPS_HI16`, `R_MIPS_32`, ...).
+    """
+
+    offset: int
+    relocType: str
+    symbol: str
+    addend: int
+
+    @staticmethod
+    def fromRelocationInfo(offset: int, relocInfo: common.RelocationInfo) -> RelocationIR:
+        if isinstance(relocInfo.symbol, common.ContextSymbol):
+            name = relocInfo.symbol.getName()
+        else:
+            name = relocInfo.symbol
+        return RelocationIR(offset, relocInfo.relocType.name, name, relocInfo.addend)
+
+    def toJson(self) -> dict[str, Any]:
+        return {
+            "offset": self.offset,
+            "type": self.relocType,
+            "symbol": self.symbol,
+            "addend": self.addend,
+        }
+
+
+@dataclasses.dataclass
+class InstructionIR:
+    """
+    A single disassembled instruction.
+
+    JSON representation:
+    ```json
+    {"offset": int, "raw": int, "mnemonic": str, "operands": list[str]}
+    ```
+
+    Operands are rendered the same way as the assembly output, including
+    symbols and relocation operators (`%hi(sym)`).
+    """
+
+    offset: int
+    raw: int
+    mnemonic: str
+    operands: list[str]
+
+    def toJson(self) -> dict[str, Any]:
+        return {
+            "offset": self.offset,
+            "raw": self.raw,
+            "mnemonic": self.mnemonic,
+            "operands": self.operands,
+        }
+
+
+@dataclasses.dataclass
+class DataWordIR:
+    """
+    A single word of a data symbol.
+
+    JSON representation:
+    ```json
+    {"offset": int, "raw": int, "reference": str|null}
+    ```
+
+    `reference` is the `sym + 0xNN` expression this word was symbolized as, or
+    `null` if the word is emitted as a raw value.
+    """
+
+    offset: int
+    raw: int
+    reference: str|None
+
+    def toJson(self) -> dict[str, Any]:
+        return {
+            "offset": self.offset,
+            "raw": self.raw,
+            "reference": self.reference,
+        }
+
+
+@dataclasses.dataclass
+class SymbolIR:
+    """
+    A symbol of a section.
+
+    JSON representation:
+    ```json
+    {
+        "name": str,
+        "vram": int,
+        "vrom": int|null,
+        "size": int,
+        "type": str,
+        "isFunction": bool,
+        "isUserDeclared": bool,
+        "relocs": list[RelocationIR],
+        "instructions": list[InstructionIR],
+        "words": list[DataWordIR],
+        "strictHash": str|null,
+        "maskedHash": str|null
+    }
+    ```
+
+    `instructions` is only non empty for functions, while `words` is only non
+    empty for non-function symbols of sections with actual data (not bss).
+
+    `strictHash` and `maskedHash` are only set for functions, see
+    `SymbolFunction.getStrictHash` and `SymbolFunction.getSignatureHash`.
+    """
+
+    name: str
+    vram: int
+    vrom: int|None
+    size: int
+    symType: str
+    isFunction: bool
+    isUserDeclared: bool
+    relocs: list[RelocationIR] = dataclasses.field(default_factory=list)
+    instructions: list[InstructionIR] = dataclasses.field(default_factory=list)
+    words: list[DataWordIR] = dataclasses.field(default_factory=list)
+    strictHash: str|None = None
+    maskedHash: str|None = None
+
+    @staticmethod
+    def fromSymbol(sym: symbols.SymbolBase) -> SymbolIR:
+        contextSym = sym.contextSym
+        vrom = sym.vromStart if sym.sectionType != common.FileSectionType.Bss else None
+        symIR = SymbolIR(sym.getName(), sym.vram, vrom, sym.sizew * 4, contextSym.getType(), sym.isFunction(), contextSym.isUserDeclared)
+
+        if isinstance(sym, symbols.SymbolFunction):
+            for i, instr in enumerate(sym.instructions):
+                offset = i * 4
+                immOverride, relocInfo = sym._getImmOverrideForInstruction(instr, offset)
+                if relocInfo is not None and not relocInfo.isRelocNone():
+                    symIR.relocs.append(RelocationIR.fromRelocationInfo(offset, relocInfo))
+                line = instr.disassemble(immOverride).strip()
+                mnemonic, _, operands = line.partition(" ")
+                operandsList = [x.strip() for x in operands.split(",")] if operands.strip() else []
+                symIR.instructions.append(InstructionIR(offset, instr.getRaw(), mnemonic, operandsList))
+            symIR.strictHash = sym.getStrictHash()
+            symIR.maskedHash = sym.getSignatureHash()
+            return symIR
+
+        if isinstance(sym, symbols.SymbolBss):
+            return symIR
+
+        canReferenceSymbolsWithAddends = sym.canUseAddendsOnData()
+        for i, word in enumerate(sym.words):
+            offset = i * 4
+            reference: str|None = None
+            relocInfo = sym.getReloc(offset, None)
+            if relocInfo is not None:
+                if not relocInfo.isRelocNone():
+                    symIR.relocs.append(RelocationIR.fromRelocationInfo(offset, relocInfo))
+                    reference = relocInfo.getName()
+            elif not sym.context.isRawDataAddress(sym.getVramOffset(offset)) and not sym.context.isAddressBanned(word):
+                symbolRef = sym.getSymbol(word, tryPlusOffset=canReferenceSymbolsWithAddends)
+                if symbolRef is not None and sym._allowWordSymbolReference(symbolRef, word):
+                    reference = symbolRef.getSymbolPlusOffset(word)
+            symIR.words.append(DataWordIR(offset, word, reference))
+
+        return symIR
+
+    def toJson(self) -> dict[str, Any]:
+        return {
+            "name": self.name,
+            "vram": self.vram,
+            "vrom": self.vrom,
+            "size": self.size,
+            "type": self.symType,
+            "isFunction": self.isFunction,
+            "isUserDeclared": self.isUserDeclared,
+            "relocs": [x.toJson() for x in self.relocs],
+            "instructions": [x.toJson() for x in self.instructions],
+            "words": [x.toJson() for x in self.words],
+            "strictHash": self.strictHash,
+            "maskedHash": self.maskedHash,
+        }
+
+
+@dataclasses.dataclass
+class SectionIR:
+    """
+    Structured representation of an analyzed section, meant to be consumed by
+    external tools (web viewers, scripts, etc) without having to parse the
+    generated assembly.
+
+    JSON representation:
+    ```json
+    {
+        "name": str,
+        "sectionType": str,
+        "vromStart": int,
+        "vromEnd": int,
+        "vram": int,
+        "symbols": list[SymbolIR]
+    }
+    ```
+
+    The recommended way to instance this class is by using
+    `SectionIR.fromSection` after the section has been analyzed.
+    """
+
+    name: str
+    sectionType: common.FileSectionType
+    vromStart: int
+    vromEnd: int
+    vram: int
+    symbols: list[SymbolIR] = dataclasses.field(default_factory=list)
+
+    @staticmethod
+    def fromSection(section: FileBase) -> SectionIR:
+        sectionIR = SectionIR(section.getName(), section.sectionType, section.vromStart, section.vromEnd, section.vram)
+        for sym in section.symbolList:
+            sectionIR.symbols.append(SymbolIR.fromSymbol(sym))
+        return sectionIR
+
+    def toJson(self) -> dict[str, Any]:
+        return {
+            "name": self.name,
+            "sectionType": self.sectionType.toStr(),
+            "vromStart": self.vromStart,
+            "vromEnd": self.vromEnd,
+            "vram": self.vram,
+            "symbols": [x.toJson() for x in self.symbols],
+        }
+
+
+def writeSectionsIRToFile(sections: list[FileBase], path: Path) -> None:
+    """
+    Writes the JSON IR of every passed section to `path`.
+
+    The top level object has the following shape:
+    ```json
+    {"version": int, "sections": list[SectionIR]}
+    ```
+    """
+
+    output = {
+        "version": IR_FORMAT_VERSION,
+        "sections": [SectionIR.fromSection(x).toJson() for x in sections],
+    }
+
+    path.parent.mkdir(parents=True, exist_ok=True)
+    with path.open("w") as f:
+        json.dump(output, f, indent=2)
+        f.write("\n")
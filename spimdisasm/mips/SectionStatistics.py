@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+@dataclasses.dataclass
+class SectionStatistics:
+    """
+    Summary of the analysis results of a single section.
+
+    Meant to be used to build progress reports or dashboards without having to
+    parse the generated assembly.
+
+    The recommended way to instance this class is by using
+    `SectionStatistics.fromSection` after the section has been analyzed.
+    """
+
+    name: str
+    sectionType: common.FileSectionType
+    vromStart: int
+    vromEnd: int
+    vram: int
+    size: int
+
+    functions: int = 0
+    handwrittenFunctions: int = 0
+    dataSymbols: int = 0
+    "Any non function symbol, including jumptables and strings"
+    jumptables: int = 0
+    strings: int = 0
+    stringsGuessed: int = 0
+    "Strings which were not declared as such by the user"
+    userDeclaredSymbols: int = 0
+    autogeneratedSymbols: int = 0
+    instructions: int = 0
+    unpairedHis: int = 0
+    "`lui` instructions which could not be paired to any `%lo` instruction"
+    failedSymbolizations: int = 0
+    "Instructions which reference an address, but no symbol was found for it"
+    paddingBytes: int = 0
+    "Trailing padding of symbols and autogenerated pad symbols"
+
+    @staticmethod
+    def fromSection(section: FileBase) -> SectionStatistics:
+        stats = SectionStatistics(section.name, section.sectionType, section.vromStart, section.vromEnd, section.vram, section.sizew * 4)
+
+        for sym in section.symbolList:
+            contextSym = sym.contextSym
+
+            if contextSym.isUserDeclared:
+                stats.userDeclaredSymbols += 1
+            if contextSym.isAutogenerated:
+                stats.autogeneratedSymbols += 1
+
+            stats.paddingBytes += sym.countExtraPadding() * 4
+            if contextSym.isAutogeneratedPad():
+                stats.paddingBytes += sym.sizew * 4
+
+            if isinstance(sym, symbols.SymbolFunction):
+                stats.functions += 1
+                if sym.isLikelyHandwritten:
+                    stats.handwrittenFunctions += 1
+                stats.instructions += sym.nInstr
+                stats.unpairedHis += sym.countUnpairedHis()
+                stats.failedSymbolizations += sym.countFailedSymbolizations()
+                continue
+
+            stats.dataSymbols += 1
+            if sym.isJumpTable():
+                stats.jumptables += 1
+            if sym.isString():
+                stats.strings += 1
+                if contextSym.userDeclaredType is None:
+                    stats.stringsGuessed += 1
+
+        return stats
@@ -0,0 +1,126 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from .. import common
+
+from . import symbols
+from .MipsFileBase import FileBase
+
+
+class _Reporter:
+    "Registers `UNSYMBOLIZED_ADDRESS` diagnostics, skipping the ones already registered to the context"
+
+    def __init__(self, context: common.Context) -> None:
+        self.context = context
+        self.diagnostics: list[common.Diagnostic] = []
+        self.seen: set[tuple[int, int|None, str]] = {(x.vram, x.vromAddress, x.message) for x in context.getDiagnostics(common.DiagnosticKind.UNSYMBOLIZED_ADDRESS)}
+
+    def report(self, message: str, vram: int, vromAddress: int|None, symbolName: str) -> None:
+        key = (vram, vromAddress, message)
+        if key in self.seen:
+            return
+        self.seen.add(key)
+        self.diagnostics.append(self.context.addDiagnostic(common.DiagnosticKind.UNSYMBOLIZED_ADDRESS, message, vram, vromAddress, symbolName))
+
+
+def _isWordSymbolized(sym: symbols.SymbolBase, i: int) -> bool:
+    localOffset = i * 4
+    word = sym.words[i]
+
+    relocInfo = sym.getReloc(localOffset, None)
+    if relocInfo is not None:
+        return not relocInfo.isRelocNone()
+
+    if sym.contextSym.isJumpTable() or sym.contextSym.isGccExceptTable():
+        if sym.contextSym.jumpTableBase is not None:
+            # Relative entries are not affected by shifting
+            return True
+        return sym.getSymbol(word, tryPlusOffset=False) is not None
+
+    if sym.context.isAddressBanned(word):
+        return False
+    symbolRef = sym.getSymbol(word, tryPlusOffset=sym.canUseAddendsOnData())
+    if symbolRef is None:
+        return False
+    return sym._allowWordSymbolReference(symbolRef, word)
+
+def _checkDataSymbol(sym: symbols.SymbolBase, reporter: _Reporter) -> None:
+    if sym.isString() or sym.isPascalString():
+        return
+    if sym.isByte(0) or sym.isShort(0):
+        return
+
+    context = sym.context
+    i = 0
+    while i < len(sym.words):
+        if sym.isDouble(i) or sym.isDword(i):
+            i += 2
+            continue
+        if sym.isFloat(i):
+            i += 1
+            continue
+
+        word = sym.words[i]
+        currentVram = sym.getVramOffset(i * 4)
+        if context.totalVramRange.isInRange(word) and not context.isRawDataAddress(currentVram):
+            if not _isWordSymbolized(sym, i):
+                reporter.report(f"Word 0x{word:08X} points inside the known vram ranges but is not symbolized", currentVram, sym.getVromOffset(i * 4), sym.getName())
+        i += 1
+
+def _checkFunction(func: symbols.SymbolFunction, reporter: _Reporter) -> None:
+    context = func.context
+    instrAnalyzer = func.instrAnalyzer
+
+    for instrOffset, address in sorted(instrAnalyzer.symbolInstrOffset.items()):
+        if func.getSymbol(address, tryPlusOffset=True) is None:
+            reporter.report(f"Reference to 0x{address:08X} has no symbol", func.getVramOffset(instrOffset), func.getVromOffset(instrOffset), func.getName())
+
+    for instrOffset, constant in sorted(instrAnalyzer.constantInstrOffset.items()):
+        if context.totalVramRange.isInRange(constant):
+            reporter.report(f"Constant 0x{constant:08X} points inside the known vram ranges", func.getVramOffset(instrOffset), func.getVromOffset(instrOffset), func.getName())
+
+    for instrOffset, luiInstr in sorted(instrAnalyzer.luiInstrs.items()):
+        if instrOffset in instrAnalyzer.symbolHiInstrOffset or instrOffset in instrAnalyzer.constantHiInstrOffset:
+            continue
+        upperHalf = luiInstr.getProcessedImmediate() << 16
+        if context.totalVramRange.isInRange(upperHalf):
+            reporter.report(f"Unpaired `lui` with value 0x{upperHalf:08X} points inside the known vram ranges", func.getVramOffset(instrOffset), func.getVromOffset(instrOffset), func.getName())
+
+    for instrOffset in sorted(instrAnalyzer.rejectedjumpRegisterIntrOffset.keys()):
+        reporter.report("Rejected jumptable, its targets may not be symbolized", func.getVramOffset(instrOffset), func.getVromOffset(instrOffset), func.getName())
+
+def checkShiftability(sections: list[FileBase]) -> list[common.Diagnostic]:
+    """
+    Reports every place on the passed sections where a value pointing inside
+    the known vram ranges would be emitted as a raw number instead of a
+    symbol, which would make the generated assembly break if the code gets
+    shifted.
+
+    Every problem found is registered to the context as an
+    `UNSYMBOLIZED_ADDRESS` diagnostic and returned. Problems which were
+    already registered (i.e. by a previous call) are not registered nor
+    returned again.
+
+    This is only a verification pass, the generated assembly is not changed.
+
+    Must be called after every passed section has been analyzed.
+    """
+
+    if len(sections) == 0:
+        return []
+
+    reporter = _Reporter(sections[0].context)
+    for section in sections:
+        if section.sectionType == common.FileSectionType.Bss:
+            continue
+
+        for sym in section.symbolList:
+            if isinstance(sym, symbols.SymbolFunction):
+                _checkFunction(sym, reporter)
+            elif section.sectionType != common.FileSectionType.Text:
+                _checkDataSymbol(sym, reporter)
+    return reporter.diagnostics
@@ -0,0 +1,123 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import enum
+
+from . import sections
+from . import symbols
+
+
+class SymbolDiffKind(enum.Enum):
+    EQUAL   = enum.auto()
+    ADDED   = enum.auto()
+    "Only exists in the second section"
+    REMOVED = enum.auto()
+    "Only exists in the first section"
+    MOVED   = enum.auto()
+    "Same contents but placed at a different address"
+    RESIZED = enum.auto()
+    CHANGED = enum.auto()
+    "Same size but different contents"
+
+
+@dataclasses.dataclass
+class SymbolDiffEntry:
+    """
+    Symbol-level difference between two versions of the same section, for
+    example the same overlay in different releases of a game.
+
+    Symbols are paired by name.
+
+    The recommended way to instance this class is by using
+    `SymbolDiffEntry.diffSections`.
+    """
+
+    name: str
+    kind: SymbolDiffKind
+    symOne: symbols.SymbolBase|None = None
+    symTwo: symbols.SymbolBase|None = None
+
+    @property
+    def sizeDiff(self) -> int:
+        sizeOne = self.symOne.sizew * 4 if self.symOne is not None else 0
+        sizeTwo = self.symTwo.sizew * 4 if self.symTwo is not None else 0
+        return sizeTwo - sizeOne
+
+    def getWordsDiff(self) -> list[tuple[int, int|None, int|None]]:
+        """
+        Returns every offset (relative to the start of the symbol) where the
+        normalized words of both symbols differ, alongside the words of each
+        symbol. A word is `None` if a symbol is shorter than the other.
+
+        For functions the relocation-normalized instructions are compared (see
+        `SymbolFunction.getNormalizedWords`), so references to symbols which
+        were moved don't produce differences.
+        """
+
+        wordsOne = _getNormalizedWords(self.symOne)
+        wordsTwo = _getNormalizedWords(self.symTwo)
+
+        result: list[tuple[int, int|None, int|None]] = []
+        for i in range(max(len(wordsOne), len(wordsTwo))):
+            wordOne = wordsOne[i] if i < len(wordsOne) else None
+            wordTwo = wordsTwo[i] if i < len(wordsTwo) else None
+            if wordOne != wordTwo:
+                result.append((i*4, wordOne, wordTwo))
+        return result
+
+    @staticmethod
+    def diffSymbols(symOne: symbols.SymbolBase|None, symTwo: symbols.SymbolBase|None) -> SymbolDiffEntry:
+        assert symOne is not None or symTwo is not None
+
+        if symOne is None:
+            assert symTwo is not None
+            return SymbolDiffEntry(symTwo.getName(), SymbolDiffKind.ADDED, None, symTwo)
+        if symTwo is None:
+            return SymbolDiffEntry(symOne.getName(), SymbolDiffKind.REMOVED, symOne, None)
+
+        if symOne.sizew != symTwo.sizew:
+            kind = SymbolDiffKind.RESIZED
+        elif _getNormalizedWords(symOne) != _getNormalizedWords(symTwo):
+            kind = SymbolDiffKind.CHANGED
+        elif symOne.vram != symTwo.vram:
+            kind = SymbolDiffKind.MOVED
+        else:
+            kind = SymbolDiffKind.EQUAL
+        return SymbolDiffEntry(symOne.getName(), kind, symOne, symTwo)
+
+    @staticmethod
+    def diffSections(sectionOne: sections.SectionBase, sectionTwo: sections.SectionBase) -> list[SymbolDiffEntry]:
+        """
+        Pairs the symbols of both sections by name and compares them.
+
+        The returned list follows the order of the first section, with the
+        symbols only present on the second section placed at the end.
+        """
+
+        symsTwo: dict[str, symbols.SymbolBase] = {sym.getName(): sym for sym in sectionTwo.symbolList}
+
+        entries: list[SymbolDiffEntry] = []
+        seenNames: set[str] = set()
+        for symOne in sectionOne.symbolList:
+            name = symOne.getName()
+            seenNames.add(name)
+            entries.append(SymbolDiffEntry.diffSymbols(symOne, symsTwo.get(name)))
+
+        for name, symTwo in symsTwo.items():
+            if name not in seenNames:
+                entries.append(SymbolDiffEntry.diffSymbols(None, symTwo))
+
+        return entries
+
+
+def _getNormalizedWords(sym: symbols.SymbolBase|None) -> list[int]:
+    if sym is None:
+        return []
+    if isinstance(sym, symbols.SymbolFunction):
+        return sym.getNormalizedWords()
+    return list(sym.words)
@@ -9,10 +9,26 @@ from . import sections as sections
 from . import symbols as symbols
 
 from .FuncRodataEntry import FunctionRodataEntry as FunctionRodataEntry
+from .SymbolsDiff import SymbolDiffKind as SymbolDiffKind
+from .SymbolsDiff import SymbolDiffEntry as SymbolDiffEntry
 
 from . import FilesHandlers as FilesHandlers
 
 from .InstructionConfig import InstructionConfig as InstructionConfig
 from .MipsFileBase import FileBase as FileBase
 from .MipsFileBase import createEmptyFile as createEmptyFile
+from .SectionStatistics import SectionStatistics as SectionStatistics
+from .SectionIR import SectionIR as SectionIR
+from .SectionIR import SymbolIR as SymbolIR
+from .SectionIR import writeSectionsIRToFile as writeSectionsIRToFile
+from .LibultraStructs import detectLibultraStructs as detectLibultraStructs
+from .PointerTypes import propagatePointerTypes as propagatePointerTypes
+from .IndirectCalls import inferIndirectCallTargets as inferIndirectCallTargets
+from .ShiftabilityCheck import checkShiftability as checkShiftability
+from .OverlayCategory import OverlaySectionEntry as OverlaySectionEntry
+from .OverlayCategory import analyzeOverlayCategory as analyzeOverlayCategory
+from .RoundTripVerification import RoundTripMismatch as RoundTripMismatch
+from .RoundTripVerification import RoundTripResult as RoundTripResult
+from .RoundTripVerification import verifySymbol as verifySymbol
+from .RoundTripVerification import verifySections as verifySections
 from .MipsFileSplits import FileSplits as FileSplits
@@ -7,6 +7,7 @@ from __future__ import annotations
 
 from ... import common
 
+from .. import symbols
 from ..MipsFileBase import FileBase
 
 class SectionBase(FileBase):
@@ -14,6 +15,8 @@ class SectionBase(FileBase):
         super().__init__(context, vromStart, vromEnd, vram, filename, words, sectionType, segmentVromStart, overlayCategory)
 
         self.stringEncoding: str = common.GlobalConfig.DATA_STRING_ENCODING
+        self.charmap: common.Charmap|None = common.GlobalConfig.DATA_CHARMAP
+        "If not None, strings are detected and decoded with this charmap instead of `stringEncoding`"
         self.enableStringGuessing: bool = True
         """
         Allows to toggle string guessing at the section level.
@@ -22,6 +25,7 @@ class SectionBase(FileBase):
 
         This option is ignored if the global string guessing option is disabled.
         """
+        self._registeredStringsInPool: bool = False
 
         self.typeForOwnedSymbols: str|None = None
         """
@@ -49,11 +53,101 @@ class SectionBase(FileBase):
         8 bytes symbols.
         """
 
-    def checkWordIsASymbolReference(self, word: int) -> bool:
+        self.maxSymbolSize: int|None = None
+        """
+        If not `None`, overrides the global maximum size of the untyped
+        symbols of this section (`GlobalConfig.DATA_MAX_SYMBOL_SIZE` and
+        `GlobalConfig.RODATA_MAX_SYMBOL_SIZE`). 0 means no limit.
+        """
+
+        self.minimalAnalysis: bool|None = None
+        """
+        If not `None`, overrides `GlobalConfig.MINIMAL_DATA_ANALYSIS` for this
+        section.
+
+        Must be set before analyzing the section. The string pool analysis
+        (`GlobalConfig.STRING_POOL_ANALYSIS`) always uses the global setting,
+        since it happens when the section is created.
+        """
+
+        self.strictTextPointers: bool|None = None
+        """
+        If not `None`, overrides `GlobalConfig.RODATA_STRICT_TEXT_POINTERS`
+        for this section, which by default only applies to rodata sections.
+        """
+
+        self.isSmallSection: bool = False
+        """
+        This section is a gp-relative small section (`.sdata`, `.sbss`, etc).
+
+        Symbols owned by this section are marked as small, so `$gp` based
+        accesses to them are symbolized with `%gp_rel` instead of `%got`.
+        """
+
+    @staticmethod
+    def _checkCreationParameters(filename: str, sectionType: common.FileSectionType, vromStart: int, vromEnd: int, vram: int, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        "Raises a `SectionCreationError` if the section can't be created from the given parameters"
+
+        def error(reason: str) -> common.SectionCreationError:
+            return common.SectionCreationError(reason, filename, sectionType, vromStart, vromEnd, vram, vram + max(vromEnd - vromStart, 0), segmentVromStart, overlayCategory)
+
+        if vromStart < 0:
+            raise error("Negative rom start")
+        if vromStart > vromEnd:
+            raise error("The rom start is bigger than the rom end")
+        if vromEnd > len(array_of_bytes):
+            raise error(f"The section ends past the end of the input bytes (size 0x{len(array_of_bytes):X})")
+        if vram < 0:
+            raise error("Negative vram")
+
+    def isWordAllowedToBeSymbolized(self, wordVram: int) -> bool:
+        "Checks the word at the given vram was not declared as raw data, either by a raw data range or by its symbol"
+        if self.context.isRawDataAddress(wordVram):
+            return False
+
+        ownerSym = self.getSymbol(wordVram, tryPlusOffset=True)
+        if ownerSym is not None and not ownerSym.allowedToReferenceSymbols:
+            return False
+        return True
+
+    def usesStrictTextPointers(self) -> bool:
+        if self.strictTextPointers is not None:
+            return self.strictTextPointers
+        if self.sectionType == common.FileSectionType.Rodata:
+            return common.GlobalConfig.RODATA_STRICT_TEXT_POINTERS
+        return False
+
+    def _isAcceptableTextPointer(self, word: int) -> bool:
+        "Checks if the word points to the start of a known function or to one of its known labels. Words which don't point inside a known function are always acceptable"
+        funcSym = self.getSegmentForVram(word).getFunctionContaining(word)
+        if funcSym is None:
+            return True
+        if word % 4 != 0:
+            return False
+        return self.getSymbol(word, tryPlusOffset=False) is not None
+
+    def checkWordIsASymbolReference(self, word: int, wordVram: int|None=None) -> bool:
+        if wordVram is not None and not self.isWordAllowedToBeSymbolized(wordVram):
+            return False
+
+        segmentedVram = common.GlobalConfig.getSegmentedAddressVram(word)
+        if segmentedVram is not None:
+            word = segmentedVram
+        elif not self.context.totalVramRange.isInRange(word):
+            kseg0Vram = common.GlobalConfig.getKseg0AliasVram(word)
+            if kseg0Vram is not None and self.getSymbol(word, tryPlusOffset=False) is None:
+                word = kseg0Vram
+
         if not self.context.totalVramRange.isInRange(word):
             return False
         if self.context.isAddressBanned(word):
             return False
+        if not common.GlobalConfig.isPointerAllowed(word):
+            return False
+        if self.context.isSymbolCreationVetoed(word, common.SymbolCreationReason.DATA_POINTER):
+            return False
+        if self.usesStrictTextPointers() and not self._isAcceptableTextPointer(word):
+            return False
 
         contextSym = self.getSymbol(word, tryPlusOffset=True, checkUpperLimit=False)
         if contextSym is not None:
@@ -77,7 +171,7 @@ class SectionBase(FileBase):
             if relocInfo is None or relocInfo.staticReference is None:
                 continue
 
-            relocVram = relocInfo.staticReference.sectionVram + word
+            relocVram = relocInfo.staticReference.sectionVram + word + relocInfo.addend
             sectionType = relocInfo.staticReference.sectionType
             if self.sectionType == common.FileSectionType.Rodata and sectionType == common.FileSectionType.Text:
                 contextSym = self.addJumpTableLabel(relocVram, isAutogenerated=True)
@@ -86,9 +180,103 @@ class SectionBase(FileBase):
             contextSym._isStatic = True
 
 
+    def pruneUnreferencedSymbols(self) -> list[common.ContextSymbol]:
+        """
+        Merges every autogenerated symbol of this section which ended up not
+        being referenced by anything into the symbol before it, and removes it
+        from the context.
+
+        The first symbol of the section and autogenerated pads are always
+        kept, and symbols are never merged into one with a known size or type
+        (like jumptables, strings or floats). Only data and rodata sections
+        are pruned.
+
+        Since references may come from any other section, this should only be
+        called after every section has been analyzed.
+
+        Returns the removed symbols.
+        """
+
+        if self.sectionType not in {common.FileSectionType.Data, common.FileSectionType.Rodata}:
+            return []
+
+        pruned: list[common.ContextSymbol] = []
+        newSymbolList: list[symbols.SymbolBase] = []
+        for sym in self.symbolList:
+            if len(newSymbolList) == 0 or not sym.contextSym.isUnreferencedAutogenerated():
+                newSymbolList.append(sym)
+                continue
+
+            prevSym = newSymbolList[-1]
+            if prevSym.vramEnd != sym.vram or not self._canSymbolAbsorbNext(prevSym.contextSym):
+                newSymbolList.append(sym)
+                continue
+
+            if not sym.getSegment().deleteAutogeneratedSymbol(sym.vram, detachLinks=False):
+                newSymbolList.append(sym)
+                continue
+
+            prevSym.words = prevSym.words + sym.words
+            prevSym.vromEnd = sym.vromEnd
+            prevSym.contextSym.distanceToNextSymbol = prevSym.sizew * 4
+            self.symbolsVRams.discard(sym.vram)
+            pruned.append(sym.contextSym)
+
+        # A single walk over the context for every removed symbol
+        self.getSegment().detachDeletedSymbols(pruned)
+
+        self.symbolList = newSymbolList
+        return pruned
+
+    @staticmethod
+    def _canSymbolAbsorbNext(contextSym: common.ContextSymbol) -> bool:
+        if contextSym.hasUserDeclaredSize() or contextSym.autodetectedSize is not None:
+            return False
+        if contextSym.isJumpTable() or contextSym.isString() or contextSym.isPascalString():
+            return False
+        if contextSym.isFloat() or contextSym.isDouble():
+            return False
+        return True
+
+    def getFoldedPads(self) -> list[common.ContextSymbol]:
+        """
+        Returns the automatically generated pads of this section which are
+        emitted as trailing padding of their previous symbol instead of as
+        symbols on their own.
+
+        Always empty unless `GlobalConfig.FOLD_AUTOGENERATED_PADS` is enabled.
+        """
+        return [sym.contextSym for sym in self.symbolList if sym.isFoldedPad()]
+
+    def _checkSymbolsCrossingBoundary(self) -> None:
+        """
+        Reports the user declared symbols of this section whose size goes past
+        the end of the section, which usually means either the file split or
+        the declared size is wrong.
+
+        If `TRUNCATE_SYMBOLS_AT_SPLIT_BOUNDARY` is enabled then the size of
+        those symbols is truncated to fit inside this section.
+        """
+
+        sectionEnd = self.vramEnd
+        for symVram, contextSym in self.getSymbolsRange(self.vram, sectionEnd):
+            if contextSym.userDeclaredSize is None:
+                continue
+            symEnd = symVram + contextSym.userDeclaredSize
+            if symEnd <= sectionEnd:
+                continue
+
+            message = f"User declared size (0x{contextSym.userDeclaredSize:X}) ends at 0x{symEnd:08X}, past the end of the file split '{self.getName()}' (0x{sectionEnd:08X})"
+            if common.GlobalConfig.TRUNCATE_SYMBOLS_AT_SPLIT_BOUNDARY:
+                contextSym.userDeclaredSize = sectionEnd - symVram
+                message += f". Truncated to 0x{contextSym.userDeclaredSize:X}"
+            self.context.addDiagnostic(common.DiagnosticKind.SYMBOL_CROSSES_BOUNDARY, message, symVram, contextSym.vromAddress, contextSym.getName())
+
     def _checkAndCreateFirstSymbol(self) -> common.ContextSymbol:
         "Check if the very start of the file has a symbol and create it if it doesn't exist yet"
 
+        self._checkSymbolsCrossingBoundary()
+
         currentVram = self.getVramOffset(0)
         currentVrom = self.getVromOffsetNone(0)
 
@@ -102,6 +290,12 @@ class SectionBase(FileBase):
         if contextSym is None:
             return None
 
+        if contextSym.innerLabelOf is not None and self.sectionType != common.FileSectionType.Bss:
+            # Emitted as a label by the symbol containing it
+            return None
+
+        if self.isSmallSection:
+            contextSym.isSmallData = True
         if self.typeForOwnedSymbols is not None:
             contextSym.autodetectedType = self.typeForOwnedSymbols
         if self.sizeForOwnedSymbols is not None:
@@ -123,6 +317,8 @@ class SectionBase(FileBase):
         if contextSym.vram != currentVram:
             return None
 
+        if self.isSmallSection:
+            contextSym.isSmallData = True
         if self.typeForOwnedSymbols is not None:
             contextSym.autodetectedType = self.typeForOwnedSymbols
         if self.sizeForOwnedSymbols is not None:
@@ -165,44 +361,187 @@ class SectionBase(FileBase):
 
         return None
 
-    def _stringGuesser(self, contextSym: common.ContextSymbol, localOffset: int) -> bool:
-        if contextSym._ranStringCheck:
-            return contextSym.isMaybeString
-
-        if contextSym.isMaybeString or contextSym.isString():
-            return True
+    def usesMinimalAnalysis(self) -> bool:
+        if self.minimalAnalysis is not None:
+            return self.minimalAnalysis
+        return common.GlobalConfig.MINIMAL_DATA_ANALYSIS
+
+    def getMaxSymbolSize(self) -> int:
+        "Returns the maximum size of the untyped symbols of this section, or 0 if they have no limit"
+        if self.usesMinimalAnalysis():
+            return 0
+        if self.maxSymbolSize is not None:
+            return self.maxSymbolSize
+        if self.sectionType == common.FileSectionType.Data:
+            return common.GlobalConfig.DATA_MAX_SYMBOL_SIZE
+        if self.sectionType == common.FileSectionType.Rodata:
+            return common.GlobalConfig.RODATA_MAX_SYMBOL_SIZE
+        return 0
 
-        if not self.enableStringGuessing:
+    def _canChunkSymbol(self, contextSym: common.ContextSymbol) -> bool:
+        if not contextSym.isAutogenerated or contextSym.isUserDeclared or contextSym.hasUserDeclaredSize():
+            return False
+        if not contextSym.hasNoType() or contextSym.isString() or contextSym.isPascalString():
             return False
+        if contextSym.getReferenceCount() > 0 or len(contextSym.referenceLocations) > 0:
+            # Referenced symbols are probably accessed as a whole
+            return False
+        return True
+
+    def _chunkBigSymbols(self, symbolList: list[tuple[int, common.ContextSymbol]]) -> list[tuple[int, common.ContextSymbol]]:
+        """
+        Splits the untyped and unreferenced autogenerated symbols bigger than
+        `getMaxSymbolSize` into multiple symbols. The new symbols start at
+        addresses aligned to said size.
+
+        `symbolList` must be sorted.
+        """
+        chunkSize = self.getMaxSymbolSize() & ~3
+        if chunkSize <= 0:
+            return symbolList
+
+        sectionSize = self.sizew * 4
+        newSymbolList: list[tuple[int, common.ContextSymbol]] = []
+        for i, (offset, contextSym) in enumerate(symbolList):
+            newSymbolList.append((offset, contextSym))
+
+            nextOffset = symbolList[i+1][0] if i + 1 < len(symbolList) else sectionSize
+            if nextOffset - offset <= chunkSize:
+                continue
+            if not self._canChunkSymbol(contextSym):
+                continue
+
+            currentVram = self.getVramOffset(offset)
+            chunkOffset = offset + chunkSize - (currentVram % chunkSize)
+            while chunkOffset < nextOffset:
+                chunkSym = self._addOwnedSymbol(chunkOffset)
+                if chunkSym is not None:
+                    newSymbolList.append((chunkOffset, chunkSym))
+                chunkOffset += chunkSize
+        return newSymbolList
+
+    def registerStringsInPool(self) -> None:
+        """
+        Registers the strings of this section on the context-wide string pool.
+        See `GlobalConfig.STRING_POOL_ANALYSIS`.
+
+        Only the strings accepted by the string guesser settings of this
+        section are registered. Should be called after configuring the section
+        (for example its string encoding) and before analyzing any function.
+        It is called automatically when analyzing the section if it wasn't
+        called before. Calling it more than once does nothing.
+        """
+        if self._registeredStringsInPool:
+            return
+        self._registeredStringsInPool = True
+
+        if not common.GlobalConfig.STRING_POOL_ANALYSIS or not self.enableStringGuessing or self.usesMinimalAnalysis():
+            return
+        if self.sectionType not in {common.FileSectionType.Data, common.FileSectionType.Rodata}:
+            return
 
         if self.sectionType == common.FileSectionType.Rodata:
             stringGuesserLevel = common.GlobalConfig.RODATA_STRING_GUESSER_LEVEL
         else:
             stringGuesserLevel = common.GlobalConfig.DATA_STRING_GUESSER_LEVEL
 
-        if stringGuesserLevel < 1:
+        localOffset = 0
+        while localOffset < self.sizew * 4:
+            if self.words[localOffset//4] == 0:
+                localOffset += 4
+                continue
+
+            decodedString, rawStringSize = self._decodeString(localOffset)
+            if rawStringSize <= 0:
+                localOffset += 4
+                continue
+
+            if self._isStringAllowedInPool(localOffset, decodedString, rawStringSize, stringGuesserLevel):
+                entry = common.StringPoolEntry(self.getVramOffset(localOffset), self.getVromOffset(localOffset), rawStringSize + 1, decodedString, self.overlayCategory)
+                self.context.addStringToPool(entry)
+
+            # Strings are word aligned
+            localOffset += (rawStringSize + 1 + 3) & ~3
+
+    def _isStringAllowedInPool(self, localOffset: int, decodedString: str, rawStringSize: int, stringGuesserLevel: int) -> bool:
+        currentVram = self.getVramOffset(localOffset)
+        currentVrom = self.getVromOffset(localOffset)
+
+        rangeOverride = self.context.getStringGuessRangeOverride(currentVram)
+        if rangeOverride is False:
+            return False
+        if rangeOverride is None and stringGuesserLevel < 1:
             return False
 
-        if contextSym.referenceCounter > 1:
-            if stringGuesserLevel < 2:
+        contextSym = self.getSymbol(currentVram, vromAddress=currentVrom, tryPlusOffset=False)
+        if contextSym is not None and not contextSym.hasNoType() and not contextSym.isString():
+            if not contextSym.hasOnlyAutodetectedType() or stringGuesserLevel < 4:
+                # The symbol is known to not be a string
                 return False
 
-        # This would mean the string is an empty string, which is not very likely
-        if self.words[localOffset//4] == 0:
-            if stringGuesserLevel < 3:
-                return False
+        if not self._stringGuesserTuningCheck(localOffset, decodedString, rawStringSize):
+            return False
 
-        if contextSym.hasOnlyAutodetectedType():
-            if stringGuesserLevel < 4:
+        # A declared symbol in the middle means this is not a single string
+        for _, innerSym in self.getSymbolsRange(currentVram + 1, currentVram + rawStringSize + 1):
+            if innerSym.isUserDeclared:
                 return False
 
+        return True
+
+    def _decodeString(self, localOffset: int) -> tuple[str, int]:
+        if self.charmap is not None:
+            return self.charmap.decode(self.bytes, localOffset)
+        decodedStrings, rawStringSize = common.Utils.decodeBytesToStrings(self.bytes, localOffset, self.stringEncoding)
+        return "".join(decodedStrings), rawStringSize
+
+    def _stringGuesser(self, contextSym: common.ContextSymbol, localOffset: int) -> bool:
+        if contextSym._ranStringCheck:
+            return contextSym.isMaybeString
+
+        if contextSym.isMaybeString or contextSym.isString():
+            return True
+
+        if not self.enableStringGuessing or self.usesMinimalAnalysis():
+            return False
+
         currentVram = self.getVramOffset(localOffset)
         currentVrom = self.getVromOffset(localOffset)
-        _, rawStringSize = common.Utils.decodeBytesToStrings(self.bytes, localOffset, self.stringEncoding)
+
+        rangeOverride = self.context.getStringGuessRangeOverride(currentVram)
+        if rangeOverride is False:
+            return False
+
+        if rangeOverride is None:
+            if self.sectionType == common.FileSectionType.Rodata:
+                stringGuesserLevel = common.GlobalConfig.RODATA_STRING_GUESSER_LEVEL
+            else:
+                stringGuesserLevel = common.GlobalConfig.DATA_STRING_GUESSER_LEVEL
+
+            if stringGuesserLevel < 1:
+                return False
+
+            if contextSym.referenceCounter > 1:
+                if stringGuesserLevel < 2:
+                    return False
+
+            # This would mean the string is an empty string, which is not very likely
+            if self.words[localOffset//4] == 0:
+                if stringGuesserLevel < 3:
+                    return False
+
+            if contextSym.hasOnlyAutodetectedType():
+                if stringGuesserLevel < 4:
+                    return False
+
+        decodedString, rawStringSize = self._decodeString(localOffset)
         if rawStringSize < 0:
             # String can't be decoded
             return False
 
+        if not self._stringGuesserTuningCheck(localOffset, decodedString, rawStringSize):
+            return False
+
         # Check if there is already another symbol after the current one and before the end of the string,
         # in which case we say this symbol should not be a string
         otherSym = self.getSymbol(currentVram + rawStringSize, vromAddress=currentVrom + rawStringSize, checkUpperLimit=False, checkGlobalSegment=False)
@@ -211,6 +550,38 @@ class SectionBase(FileBase):
 
         return True
 
+    def _stringGuesserTuningCheck(self, localOffset: int, decodedString: str, rawStringSize: int) -> bool:
+        if self.charmap is not None:
+            decodedChars = decodedString
+            terminator = self.charmap.terminator
+        else:
+            # `decodedString` has its special characters escaped, so decode again to count the real characters
+            decodedChars = self.bytes[localOffset:localOffset+rawStringSize].decode(self.stringEncoding, errors="replace")
+            terminator = 0
+
+        minLength = common.GlobalConfig.STRING_GUESSER_MIN_LENGTH
+        if len(decodedChars) < minLength:
+            return False
+        maxLength = common.GlobalConfig.STRING_GUESSER_MAX_LENGTH
+        if maxLength > 0 and len(decodedChars) > maxLength:
+            return False
+
+        characterClass = common.GlobalConfig.STRING_GUESSER_CHARACTER_CLASS
+        if characterClass != common.StringGuesserCharacterClass.ANY:
+            for char in decodedChars:
+                if not characterClass.isValidChar(char):
+                    return False
+
+        alignment = common.GlobalConfig.STRING_GUESSER_TERMINATOR_ALIGNMENT
+        if alignment > 1:
+            # The terminator itself is at `localOffset + rawStringSize`
+            currentVram = self.getVramOffset(localOffset)
+            paddingEnd = (currentVram + rawStringSize + 1 + alignment - 1) // alignment * alignment - currentVram
+            if any(x != terminator for x in self.bytes[localOffset+rawStringSize:localOffset+paddingEnd]):
+                return False
+
+        return True
+
     def _pascalStringGuesser(self, contextSym: common.ContextSymbol, localOffset: int) -> bool:
         if contextSym._ranPascalStringCheck:
             return contextSym.isMaybePascalString
@@ -218,7 +589,7 @@ class SectionBase(FileBase):
         if contextSym.isMaybePascalString or contextSym.isPascalString():
             return True
 
-        if not self.enableStringGuessing:
+        if not self.enableStringGuessing or self.usesMinimalAnalysis():
             return False
 
         if self.sectionType == common.FileSectionType.Rodata:
@@ -0,0 +1,115 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from pathlib import Path
+import sys
+
+from ... import common
+
+from .. import symbols
+
+from . import SectionBase
+
+
+class SectionBin(SectionBase):
+    """
+    Section for raw blobs which should not be decoded, like textures or other
+    assets embedded in a segment.
+
+    The bytes are emitted either as an hex dump or as `.incbin` directives
+    (see `incbinPath`). Addresses of this section still take part in symbol
+    lookup, so pointers into it get proper labels.
+    """
+
+    def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None, sectionType: common.FileSectionType=common.FileSectionType.Bin) -> None:
+        self._checkCreationParameters(filename, sectionType, vromStart, vromEnd, vram, array_of_bytes, segmentVromStart, overlayCategory)
+
+        rawBytes = array_of_bytes[vromStart:vromEnd]
+        # Words are kept for compatibility with the other sections, but the size of a blob doesn't need to be word aligned
+        alignedEnd = vromStart + (len(rawBytes) & ~3)
+        super().__init__(context, vromStart, vromEnd, vram, filename, common.Utils.bytesToWords(array_of_bytes, vromStart, alignedEnd), sectionType, segmentVromStart, overlayCategory)
+
+        self.rawBytes: bytes = rawBytes
+        self.bytes = rawBytes
+
+        self.incbinPath: str|None = None
+        """
+        If not `None` then every symbol of this section is emitted as an
+        `.incbin` directive of the given path instead of an hex dump.
+
+        The path should point to a file with the contents of this section, like
+        the one written when `GlobalConfig.WRITE_BINARY` is enabled.
+
+        Must be set before calling `analyze`.
+        """
+
+    @property
+    def vramEnd(self) -> int:
+        return self.vram + len(self.rawBytes)
+
+
+    def registerSymbols(self) -> None:
+        """
+        Adds the symbols of this section to the context before splitting it.
+        Does nothing by default.
+
+        User relocs (`Context.globalRelocationOverrides`) have already been
+        applied to the words of the section when this is called.
+        """
+        pass
+
+    def createSymbol(self, localOffset: int, localOffsetEnd: int) -> symbols.SymbolBase:
+        "Creates the symbol covering `[localOffset, localOffsetEnd)` of this section"
+        vrom = self.getVromOffset(localOffset)
+        vromEnd = vrom + localOffsetEnd - localOffset
+        sym = symbols.SymbolBin(self.context, vrom, vromEnd, localOffset + self.inFileOffset, self.vram + localOffset, self.rawBytes[localOffset:localOffsetEnd], self.segmentVromStart, self.overlayCategory)
+        sym.incbinPath = self.incbinPath
+        sym.incbinOffset = localOffset
+        return sym
+
+    def getFileSuffix(self) -> str:
+        "The suffix appended to the path passed to `saveToFile`"
+        return self.sectionType.toStr()
+
+    def analyze(self) -> None:
+        self._checkAndCreateFirstSymbol()
+
+        self.processStaticRelocs()
+        self.registerSymbols()
+
+        # Anything pointing into the blob gets a label
+        for ptr in self.getAndPopPointerInDataReferencesRange(self.vram, self.vramEnd):
+            if self.getSymbol(ptr, tryPlusOffset=False) is None:
+                self.addSymbol(ptr, sectionType=self.sectionType, isAutogenerated=True, symbolVrom=self.getVromOffset(ptr - self.vram))
+
+        symbolOffsets = sorted({symVram - self.vram for symVram, _ in self.getSymbolsRange(self.vram, self.vramEnd)})
+
+        for i, offset in enumerate(symbolOffsets):
+            end = symbolOffsets[i+1] if i + 1 < len(symbolOffsets) else len(self.rawBytes)
+
+            sym = self.createSymbol(offset, end)
+            sym.parent = self
+            sym.setCommentOffset(self.commentOffset)
+            sym.contextSym.distanceToNextSymbol = end - offset
+            sym.analyze()
+            self.symbolList.append(sym)
+
+            self.symbolsVRams.add(sym.vram)
+
+
+    def saveToFile(self, filepath: str) -> None:
+        if len(self.symbolList) == 0:
+            return
+
+        if filepath == "-":
+            self.disassembleToFile(sys.stdout)
+        else:
+            suffix = self.getFileSuffix()
+            if common.GlobalConfig.WRITE_BINARY and len(self.rawBytes) > 0:
+                common.Utils.writeBytesToFile(Path(filepath + suffix), self.rawBytes)
+            with open(filepath + suffix + ".s", "w", encoding="utf-8") as f:
+                self.disassembleToFile(f)
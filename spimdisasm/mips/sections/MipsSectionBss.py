@@ -14,6 +14,9 @@ from . import SectionBase
 
 class SectionBss(SectionBase):
     def __init__(self, context: common.Context, vromStart: int, vromEnd: int, bssVramStart: int, bssVramEnd: int, filename: str, segmentVromStart: int, overlayCategory: str|None) -> None:
+        if bssVramStart > bssVramEnd:
+            raise common.SectionCreationError("The bss vram start is bigger than the bss vram end", filename, common.FileSectionType.Bss, vromStart, vromEnd, bssVramStart, bssVramEnd, segmentVromStart, overlayCategory)
+
         super().__init__(context, vromStart, vromEnd, bssVramStart, filename, [], common.FileSectionType.Bss, segmentVromStart, overlayCategory)
 
         self.bssVramStart: int = bssVramStart
@@ -35,6 +38,43 @@ class SectionBss(SectionBase):
         self.bssVramStart = vram
         self.bssVramEnd = vram + self.bssTotalSize
 
+    def _findAlignmentGaps(self, sortedOffsets: list[int]) -> list[int]:
+        """
+        Returns the offsets of the alignment gaps which should become their own
+        pad symbols.
+
+        A gap is only considered if the size of the symbol can be inferred from
+        its type, it has no user-declared size, and the distance to the next
+        symbol is smaller than the alignment of the next symbol.
+        """
+        gaps: list[int] = []
+        for i, symbolOffset in enumerate(sortedOffsets):
+            if i + 1 >= len(sortedOffsets):
+                break
+            nextSymbolOffset = sortedOffsets[i+1]
+
+            contextSym = self.getSymbol(self.bssVramStart + symbolOffset, tryPlusOffset=False)
+            if contextSym is None or contextSym.hasUserDeclaredSize() or contextSym.isAutoCreatedPad:
+                continue
+            typeSize = contextSym.getSizeFromType()
+            if typeSize is None or typeSize <= 0:
+                continue
+
+            padOffset = symbolOffset + typeSize
+            gapSize = nextSymbolOffset - padOffset
+            if gapSize <= 0:
+                continue
+
+            nextVram = self.bssVramStart + nextSymbolOffset
+            nextAlignment = nextVram & -nextVram
+            if gapSize >= nextAlignment:
+                # Bigger than any alignment the next symbol may require, so it
+                # probably is part of this symbol
+                continue
+            gaps.append(padOffset)
+
+        return gaps
+
     def analyze(self) -> None:
         self._checkAndCreateFirstSymbol()
 
@@ -52,6 +92,8 @@ class SectionBss(SectionBase):
         for symbolVram, contextSym in self.getSymbolsRange(self.bssVramStart, self.bssVramEnd):
             # Mark every known symbol that happens to be in this address space as defined
             contextSym.sectionType = common.FileSectionType.Bss
+            if self.isSmallSection:
+                contextSym.isSmallData = True
 
             # Needs to move this to a list because the algorithm requires to check the size of a bss variable based on the next bss variable' vram
             assert symbolVram >= self.bssVramStart
@@ -68,6 +110,10 @@ class SectionBss(SectionBase):
                     bssSymbolOffsets.add(symOffset)
                     autoCreatedPads.add(symOffset)
 
+        if common.GlobalConfig.CREATE_BSS_ALIGNMENT_PADS:
+            for padOffset in self._findAlignmentGaps(sorted(bssSymbolOffsets)):
+                bssSymbolOffsets.add(padOffset)
+                autoCreatedPads.add(padOffset)
 
         sortedOffsets = sorted(bssSymbolOffsets)
 
@@ -90,9 +136,12 @@ class SectionBss(SectionBase):
             sym = symbols.SymbolBss(self.context, vrom, vromEnd, symbolOffset + self.inFileOffset, symbolVram, space, self.segmentVromStart, self.overlayCategory)
             sym.parent = self
             sym.contextSym.autodetectedSize = space
+            sym.contextSym.distanceToNextSymbol = space
             sym.setCommentOffset(self.commentOffset)
             if symbolOffset in autoCreatedPads:
                 sym.contextSym.isAutoCreatedPad = True
+                if sym.contextSym.autoCreatedPadMainSymbol is None and i > 0:
+                    sym.contextSym.autoCreatedPadMainSymbol = self.getSymbol(self.bssVramStart + sortedOffsets[i-1], tryPlusOffset=False)
             sym.analyze()
             self.symbolList.append(sym)
 
@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from typing import Callable
+
+from ... import common
+
+from .MipsSectionBin import SectionBin
+
+
+class SectionCustom(SectionBin):
+    """
+    Base class for user defined section kinds, like an index of compressed
+    assets, allowing them to take part in the same pipeline as the built-in
+    sections.
+
+    Subclasses customize the analysis by overriding:
+
+    - `registerSymbols`: Adds the symbols of this section to the context, for
+      example one per entry of a table. User relocs
+      (`Context.globalRelocationOverrides`) have already been applied to the
+      words of the section when this is called.
+    - `createSymbol`: Creates the symbol which will disassemble each of the
+      symbols found on this section. By default those are emitted as raw
+      bytes, like `SectionBin` does.
+
+    Register the subclass with `registerCustomSectionKind` to allow split
+    files to use it.
+    """
+
+    def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None, kind: str) -> None:
+        super().__init__(context, vromStart, vromEnd, vram, filename, array_of_bytes, segmentVromStart, overlayCategory, sectionType=common.FileSectionType.Custom)
+
+        self.kind: str = kind
+        "The name of this section kind, like `.assetidx`"
+
+        self.customSectionName = kind
+
+
+    def getFileSuffix(self) -> str:
+        return self.kind
+
+
+CustomSectionFactory = Callable[[common.Context, int, int, int, str, bytes, int, "str|None", str], SectionCustom]
+"""
+Creates a custom section. Receives the same parameters as the constructor of
+`SectionCustom`, so any subclass can be used as a factory.
+"""
+
+_customSectionKinds: dict[str, CustomSectionFactory] = dict()
+
+def registerCustomSectionKind(kind: str, factory: CustomSectionFactory) -> None:
+    """
+    Registers a custom section kind. Split files can then use `kind` (like
+    `.assetidx`) as a section name, and `FilesHandlers.createSectionFromSplitEntry`
+    will use `factory` to create those sections.
+    """
+    common.FileSectionType.registerCustomKind(kind)
+    _customSectionKinds[kind] = factory
+
+def getCustomSectionKindFactory(kind: str) -> CustomSectionFactory|None:
+    return _customSectionKinds.get(kind)
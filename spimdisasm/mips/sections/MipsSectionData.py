@@ -14,6 +14,8 @@ from . import SectionBase
 
 class SectionData(SectionBase):
     def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        self._checkCreationParameters(filename, common.FileSectionType.Data, vromStart, vromEnd, vram, array_of_bytes, segmentVromStart, overlayCategory)
+
         if common.GlobalConfig.ENDIAN_DATA is not None:
             words = common.Utils.endianessBytesToWords(common.GlobalConfig.ENDIAN_DATA, array_of_bytes, vromStart, vromEnd)
         else:
@@ -22,6 +24,7 @@ class SectionData(SectionBase):
 
 
     def analyze(self) -> None:
+        self.registerStringsInPool()
         self._checkAndCreateFirstSymbol()
 
         symbolList: list[tuple[int, common.ContextSymbol]] = []
@@ -44,7 +47,7 @@ class SectionData(SectionBase):
                     symbolList.append((localOffset, contextSym))
                     localOffsetsWithSymbols.add(localOffset)
 
-            if self.checkWordIsASymbolReference(w):
+            if self.checkWordIsASymbolReference(w, currentVram):
                 if w < currentVram and self.containsVram(w):
                     # References a data symbol from this section and it is behind this current symbol
                     needsFurtherAnalyzis = True
@@ -67,6 +70,8 @@ class SectionData(SectionBase):
             # Since we appended new symbols, this list is not sorted anymore
             symbolList.sort()
 
+        symbolList = self._chunkBigSymbols(symbolList)
+
         self.processStaticRelocs()
 
         for i, (offset, contextSym) in enumerate(symbolList):
@@ -84,6 +89,8 @@ class SectionData(SectionBase):
             sym.parent = self
             sym.setCommentOffset(self.commentOffset)
             sym.stringEncoding = self.stringEncoding
+            sym.charmap = self.charmap
+            sym.contextSym.distanceToNextSymbol = len(words)*4
             sym.analyze()
             self.symbolList.append(sym)
 
@@ -33,6 +33,8 @@ class SectionGccExceptTable(SectionBase):
     """
 
     def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        self._checkCreationParameters(filename, common.FileSectionType.GccExceptTable, vromStart, vromEnd, vram, array_of_bytes, segmentVromStart, overlayCategory)
+
         super().__init__(context, vromStart, vromEnd, vram, filename, common.Utils.bytesToWords(array_of_bytes, vromStart, vromEnd), common.FileSectionType.GccExceptTable, segmentVromStart, overlayCategory)
 
 
@@ -78,6 +80,16 @@ class SectionGccExceptTable(SectionBase):
                 return None, firstExceptTableWord
 
             labelSym.referenceCounter += 1
+            if exceptTableSym is not None:
+                labelSym.referenceSymbols.add(exceptTableSym)
+                labelSym.addReferenceLocation(exceptTableSym, self.getVromOffset(localOffset), isFromFunction=False)
+
+            if labelSym.parentFunction is None:
+                funcSym = self.getSegmentForVram(labelAddr).getFunctionContaining(labelAddr)
+                if funcSym is not None and funcSym is not labelSym:
+                    labelSym.parentFunction = funcSym
+                    labelSym.parentFileName = funcSym.parentFileName
+                    funcSym.exceptTableLabels.add(labelSym.vram, labelSym)
 
         return exceptTableSym, firstExceptTableWord
 
@@ -130,7 +142,7 @@ class SectionGccExceptTable(SectionBase):
                     if contextSym is not None:
                         lastVramSymbol = contextSym
 
-                self.checkWordIsASymbolReference(w)
+                self.checkWordIsASymbolReference(w, currentVram)
 
             if contextSym is not None:
                 self.symbolsVRams.add(currentVram)
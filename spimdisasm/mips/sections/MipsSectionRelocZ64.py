@@ -46,6 +46,10 @@ class RelocEntry:
 
 class SectionRelocZ64(SectionBase):
     def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        self._checkCreationParameters(filename, common.FileSectionType.Reloc, vromStart, vromEnd, vram, array_of_bytes, segmentVromStart, overlayCategory)
+        if vromEnd - vromStart < 4:
+            raise common.SectionCreationError("The section is too small to contain the reloc seekup", filename, common.FileSectionType.Reloc, vromStart, vromEnd, vram, None, segmentVromStart, overlayCategory)
+
         super().__init__(context, vromStart, vromEnd, vram, filename, common.Utils.bytesToWords(array_of_bytes, vromStart, vromEnd), common.FileSectionType.Reloc, segmentVromStart, overlayCategory)
 
         self.seekup: int = self.words[-1]
@@ -16,6 +16,8 @@ from . import SectionBase
 
 class SectionRodata(SectionBase):
     def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        self._checkCreationParameters(filename, common.FileSectionType.Rodata, vromStart, vromEnd, vram, array_of_bytes, segmentVromStart, overlayCategory)
+
         if common.GlobalConfig.ENDIAN_RODATA is not None:
             words = common.Utils.endianessBytesToWords(common.GlobalConfig.ENDIAN_RODATA, array_of_bytes, vromStart, vromEnd)
         else:
@@ -23,13 +25,23 @@ class SectionRodata(SectionBase):
         super().__init__(context, vromStart, vromEnd, vram, filename, words, common.FileSectionType.Rodata, segmentVromStart, overlayCategory)
 
         self.stringEncoding = common.GlobalConfig.RODATA_STRING_ENCODING
+        self.charmap = common.GlobalConfig.RODATA_CHARMAP
+
 
+    def _isInParentFunction(self, jumpTableSym: common.ContextSymbol, address: int) -> bool:
+        parentFunction = jumpTableSym.parentFunction
+        if parentFunction is None:
+            return False
+        return parentFunction.vram <= address < parentFunction.vram + parentFunction.getSize()
 
     def _analyze_processJumptable(self, localOffset: int, w: int, contextSym: common.ContextSymbol|None, lastVramSymbol: common.ContextSymbol, jumpTableSym: common.ContextSymbol|None, firstJumptableWord: int) -> tuple[common.ContextSymbol|None, int]:
         if contextSym is not None and contextSym.isJumpTable():
             # New jumptable
             jumpTableSym = contextSym
             firstJumptableWord = w
+            if common.GlobalConfig.DETECT_RELATIVE_JUMPTABLES and jumpTableSym.jumpTableBase is None:
+                if not self._isInParentFunction(jumpTableSym, w) and self._isInParentFunction(jumpTableSym, jumpTableSym.vram + rabbitizer.Utils.from2Complement(w, 32)):
+                    jumpTableSym.jumpTableBase = jumpTableSym
 
         elif jumpTableSym is not None:
             # The last symbol found was part of a jumptable, check if this word still is part of the jumptable
@@ -41,6 +53,10 @@ class SectionRodata(SectionBase):
                 elif contextSym is not None:
                     return None, firstJumptableWord
 
+                elif jumpTableSym.jumpTableBase is not None:
+                    if not self._isInParentFunction(jumpTableSym, jumpTableSym.jumpTableBase.vram + rabbitizer.Utils.from2Complement(w, 32)):
+                        return None, firstJumptableWord
+
                 elif ((w >> 24) & 0xFF) != ((firstJumptableWord >> 24) & 0xFF):
                     if not (
                         lastVramSymbol.isJumpTable()
@@ -53,17 +69,41 @@ class SectionRodata(SectionBase):
             return None, firstJumptableWord
 
         # Generate the current label
-        if lastVramSymbol.isGot and common.GlobalConfig.GP_VALUE is not None:
+        if jumpTableSym.jumpTableBase is not None:
+            labelAddr = jumpTableSym.jumpTableBase.vram + rabbitizer.Utils.from2Complement(w, 32)
+            labelVrom = None
+        elif lastVramSymbol.isGot and common.GlobalConfig.GP_VALUE is not None:
             labelAddr = common.GlobalConfig.GP_VALUE + rabbitizer.Utils.from2Complement(w, 32)
             labelVrom = None
         else:
             labelAddr = w
-            maybeVrom = self.vromStart + labelAddr - self.vram
             segment = self.getSegmentForVrom(self.segmentVromStart)
-            if not segment._isTheUnknownSegment and segment.isVromInRange(maybeVrom):
+            maybeVrom: int|None
+            if segment.romChunks is not None:
+                maybeVrom = segment.romChunks.vramToVrom(labelAddr)
+            else:
+                maybeVrom = self.vromStart + labelAddr - self.vram
+            if maybeVrom is not None and not segment._isTheUnknownSegment and segment.isVromInRange(maybeVrom):
                 labelVrom = maybeVrom
             else:
                 labelVrom = None
+
+        parentFunction = jumpTableSym.parentFunction
+        if parentFunction is not None and parentFunction.isDefined and not self._isInParentFunction(jumpTableSym, labelAddr):
+            policy = common.GlobalConfig.JUMPTABLE_LABEL_OUTSIDE_FUNCTION
+            message = f"Entry 0x{labelAddr:08X} of jumptable {jumpTableSym.getName()} is outside of its function {parentFunction.getName()}"
+            currentVram = self.getVramOffset(localOffset)
+            if policy == common.JumptableLabelOutsideFunctionPolicy.CLAMP:
+                self.context.addDiagnostic(common.DiagnosticKind.JUMPTABLE_LABEL_OUTSIDE_FUNCTION, message + ". The jumptable was ended before this entry", currentVram, self.getVromOffset(localOffset), jumpTableSym.getName())
+                return None, firstJumptableWord
+            if policy == common.JumptableLabelOutsideFunctionPolicy.REJECT:
+                self.context.addDiagnostic(common.DiagnosticKind.JUMPTABLE_LABEL_OUTSIDE_FUNCTION, message + ". No label was created for it", currentVram, self.getVromOffset(localOffset), jumpTableSym.getName())
+                return jumpTableSym, firstJumptableWord
+            self.context.addDiagnostic(common.DiagnosticKind.JUMPTABLE_LABEL_OUTSIDE_FUNCTION, message, currentVram, self.getVromOffset(localOffset), jumpTableSym.getName())
+
+        if self.context.isSymbolCreationVetoed(labelAddr, common.SymbolCreationReason.JUMPTABLE_LABEL):
+            # Only skip this entry, the rest of the jumptable is still valid
+            return jumpTableSym, firstJumptableWord
         labelSym = self.addJumpTableLabel(labelAddr, isAutogenerated=True, symbolVrom=labelVrom)
 
         if labelSym.unknownSegment:
@@ -78,7 +118,58 @@ class SectionRodata(SectionBase):
         return jumpTableSym, firstJumptableWord
 
 
+    @staticmethod
+    def _isReasonableFloat(w: int) -> bool:
+        exponent = (w >> 23) & 0xFF
+        if exponent == 0 or exponent == 0xFF:
+            # zero, denormals, NaN and infinity
+            return False
+        # |x| in [2**-32, 2**32]
+        return abs(exponent - 127) <= 32
+
+    @staticmethod
+    def _isReasonableDouble(dw: int) -> bool:
+        exponent = (dw >> 52) & 0x7FF
+        if exponent == 0 or exponent == 0x7FF:
+            return False
+        return abs(exponent - 1023) <= 32
+
+    def _floatGuesser(self, contextSym: common.ContextSymbol, words: list[int]) -> None:
+        guesserLevel = common.GlobalConfig.RODATA_FLOAT_GUESSER_LEVEL
+        if guesserLevel < 1 or self.usesMinimalAnalysis():
+            return
+
+        if not contextSym.hasNoType() or contextSym.isMaybeString or contextSym.isMaybePascalString:
+            return
+        if contextSym.referenceCounter == 0:
+            return
+        if guesserLevel < 2:
+            if len(contextSym.referenceFunctions) == 0 or len(contextSym.referenceSymbols) != 0:
+                return
+
+        if len(words) == 2 and contextSym.vram % 8 == 0:
+            if common.GlobalConfig.ENDIAN == common.InputEndian.LITTLE:
+                dword = (words[1] << 32) | words[0]
+            else:
+                dword = (words[0] << 32) | words[1]
+            if self._isReasonableDouble(dword):
+                # A float padded to 8 bytes also looks like a double, so
+                # prefer the float in that case
+                if words[1] != 0 or not self._isReasonableFloat(words[0]):
+                    contextSym.autodetectedType = "f64"
+                    return
+
+        if len(words) == 2 and words[1] == 0:
+            # Padding
+            words = words[:1]
+        if len(words) > 1 and guesserLevel < 2:
+            return
+
+        if all(self._isReasonableFloat(w) for w in words):
+            contextSym.autodetectedType = "f32"
+
     def analyze(self) -> None:
+        self.registerStringsInPool()
         lastVramSymbol: common.ContextSymbol = self._checkAndCreateFirstSymbol()
 
         symbolList: list[tuple[int, common.ContextSymbol]] = []
@@ -107,7 +198,7 @@ class SectionRodata(SectionBase):
                     if contextSym is not None:
                         lastVramSymbol = contextSym
 
-                self.checkWordIsASymbolReference(w)
+                self.checkWordIsASymbolReference(w, currentVram)
 
             if contextSym is not None:
                 symbolList.append((localOffset, contextSym))
@@ -122,7 +213,7 @@ class SectionRodata(SectionBase):
                     localOffsetsWithSymbols.add(localOffset)
 
             if not lastVramSymbol.notPointerByType():
-                if self.checkWordIsASymbolReference(w):
+                if self.checkWordIsASymbolReference(w, currentVram):
                     if w < currentVram and self.containsVram(w):
                         # References a data symbol from this section and it is behind this current symbol
                         needsFurtherAnalyzis = True
@@ -145,9 +236,11 @@ class SectionRodata(SectionBase):
             # Since we appended new symbols, this list is not sorted anymore
             symbolList.sort()
 
+        symbolList = self._chunkBigSymbols(symbolList)
+
         previousSymbolWasLateRodata = False
         previousSymbolExtraPadding = 0
-        sectionAlign_rodata = common.GlobalConfig.COMPILER.value.sectionAlign_rodata
+        sectionAlign_rodata = common.GlobalConfig.getCompilerProperties().sectionAlign_rodata
         rodataAlignment = 1 << sectionAlign_rodata if sectionAlign_rodata is not None else None
 
         for i, (offset, contextSym) in enumerate(symbolList):
@@ -157,19 +250,23 @@ class SectionRodata(SectionBase):
                 nextOffset = symbolList[i+1][0]
                 words = self.words[offset//4:nextOffset//4]
 
+            self._floatGuesser(contextSym, words)
+
             vrom = self.getVromOffset(offset)
             vromEnd = vrom + len(words)*4
             sym = symbols.SymbolRodata(self.context, vrom, vromEnd, offset + self.inFileOffset, contextSym.vram, words, self.segmentVromStart, self.overlayCategory)
             sym.parent = self
             sym.setCommentOffset(self.commentOffset)
             sym.stringEncoding = self.stringEncoding
+            sym.charmap = self.charmap
+            sym.contextSym.distanceToNextSymbol = len(words)*4
             sym.analyze()
             self.symbolList.append(sym)
             self.symbolsVRams.add(contextSym.vram)
 
             if rodataAlignment is not None:
             # Section boundaries detection
-                if (self.vromStart + sym.inFileOffset) % rodataAlignment == 0:
+                if vrom % rodataAlignment == 0:
                     if previousSymbolWasLateRodata and not sym.contextSym.isLateRodata():
                         # late rodata followed by normal rodata implies a file split
                         self.fileBoundaries.append(sym.inFileOffset)
@@ -179,11 +276,11 @@ class SectionRodata(SectionBase):
                             if previousSymbolExtraPadding >= 2:
                                 self.fileBoundaries.append(sym.inFileOffset)
                         elif sym.isJumpTable():
-                            if common.GlobalConfig.COMPILER.value.prevAlign_jumptable is not None and common.GlobalConfig.COMPILER.value.prevAlign_jumptable >= 3:
+                            if common.GlobalConfig.getCompilerProperties().prevAlign_jumptable is not None and common.GlobalConfig.getCompilerProperties().prevAlign_jumptable >= 3:
                                 if previousSymbolExtraPadding >= 2:
                                     self.fileBoundaries.append(sym.inFileOffset)
                         elif sym.isString():
-                            if common.GlobalConfig.COMPILER.value.prevAlign_string is not None and common.GlobalConfig.COMPILER.value.prevAlign_string >= 3:
+                            if common.GlobalConfig.getCompilerProperties().prevAlign_string is not None and common.GlobalConfig.getCompilerProperties().prevAlign_string >= 3:
                                 if previousSymbolExtraPadding >= 2:
                                     self.fileBoundaries.append(sym.inFileOffset)
                         else:
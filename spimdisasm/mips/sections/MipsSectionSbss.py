@@ -0,0 +1,28 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from ... import common
+
+from .MipsSectionBss import SectionBss
+
+
+class SectionSbss(SectionBss):
+    """
+    A gp-relative small bss section (`.sbss`).
+    """
+
+    def __init__(self, context: common.Context, vromStart: int, vromEnd: int, bssVramStart: int, bssVramEnd: int, filename: str, segmentVromStart: int, overlayCategory: str|None) -> None:
+        super().__init__(context, vromStart, vromEnd, bssVramStart, bssVramEnd, filename, segmentVromStart, overlayCategory)
+
+        self.isSmallSection = True
+        # Small sections only hold objects of at most 8 bytes
+        self.sectionAlignment = 3
+
+    def getSectionName(self) -> str:
+        if self.customSectionName is not None:
+            return self.customSectionName
+        return ".sbss"
@@ -0,0 +1,28 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from ... import common
+
+from .MipsSectionRodata import SectionRodata
+
+
+class SectionSrodata(SectionRodata):
+    """
+    A gp-relative small read-only data section (`.srodata`).
+    """
+
+    def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        super().__init__(context, vromStart, vromEnd, vram, filename, array_of_bytes, segmentVromStart, overlayCategory)
+
+        self.isSmallSection = True
+        # Small sections only hold objects of at most 8 bytes
+        self.sectionAlignment = 3
+
+    def getSectionName(self) -> str:
+        if self.customSectionName is not None:
+            return self.customSectionName
+        return ".srodata"
@@ -5,6 +5,9 @@
 
 from __future__ import annotations
 
+import bisect
+from typing import Generator
+
 import rabbitizer
 
 from ... import common
@@ -17,6 +20,8 @@ from . import SectionBase
 
 class SectionText(SectionBase):
     def __init__(self, context: common.Context, vromStart: int, vromEnd: int, vram: int, filename: str, array_of_bytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        self._checkCreationParameters(filename, common.FileSectionType.Text, vromStart, vromEnd, vram, array_of_bytes, segmentVromStart, overlayCategory)
+
         super().__init__(context, vromStart, vromEnd, vram, filename, common.Utils.bytesToWords(array_of_bytes, vromStart, vromEnd), common.FileSectionType.Text, segmentVromStart, overlayCategory)
 
         self.instrCat: rabbitizer.Enum = rabbitizer.InstrCategory.CPU
@@ -29,7 +34,23 @@ class SectionText(SectionBase):
 
     @property
     def nFuncs(self) -> int:
-        return len(self.symbolList)
+        return sum(1 for _ in self.iterFunctions())
+
+    def iterFunctions(self) -> Generator[symbols.SymbolFunction, None, None]:
+        "Iterates over the functions of this section, skipping data pools"
+        for sym in self.symbolList:
+            if isinstance(sym, symbols.SymbolFunction):
+                yield sym
+
+    def defineLabels(self) -> None:
+        "Calls `SymbolFunction.defineLabels` on every function of this section"
+        for func in self.iterFunctions():
+            func.defineLabels()
+
+    def resolveDisplayReferences(self) -> None:
+        "Calls `SymbolFunction.resolveDisplayReferences` on every function of this section"
+        for func in self.iterFunctions():
+            func.resolveDisplayReferences()
 
     @staticmethod
     def wordListToInstructions(wordList: list[int], currentVram: int|None, instrCat: rabbitizer.Enum) -> list[rabbitizer.Instruction]:
@@ -45,20 +66,39 @@ class SectionText(SectionBase):
         return instrsList
 
 
+    def _wordsToInstructions(self) -> list[rabbitizer.Instruction]:
+        "Decodes the words of this section, honoring the ranges from `Context.addInstrCategoryRange`"
+        if len(self.context.instrCategoryRanges) == 0:
+            return self.wordListToInstructions(self.words, self.getVramOffset(0), self.instrCat)
+
+        instrsList: list[rabbitizer.Instruction] = list()
+        for i, word in enumerate(self.words):
+            currentVram = self.getVramOffset(i*4)
+            instrCat = self.context.getInstrCategoryRangeOverride(currentVram)
+            if instrCat is None:
+                instrCat = self.instrCat
+            instr = rabbitizer.Instruction(word, category=instrCat)
+            instr.vram = currentVram
+            instrsList.append(instr)
+        return instrsList
+
+
     def getAsmPrelude_instructionDirectives(self) -> str:
         output = ""
 
-        output += f"/* assembler directives */{common.GlobalConfig.LINE_ENDS}"
-        output += f".set noat      /* allow manual use of $at */{common.GlobalConfig.LINE_ENDS}"
-        output += f".set noreorder /* do not insert nops after branches */{common.GlobalConfig.LINE_ENDS}"
+        if common.GlobalConfig.ASM_ASSEMBLER_MODES_PLACEMENT == common.AsmAssemblerModesPlacement.FILE:
+            output += f".set noat      /* allow manual use of $at */{common.GlobalConfig.LINE_ENDS}"
+            output += f".set noreorder /* do not insert nops after branches */{common.GlobalConfig.LINE_ENDS}"
         if common.GlobalConfig.ARCHLEVEL >= common.ArchLevel.MIPS3:
             output += f".set gp=64     /* allow use of 64-bit general purpose registers */{common.GlobalConfig.LINE_ENDS}"
-        output += common.GlobalConfig.LINE_ENDS
-        return output
+
+        if output == "":
+            return ""
+        return f"/* assembler directives */{common.GlobalConfig.LINE_ENDS}" + output + common.GlobalConfig.LINE_ENDS
 
 
     def tryDetectRedundantFunctionEnd(self) -> bool:
-        if common.GlobalConfig.COMPILER != common.Compiler.IDO:
+        if not common.GlobalConfig.getCompilerProperties().hasRedundantFunctionEnd:
             return False
 
         if self.detectRedundantFunctionEnd is None:
@@ -168,9 +208,128 @@ class SectionText(SectionBase):
                             if auxSym is not None and auxSym.isTrustableFunction(self.instrCat == rabbitizer.InstrCategory.RSP):
                                 functionEnded = True
 
+            if functionEnded:
+                nextSym = self.getSymbol(currentVram + 8, vromAddress=currentVrom + 8, tryPlusOffset=False, checkGlobalSegment=False)
+                if nextSym is not None and nextSym.forceFunctionContinuation:
+                    # The user asked for the function to continue after this point
+                    functionEnded = False
+
         return functionEnded, prevFuncHadUserDeclaredSize
 
-    def _findFunctions(self, instrsList: list[rabbitizer.Instruction]) -> tuple[list[int], list[bool]]:
+    def _getDataPools(self, nInstr: int) -> dict[int, int]:
+        """
+        Returns the instruction index ranges (start -> end) of the user
+        declared data symbols placed inside this section.
+        """
+        dataPools: dict[int, int] = dict()
+
+        for address, contextSym in self.getSymbolsRange(self.vram, self.vram + nInstr*4):
+            if not contextSym.isTextDataPool():
+                continue
+            if address % 4 != 0:
+                continue
+
+            startIndex = (address - self.vram) // 4
+            if contextSym.vromAddress is not None and contextSym.vromAddress != self.getVromOffset(startIndex*4):
+                continue
+
+            size = contextSym.getSize()
+            endIndex = min(startIndex + (size + 3) // 4, nInstr)
+            if endIndex > startIndex:
+                dataPools[startIndex] = endIndex
+
+        return dataPools
+
+    def _isReachabilitySeed(self, index: int) -> bool:
+        vram = self.getVramOffset(index*4)
+        if vram in self.context.entryPoints:
+            return True
+        contextSym = self.getSymbol(vram, vromAddress=self.getVromOffset(index*4), tryPlusOffset=False, checkGlobalSegment=False)
+        if contextSym is None or contextSym.getTypeSpecial() != common.SymbolSpecialType.function:
+            return False
+        return contextSym.isUserDeclared
+
+    def _getDataPoolEnd(self, index: int, dataPools: dict[int, int]) -> int|None:
+        for poolStart, poolEnd in dataPools.items():
+            if poolStart <= index < poolEnd:
+                return poolEnd
+        return None
+
+    def _findUnreachableRanges(self, instrsList: list[rabbitizer.Instruction], funcsStartsList: list[int], dataPools: dict[int, int]) -> dict[int, int]:
+        """
+        Returns the instruction index ranges (start -> end) of the detected
+        functions which can't be reached from any entry point or user declared
+        function.
+
+        Besides direct calls, jumps and branches, the addresses built with
+        `%hi`/`%lo` pairs pointing inside this section are followed too. If
+        such an address points to a data pool of this section then every
+        consecutive word of it pointing inside this section is followed, so
+        tables of function pointers and jumptables placed on the section are
+        handled. References from other sections are not taken into account,
+        so the result doesn't depend on the order sections are analyzed.
+
+        See `GlobalConfig.TEXT_REACHABILITY_ANALYSIS`.
+        """
+
+        nInstr = len(instrsList)
+        starts = [x for x in funcsStartsList if x < nInstr]
+        ends = starts[1:] + [nInstr]
+
+        def findContaining(index: int) -> int|None:
+            pos = bisect.bisect_right(starts, index) - 1
+            if pos < 0 or index >= ends[pos]:
+                return None
+            return pos
+
+        pending = [pos for pos, start in enumerate(starts) if start not in dataPools and self._isReachabilitySeed(start)]
+        reached: set[int] = set(pending)
+
+        def reach(targetIndex: int) -> None:
+            targetPos = findContaining(targetIndex)
+            if targetPos is not None and targetPos not in reached and starts[targetPos] not in dataPools:
+                reached.add(targetPos)
+                pending.append(targetPos)
+
+        while len(pending) > 0:
+            pos = pending.pop()
+            hiValues: dict[int, int] = dict()
+            for instr in instrsList[starts[pos]:ends[pos]]:
+                if instr.canBeHi():
+                    hiValues[instr.rt.value] = instr.getProcessedImmediate() << 16
+                    continue
+                if instr.canBeLo() and instr.rs.value in hiValues:
+                    targetVram = hiValues[instr.rs.value] + instr.getProcessedImmediate()
+                    if self.vram <= targetVram < self.vram + nInstr*4 and targetVram % 4 == 0:
+                        targetIndex = (targetVram - self.vram) // 4
+                        poolEnd = self._getDataPoolEnd(targetIndex, dataPools)
+                        if poolEnd is None:
+                            reach(targetIndex)
+                        else:
+                            # A table of function pointers or a jumptable
+                            for word in self.words[targetIndex:poolEnd]:
+                                if word < self.vram or word % 4 != 0 or word >= self.vram + nInstr*4:
+                                    break
+                                reach((word - self.vram) // 4)
+                    continue
+
+                if instr.isJumpWithAddress():
+                    targetVram = instr.getInstrIndexAsVram()
+                elif instr.isBranch() or instr.isUnconditionalBranch():
+                    targetVram = instr.getBranchVramGeneric()
+                else:
+                    continue
+                if targetVram < self.vram or targetVram % 4 != 0:
+                    continue
+                reach((targetVram - self.vram) // 4)
+
+        unreachable: dict[int, int] = dict()
+        for pos, start in enumerate(starts):
+            if pos not in reached and start not in dataPools and start < ends[pos]:
+                unreachable[start] = ends[pos]
+        return unreachable
+
+    def _findFunctions(self, instrsList: list[rabbitizer.Instruction], dataPools: dict[int, int]) -> tuple[list[int], list[bool]]:
         nInstr = len(instrsList)
 
         if nInstr == 0:
@@ -212,6 +371,31 @@ class SectionText(SectionBase):
         prevFuncHadUserDeclaredSize = False
 
         while index < nInstr:
+            dataPoolEnd = dataPools.get(index)
+            if dataPoolEnd is not None:
+                # Carve out the user declared data, so it doesn't get
+                # disassembled as instructions
+                if funcsStartsList[-1] != index:
+                    funcsStartsList.append(index)
+                    unimplementedInstructionsFuncList.append(not isInstrImplemented)
+
+                index = dataPoolEnd
+                instructionOffset = index * 4
+                functionEnded = False
+                prevFuncHadUserDeclaredSize = False
+                farthestBranch = 0
+                isLikelyHandwritten = self.isHandwritten
+                isInstrImplemented = True
+                if index >= nInstr:
+                    break
+
+                funcsStartsList.append(index)
+                unimplementedInstructionsFuncList.append(False)
+
+                currentInstructionStart = instructionOffset
+                currentFunctionSym = self.getSymbol(self.getVramOffset(instructionOffset), vromAddress=self.getVromOffset(instructionOffset), tryPlusOffset=False, checkGlobalSegment=False)
+                continue
+
             instr = instrsList[index]
             if not instr.isImplemented() or not instr.isValid():
                 isInstrImplemented = False
@@ -274,13 +458,18 @@ class SectionText(SectionBase):
 
 
     def analyze(self) -> None:
-        instrsList = self.wordListToInstructions(self.words, self.getVramOffset(0), self.instrCat)
+        self._checkSymbolsCrossingBoundary()
+
+        instrsList = self._wordsToInstructions()
         nInstr = len(instrsList)
 
-        funcsStartsList, unimplementedInstructionsFuncList = self._findFunctions(instrsList)
+        dataPools = self._getDataPools(nInstr)
+        funcsStartsList, unimplementedInstructionsFuncList = self._findFunctions(instrsList, dataPools)
+        if common.GlobalConfig.TEXT_REACHABILITY_ANALYSIS:
+            dataPools.update(self._findUnreachableRanges(instrsList, funcsStartsList, dataPools))
 
         previousSymbolExtraPadding = 0
-        sectionAlign_text = common.GlobalConfig.COMPILER.value.sectionAlign_text
+        sectionAlign_text = common.GlobalConfig.getCompilerProperties().sectionAlign_text
         textAlignment = 1 << sectionAlign_text if sectionAlign_text is not None else None
 
         i = 0
@@ -301,20 +490,45 @@ class SectionText(SectionBase):
             vrom = self.getVromOffset(localOffset)
             vromEnd = vrom + (end - start)*4
 
+            if start in dataPools:
+                self.symbolsVRams.add(vram)
+
+                dataSym = symbols.SymbolText(self.context, vrom, vromEnd, self.inFileOffset + localOffset, vram, self.words[start:end], self.segmentVromStart, self.overlayCategory)
+                dataSym.setCommentOffset(self.commentOffset)
+                dataSym.index = i
+                dataSym.parent = self
+                dataSym.analyze()
+                self.symbolList.append(dataSym)
+
+                # Don't let the data pool confuse the boundary detection
+                previousSymbolExtraPadding = 0
+                i += 1
+                continue
+
             if common.GlobalConfig.DISASSEMBLE_UNKNOWN_INSTRUCTIONS or not hasUnimplementedIntrs:
-                self.addFunction(vram, isAutogenerated=True, symbolVrom=vrom)
+                funcSym = self.addFunction(vram, isAutogenerated=True, symbolVrom=vrom)
             else:
-                self.addSymbol(vram, sectionType=self.sectionType, isAutogenerated=True, symbolVrom=vrom)
+                funcSym = self.addSymbol(vram, sectionType=self.sectionType, isAutogenerated=True, symbolVrom=vrom)
 
             self.symbolsVRams.add(vram)
 
-            func = symbols.SymbolFunction(self.context, vrom, vromEnd, self.inFileOffset + localOffset, vram, instrsList[start:end], self.segmentVromStart, self.overlayCategory)
+            funcInstrCat = self.instrCat
+            funcInstrs = instrsList[start:end]
+            if funcSym.instrCategory is not None:
+                funcInstrCat = funcSym.instrCategory
+                funcInstrs = self.wordListToInstructions(self.words[start:end], vram, funcInstrCat)
+            else:
+                rangeInstrCat = self.context.getInstrCategoryRangeOverride(vram)
+                if rangeInstrCat is not None:
+                    funcInstrCat = rangeInstrCat
+
+            func = symbols.SymbolFunction(self.context, vrom, vromEnd, self.inFileOffset + localOffset, vram, funcInstrs, self.segmentVromStart, self.overlayCategory)
             func.setCommentOffset(self.commentOffset)
             func.index = i
             func.pointersOffsets |= self.pointersOffsets
             func.hasUnimplementedIntrs = hasUnimplementedIntrs
             func.parent = self
-            func.isRsp = self.instrCat == rabbitizer.InstrCategory.RSP
+            func.isRsp = funcInstrCat == rabbitizer.InstrCategory.RSP
             func.gpRelHack = self.gpRelHack
             func.analyze()
             self.symbolList.append(func)
@@ -322,7 +536,7 @@ class SectionText(SectionBase):
             if textAlignment is not None:
                 # Section boundaries detection
 
-                if (self.vromStart + func.inFileOffset) % textAlignment == 0 and previousSymbolExtraPadding > 0:
+                if vrom % textAlignment == 0 and previousSymbolExtraPadding > 0:
                     # If the previous symbol had trailing padding and the
                     # current symbol is aligned to the expected alignment then
                     # add this offset as a section boundary.
@@ -349,21 +563,13 @@ class SectionText(SectionBase):
 
     def countDiffOpcodes(self, other: SectionText) -> int:
         result = 0
-        for i in range(min(self.nFuncs, other.nFuncs)):
-            func = self.symbolList[i]
-            other_func = other.symbolList[i]
-            assert isinstance(func, symbols.SymbolFunction)
-            assert isinstance(other_func, symbols.SymbolFunction)
+        for func, other_func in zip(self.iterFunctions(), other.iterFunctions()):
             result += func.countDiffOpcodes(other_func)
         return result
 
     def countSameOpcodeButDifferentArguments(self, other: SectionText) -> int:
         result = 0
-        for i in range(min(self.nFuncs, other.nFuncs)):
-            func = self.symbolList[i]
-            other_func = other.symbolList[i]
-            assert isinstance(func, symbols.SymbolFunction)
-            assert isinstance(other_func, symbols.SymbolFunction)
+        for func, other_func in zip(self.iterFunctions(), other.iterFunctions()):
             result += func.countSameOpcodeButDifferentArguments(other_func)
         return result
 
@@ -375,11 +581,7 @@ class SectionText(SectionBase):
             return False
 
         was_updated = False
-        for i in range(min(self.nFuncs, other_file.nFuncs)):
-            func = self.symbolList[i]
-            other_func = other_file.symbolList[i]
-            assert isinstance(func, symbols.SymbolFunction)
-            assert isinstance(other_func, symbols.SymbolFunction)
+        for func, other_func in zip(self.iterFunctions(), other_file.iterFunctions()):
             func_updated = func.blankOutDifferences(other_func)
             if func_updated:
                 localOffset = func.inFileOffset - self.inFileOffset
@@ -398,8 +600,7 @@ class SectionText(SectionBase):
             return False
 
         was_updated = False
-        for func in self.symbolList:
-            assert isinstance(func, symbols.SymbolFunction)
+        for func in self.iterFunctions():
             func_updated = func.removePointers()
             if func_updated:
                 localOffset = func.inFileOffset - self.inFileOffset
@@ -414,8 +615,9 @@ class SectionText(SectionBase):
 
         if self.nFuncs > 0:
             func = self.symbolList[-1]
-            assert isinstance(func, symbols.SymbolFunction)
-            func.removeTrailingNops()
-            was_updated = True
+            # The section may end with a data pool instead of a function
+            if isinstance(func, symbols.SymbolFunction):
+                func.removeTrailingNops()
+                was_updated = True
 
         return was_updated
@@ -14,3 +14,11 @@ from .MipsSectionBss import SectionBss as SectionBss
 from .MipsSectionRelocZ64 import SectionRelocZ64 as SectionRelocZ64
 from .MipsSectionRelocZ64 import RelocEntry as RelocEntry
 from .MipsSectionGccExceptTable import SectionGccExceptTable as SectionGccExceptTable
+from .MipsSectionBin import SectionBin as SectionBin
+from .MipsSectionCustom import SectionCustom as SectionCustom
+from .MipsSectionCustom import CustomSectionFactory as CustomSectionFactory
+from .MipsSectionCustom import registerCustomSectionKind as registerCustomSectionKind
+from .MipsSectionCustom import getCustomSectionKindFactory as getCustomSectionKindFactory
+from .MipsSectionSdata import SectionSdata as SectionSdata
+from .MipsSectionSrodata import SectionSrodata as SectionSrodata
+from .MipsSectionSbss import SectionSbss as SectionSbss
@@ -24,6 +24,7 @@ class SymbolBase(common.ElementBase):
         self.contextSym.sectionType = self.sectionType
 
         self.stringEncoding: str = common.GlobalConfig.DATA_STRING_ENCODING
+        self.charmap: common.Charmap|None = common.GlobalConfig.DATA_CHARMAP
 
         self.relocs: dict[int, common.RelocationInfo] = dict()
         "key: word offset"
@@ -31,6 +32,8 @@ class SymbolBase(common.ElementBase):
         self.referencedVrams: set[int] = set()
         "Every referenced vram found"
 
+        self._looksLikeDwordTableCache: bool|None = None
+
 
     def getName(self) -> str:
         return self.contextSym.getName()
@@ -68,13 +71,15 @@ class SymbolBase(common.ElementBase):
 
 
     def generateAsmLineComment(self, localOffset: int, wordValue: int|None=None, *, isDouble: bool=False, emitRomOffset: bool=True) -> str:
-        indentation = " " * common.GlobalConfig.ASM_INDENTATION
+        indentation = common.Utils.asmIndentation(common.GlobalConfig.ASM_INDENTATION)
 
         if not common.GlobalConfig.ASM_COMMENT:
             return indentation
 
         if emitRomOffset:
             offsetHex = "{0:0{1}X} ".format(localOffset + self.inFileOffset + self.commentOffset, common.GlobalConfig.ASM_COMMENT_OFFSET_WIDTH)
+            if common.GlobalConfig.ASM_COMMENT_VROM:
+                offsetHex += "{0:0{1}X} ".format(self.getVromOffset(localOffset), common.GlobalConfig.ASM_COMMENT_OFFSET_WIDTH)
         else:
             offsetHex = ""
 
@@ -90,6 +95,28 @@ class SymbolBase(common.ElementBase):
 
         return f"{indentation}/* {offsetHex}{vramHex} {wordValueHex}*/"
 
+    def joinCommentAndStatement(self, comment: str, statement: str, defaultSpacing: int=1) -> str:
+        """
+        Joins the line comment generated by `generateAsmLineComment` with an
+        instruction or data directive, aligning the latter to
+        `ASM_INSTRUCTION_COLUMN` if set.
+        """
+        if common.GlobalConfig.ASM_INSTRUCTION_COLUMN > 0:
+            return common.Utils.asmPadToColumn(comment, common.GlobalConfig.ASM_INSTRUCTION_COLUMN) + statement
+        return comment + common.Utils.asmIndentation(defaultSpacing) + statement
+
+    def formatDirective(self, dotType: str, value: str) -> str:
+        return f"{dotType.ljust(common.GlobalConfig.ASM_DIRECTIVE_LJUST)} {value}"
+
+    def _getContinuationPadding(self, comment: str) -> str:
+        "Whitespace for the extra lines of a multiline directive, so they line up with the first one"
+        if common.GlobalConfig.ASM_INSTRUCTION_COLUMN > 0:
+            width = common.Utils.asmLineWidth(self.joinCommentAndStatement(comment, ""))
+            return common.Utils.asmPadToColumn("", width, minSpacing=0)
+        if not common.GlobalConfig.ASM_COMMENT:
+            return " "
+        return 22 * " "
+
 
     def getSymbolAsmDeclaration(self, symName: str, useGlobalLabel: bool=True) -> str:
         if not useGlobalLabel:
@@ -97,15 +124,86 @@ class SymbolBase(common.ElementBase):
 
         output = ""
         output += self.getLabelFromSymbol(self.contextSym, symName)
-        if self.sectionType == common.FileSectionType.Text:
+        # A plain label was already emitted for those
+        hasPlainLabel = common.GlobalConfig.ASM_VISIBILITY_DIRECTIVES and self.contextSym.getVisibility() in {"weak", "local"}
+        if self.sectionType == common.FileSectionType.Text and not self.contextSym.isTextDataPool():
+            # Always emitted, since the function end label is emitted regardless of the visibility
             if common.GlobalConfig.ASM_TEXT_ENT_LABEL:
                 output += f"{common.GlobalConfig.ASM_TEXT_ENT_LABEL} {symName}{common.GlobalConfig.LINE_ENDS}"
 
-            if common.GlobalConfig.ASM_TEXT_FUNC_AS_LABEL:
+            if common.GlobalConfig.ASM_TEXT_FUNC_AS_LABEL and not hasPlainLabel:
                 output += f"{symName}:{common.GlobalConfig.LINE_ENDS}"
         else:
-            if common.GlobalConfig.ASM_DATA_SYM_AS_LABEL:
+            if common.GlobalConfig.ASM_DATA_SYM_AS_LABEL and not hasPlainLabel:
                 output += f"{symName}:{common.GlobalConfig.LINE_ENDS}"
+
+        if symName == self.getName():
+            output += self.getTypeDirective(symName)
+            output += self.getAliasesDeclaration(symName)
+        return output
+
+    def getTypeDirective(self, symName: str) -> str:
+        if not common.GlobalConfig.ASM_EMIT_TYPE_DIRECTIVE:
+            return ""
+        symType = "@function" if self.isFunction() else "@object"
+        return f".type {symName}, {symType}{common.GlobalConfig.LINE_ENDS}"
+
+    def getAliasesDeclaration(self, symName: str) -> str:
+        output = ""
+        for alias in self.contextSym.aliases:
+            if common.GlobalConfig.ASM_ALIASES_AS_SET:
+                visibility = self.contextSym.getVisibility()
+                if visibility == "weak":
+                    output += f".weak {alias}{common.GlobalConfig.LINE_ENDS}"
+                elif visibility != "local":
+                    output += f".globl {alias}{common.GlobalConfig.LINE_ENDS}"
+                output += f".set {alias}, {symName}{common.GlobalConfig.LINE_ENDS}"
+            else:
+                output += self.getLabelFromSymbol(self.contextSym, alias)
+        return output
+
+    def getDiagnosticsComments(self) -> str:
+        if not common.GlobalConfig.ASM_COMMENT or not common.GlobalConfig.ASM_COMMENT_DIAGNOSTICS:
+            return ""
+
+        output = ""
+        for diagnostic in self.context.getDiagnosticsInRange(self.vram, self.vramEnd, self.vromStart, self.vromEnd):
+            output += f"{diagnostic.toComment()}{common.GlobalConfig.LINE_ENDS}"
+        return output
+
+    def getDuplicatedStringsComment(self) -> str:
+        if not common.GlobalConfig.ASM_COMMENT or not common.GlobalConfig.ASM_COMMENT_DUPLICATED_STRINGS:
+            return ""
+        if not self.isString():
+            return ""
+
+        duplicates = self.context.getDuplicatedStrings(self.contextSym)
+        if len(duplicates) == 0:
+            return ""
+        names = ", ".join(sym.getName() for sym in duplicates)
+        return f"/* Duplicated string, also found at: {names} */{common.GlobalConfig.LINE_ENDS}"
+
+    def getCrossReferencesComment(self) -> str:
+        limit = common.GlobalConfig.ASM_COMMENT_DATA_XREFS
+        if not common.GlobalConfig.ASM_COMMENT or limit <= 0:
+            return ""
+
+        xrefs = self.contextSym.getCrossReferences()
+        if len(xrefs) == 0:
+            return ""
+        names = ", ".join(sym.getName() for sym in xrefs[:limit])
+        if len(xrefs) > limit:
+            names += f" and {len(xrefs) - limit} more"
+        return f"/* Referenced by: {names} */{common.GlobalConfig.LINE_ENDS}"
+
+    def getArrayElementHeader(self, elementIndex: int, elementCount: int) -> str:
+        "Separates each element of a symbol declared as an array, optionally commenting its index"
+        output = ""
+        if elementIndex != 0:
+            output += common.GlobalConfig.LINE_ENDS
+        limit = common.GlobalConfig.ASM_COMMENT_ARRAY_INDICES
+        if common.GlobalConfig.ASM_COMMENT and limit > 0 and elementCount >= limit:
+            output += f"/* [{elementIndex}] */{common.GlobalConfig.LINE_ENDS}"
         return output
 
     def getExtraLabelFromSymbol(self, contextSym: common.ContextSymbol|None) -> str:
@@ -119,9 +217,43 @@ class SymbolBase(common.ElementBase):
                     label += f"{contextSym.getName()}:" + common.GlobalConfig.LINE_ENDS
         return label
 
+    def _getNonInnerLabelSymbol(self, vram: int, vrom: int) -> common.ContextSymbol|None:
+        contextSym = self.getSymbol(vram, vromAddress=vrom, tryPlusOffset=False)
+        if contextSym is not None and contextSym.innerLabelOf is self.contextSym:
+            # Emitted by `disassembleAsData`
+            return None
+        return contextSym
+
+    def _getMiddleLabel(self, contextSym: common.ContextSymbol|None) -> str:
+        if contextSym is not None and contextSym.innerLabelOf is self.contextSym:
+            return self.getInnerLabel(contextSym)
+        return self.getExtraLabelFromSymbol(contextSym)
+
+    def getInnerLabel(self, contextSym: common.ContextSymbol) -> str:
+        """
+        Returns the label of a symbol placed in the middle of this one.
+
+        Unlike other symbols, inner labels don't end the current symbol, so no
+        size directive is emitted for them.
+
+        See `GlobalConfig.INNER_LABELS_FOR_CODE_REFERENCES`.
+        """
+        label = ""
+        symLabel = contextSym.getLabelMacro(isInMiddleLabel=True)
+        if symLabel is not None:
+            label += f"{symLabel} {contextSym.getName()}{common.GlobalConfig.LINE_ENDS}"
+        if symLabel is None or common.GlobalConfig.ASM_DATA_SYM_AS_LABEL:
+            label += f"{contextSym.getName()}:{common.GlobalConfig.LINE_ENDS}"
+        return label
+
     def getReloc(self, wordOffset: int, instr: rabbitizer.Instruction|None) -> common.RelocationInfo | None:
         relocInfo = self.context.globalRelocationOverrides.get(self.getVromOffset(wordOffset))
 
+        if relocInfo is None and instr is not None:
+            override = self.context.instructionOverrides.get(self.getVromOffset(wordOffset))
+            if override is not None:
+                relocInfo = override.reloc
+
         if relocInfo is None:
             relocInfo = self.relocs.get(wordOffset)
 
@@ -186,6 +318,65 @@ class SymbolBase(common.ElementBase):
                         return True
         return False
 
+    def _isPlausibleDword(self, index: int) -> bool:
+        "Checks if the upper half of the dword at the given word index is the sign extension of its lower half"
+        if common.GlobalConfig.ENDIAN == common.InputEndian.LITTLE:
+            lower, upper = self.words[index], self.words[index+1]
+        else:
+            upper, lower = self.words[index], self.words[index+1]
+        if lower & 0x80000000:
+            return upper == 0xFFFFFFFF
+        return upper == 0
+
+    def looksLikeDwordTable(self) -> bool:
+        """
+        Checks if every pair of words of this untyped symbol looks like a 64
+        bits integer. See `GlobalConfig.DATA_GUESS_DWORDS`.
+        """
+
+        if self._looksLikeDwordTableCache is not None:
+            return self._looksLikeDwordTableCache
+
+        self._looksLikeDwordTableCache = False
+        if not common.GlobalConfig.DATA_GUESS_DWORDS or common.GlobalConfig.ARCHLEVEL < common.ArchLevel.MIPS3:
+            return False
+        if self.sectionType not in {common.FileSectionType.Data, common.FileSectionType.Rodata}:
+            return False
+        if not self.contextSym.hasNoType() or self.vram % 8 != 0:
+            return False
+        if len(self.words) == 0 or len(self.words) % 2 != 0:
+            return False
+        if self.isString() or self.isPascalString():
+            return False
+
+        hasNonZero = False
+        for i in range(0, len(self.words), 2):
+            if not self._isPlausibleDword(i):
+                return False
+            if self.words[i] != 0 or self.words[i+1] != 0:
+                hasNonZero = True
+
+        self._looksLikeDwordTableCache = hasNonZero
+        return hasNonZero
+
+    def isDword(self, index: int) -> bool:
+        if index % 2 != 0:
+            # Must be doubleword aligned
+            return False
+        if index + 1 >= len(self.words):
+            return False
+        if not self.contextSym.isDword() and not self.looksLikeDwordTable():
+            return False
+
+        # Symbols and relocations can't be emitted in the middle of a dword
+        if self.getReloc(index*4, None) is not None or self.getReloc(index*4 + 4, None) is not None:
+            return False
+        currentVram = self.getVramOffset(index*4)
+        currentVrom = self.getVromOffset(index*4)
+        if self.getSymbol(currentVram+4, vromAddress=currentVrom, tryPlusOffset=False) is not None:
+            return False
+        return True
+
     def isJumpTable(self) -> bool:
         return False
 
@@ -225,20 +416,85 @@ class SymbolBase(common.ElementBase):
                         if self.parent is not None:
                             contextSym.parentFileName = self.parent.getName()
 
-                if isWordSized:
+                if isWordSized and self.contextSym.allowedToReferenceSymbols and not self.context.isRawDataAddress(self.getVramOffset(localOffset)):
                     word = self.words[i]
+                    segmentedVram = common.GlobalConfig.getSegmentedAddressVram(word)
+                    if segmentedVram is not None:
+                        word = segmentedVram
                     referencedSym = self.getSymbol(word, tryPlusOffset=False)
+                    if referencedSym is None:
+                        kseg0Vram = common.GlobalConfig.getKseg0AliasVram(word)
+                        if kseg0Vram is not None:
+                            referencedSym = self.getSymbol(kseg0Vram, tryPlusOffset=False)
                     if referencedSym is not None:
                         if not referencedSym.isJumpTable():
                             referencedSym.referenceSymbols.add(self.contextSym)
+                        referencedSym.addReferenceLocation(self.contextSym, self.getVromOffset(localOffset), isFromFunction=False)
                         self.referencedVrams.add(referencedSym.vram)
 
+        if self.sectionType != common.FileSectionType.Bss:
+            self._checkStringDecoding()
+
+        if self.sectionType in {common.FileSectionType.Data, common.FileSectionType.Rodata} and self.isString():
+            stringContents = self.getStringContents()
+            if stringContents is not None:
+                self.context.registerStringSymbol(self.contextSym, stringContents)
+
+    def _hasSymbolsInTheMiddleOfWord(self, i: int) -> bool:
+        currentVram = self.getVramOffset(i*4)
+        currentVrom = self.getVromOffset(i*4)
+        for j in range(1, 4):
+            if self.getSymbol(currentVram+j, vromAddress=currentVrom, tryPlusOffset=False, checkGlobalSegment=False) is not None:
+                return True
+        return False
+
+    def _checkStringDecoding(self) -> None:
+        "Decodes the strings of this symbol the same way `disassembleAsData` does, so decoding failures are known before generating the output"
+        i = 0
+        while i < self.sizew:
+            skip = 0
+            if self._hasSymbolsInTheMiddleOfWord(i) or self.isByte(i) or self.isShort(i) or self.isFloat(i):
+                pass
+            elif self.isDouble(i) or self.isDword(i):
+                skip = 1
+            elif self.isString():
+                _, skip = self.getNthWordAsString(i)
+                if skip < 0:
+                    self.contextSym.failedStringDecoding = True
+                    self.context.addDiagnostic(common.DiagnosticKind.STRING_DECODE_FAILURE, f"Unable to decode {self.getName()} as a string", self.getVramOffset(i*4), self.getVromOffset(i*4), self.getName())
+                    skip = 0
+            elif self.isPascalString():
+                _, skip = self.getNthWordAsPascalString(i)
+                if skip < 0:
+                    self.contextSym.failedPascalStringDecoding = True
+                    skip = 0
+            i += skip + 1
+
+    def getStringContents(self) -> str|None:
+        "Returns the decoded contents of the string starting at this symbol, or `None` if it can't be decoded"
+        buffer = common.Utils.wordsToBytes(self.words)
+        if self.charmap is not None:
+            decodedString, rawStringSize = self.charmap.decode(buffer, 0)
+        else:
+            decodedStrings, rawStringSize = common.Utils.decodeBytesToStrings(buffer, 0, self.stringEncoding)
+            decodedString = "".join(decodedStrings)
+        if rawStringSize < 0:
+            return None
+        return decodedString
 
-    def getEndOfLineComment(self, wordIndex: int) -> str:
+
+    def getEndOfLineComment(self, wordIndex: int, currentLine: str="") -> str:
+        """
+        `currentLine` is the text the comment is going to be appended to,
+        used to align it to `ASM_END_OF_LINE_COMMENT_COLUMN`.
+        """
         if not common.GlobalConfig.ASM_COMMENT:
             return ""
 
-        return self.endOfLineComment.get(wordIndex, "")
+        comment = self.endOfLineComment.get(wordIndex, "")
+        if comment == "" or common.GlobalConfig.ASM_END_OF_LINE_COMMENT_COLUMN <= 0:
+            return comment
+        return common.Utils.asmPadToColumn(currentLine, common.GlobalConfig.ASM_END_OF_LINE_COMMENT_COLUMN)[len(currentLine):] + comment.lstrip()
 
     def getJByteAsByte(self, i: int, j: int) -> str:
         localOffset = 4*i
@@ -253,7 +509,7 @@ class SymbolBase(common.ElementBase):
         value = f"0x{subVal:02X}"
 
         comment = self.generateAsmLineComment(localOffset+j)
-        return f"{comment} {dotType} {value}"
+        return self.joinCommentAndStatement(comment, self.formatDirective(dotType, value))
 
     def getJByteAsShort(self, i: int, j: int) -> str:
         localOffset = 4*i
@@ -267,8 +523,20 @@ class SymbolBase(common.ElementBase):
         subVal = (w & (0xFFFF << shiftValue)) >> shiftValue
         value = f"0x{subVal:04X}"
 
+        relocInfo = self.getReloc(localOffset+j, None)
+        if relocInfo is not None and relocInfo.relocType == common.RelocType.MIPS_16 and relocInfo.staticReference is None:
+            value = relocInfo.getName()
+
         comment = self.generateAsmLineComment(localOffset+j)
-        return f"{comment} {dotType} {value}"
+        return self.joinCommentAndStatement(comment, self.formatDirective(dotType, value))
+
+    def _hasShortReloc(self, i: int) -> bool:
+        "`R_MIPS_16` relocations can only be emitted on a `.short`"
+        for j in (0, 2):
+            relocInfo = self.getReloc(4*i + j, None)
+            if relocInfo is not None and relocInfo.relocType == common.RelocType.MIPS_16:
+                return True
+        return False
 
     def getNthWordAsBytesAndShorts(self, i: int, sym1: common.ContextSymbol|None, sym2: common.ContextSymbol|None, sym3: common.ContextSymbol|None, lastSymName: str) -> tuple[str, int]:
         output = ""
@@ -285,22 +553,22 @@ class SymbolBase(common.ElementBase):
             output += self.getJByteAsByte(i, 0)
             output += common.GlobalConfig.LINE_ENDS
 
-            if sym1 is not None:
+            if sym1 is not None and sym1.innerLabelOf is not self.contextSym:
                 output += self.getSizeDirective(lastSymName)
                 lastSymName = sym1.getName()
 
-            output += self.getExtraLabelFromSymbol(sym1)
+            output += self._getMiddleLabel(sym1)
             output += self.getJByteAsByte(i, 1)
             output += common.GlobalConfig.LINE_ENDS
         else:
             output += self.getJByteAsShort(i, 0)
             output += common.GlobalConfig.LINE_ENDS
 
-        if sym2 is not None:
+        if sym2 is not None and sym2.innerLabelOf is not self.contextSym:
             output += self.getSizeDirective(lastSymName)
             lastSymName = sym2.getName()
 
-        output += self.getExtraLabelFromSymbol(sym2)
+        output += self._getMiddleLabel(sym2)
         if sym3 is not None or (sym2 is not None and sym2.isByte()) or (self.isByte(i) and (sym2 is None or not sym2.isShort())):
             # Disassemble this second pair of bytes as two bytes if either:
             # - There's a symbol at (word's address + 3)
@@ -311,11 +579,11 @@ class SymbolBase(common.ElementBase):
             output += self.getJByteAsByte(i, 2)
             output += common.GlobalConfig.LINE_ENDS
 
-            if sym3 is not None:
+            if sym3 is not None and sym3.innerLabelOf is not self.contextSym:
                 output += self.getSizeDirective(lastSymName)
                 lastSymName = sym3.getName()
 
-            output += self.getExtraLabelFromSymbol(sym3)
+            output += self._getMiddleLabel(sym3)
             output += self.getJByteAsByte(i, 3)
             output += common.GlobalConfig.LINE_ENDS
         else:
@@ -338,6 +606,13 @@ class SymbolBase(common.ElementBase):
         if not symbolRef.allowedToBeReferenced:
             return False
 
+        if word != symbolRef.vram:
+            addend = word - symbolRef.vram
+            if common.GlobalConfig.DATA_ADDEND_MAX is not None and abs(addend) > common.GlobalConfig.DATA_ADDEND_MAX:
+                return False
+            if symbolRef.getType() in common.GlobalConfig.DATA_ADDEND_FORBIDDEN_TYPES:
+                return False
+
         symType = symbolRef.getTypeSpecial()
         if isinstance(symType, common.SymbolSpecialType):
             if symType == common.SymbolSpecialType.function:
@@ -362,6 +637,13 @@ class SymbolBase(common.ElementBase):
 
         return True
 
+    def _getSegmentedAddressReference(self, address: int, symName: str) -> str:
+        segmentId = address >> 24
+        if common.GlobalConfig.SEGMENTED_ADDRESS_MACRO is not None:
+            return f"{common.GlobalConfig.SEGMENTED_ADDRESS_MACRO}(0x{segmentId:02X}, {symName})"
+        base = common.GlobalConfig.SEGMENTED_ADDRESS_TABLE[segmentId]
+        return f"{symName} - 0x{base:08X} + 0x{segmentId:02X}000000"
+
     def getNthWordAsWords(self, i: int, canReferenceSymbolsWithAddends: bool=False, canReferenceConstants: bool=False, isSplittedSymbol: bool=False) -> tuple[str, int]:
         output = ""
         localOffset = 4*i
@@ -373,7 +655,7 @@ class SymbolBase(common.ElementBase):
 
         label = ""
         if i != 0:
-            label = self.getExtraLabelFromSymbol(self.getSymbol(currentVram, vromAddress=currentVrom, tryPlusOffset=False))
+            label = self.getExtraLabelFromSymbol(self._getNonInnerLabelSymbol(currentVram, currentVrom))
 
         value = f"0x{w:08X}"
 
@@ -384,7 +666,7 @@ class SymbolBase(common.ElementBase):
                 # If the reloc type is none then use the raw number instead
                 pass
             elif relocInfo.staticReference is not None:
-                relocVram = relocInfo.staticReference.sectionVram + w
+                relocVram = relocInfo.staticReference.sectionVram + w + relocInfo.addend
                 contextSym = self.getSymbol(relocVram, checkUpperLimit=False)
                 if contextSym is not None:
                     value = contextSym.getSymbolPlusOffset(relocVram)
@@ -396,27 +678,48 @@ class SymbolBase(common.ElementBase):
                 wordRel = relocInfo.relocType.getWordRel()
                 if wordRel is not None:
                     dotType = wordRel
-        elif self.contextSym.isJumpTable():
-            if self.contextSym.isGot and common.GlobalConfig.GP_VALUE is not None:
+        elif self.contextSym.isJumpTable() or self.contextSym.isGccExceptTable():
+            # Except tables reference landing pad labels in the same way jumptables reference their labels
+            validLabelTypes = {common.SymbolSpecialType.jumptablelabel, common.SymbolSpecialType.gccexcepttablelabel, common.SymbolSpecialType.function}
+            jumpTableBase = self.contextSym.jumpTableBase
+            if jumpTableBase is not None:
+                labelAddr = jumpTableBase.vram + rabbitizer.Utils.from2Complement(w, 32)
+                labelSym = self.getSymbol(labelAddr, tryPlusOffset=False)
+                if labelSym is not None and labelSym.getTypeSpecial() in validLabelTypes:
+                    value = f"{labelSym.getName()} - {jumpTableBase.getName()}"
+                labelSym = None
+            elif self.contextSym.isGot and common.GlobalConfig.GP_VALUE is not None:
                 labelAddr = common.GlobalConfig.GP_VALUE + rabbitizer.Utils.from2Complement(w, 32)
                 labelSym = self.getSymbol(labelAddr, tryPlusOffset=False)
                 if labelSym is not None:
                     labelType = labelSym.getTypeSpecial()
-                    if labelType == common.SymbolSpecialType.jumptablelabel or labelType == common.SymbolSpecialType.function:
+                    if labelType in validLabelTypes:
                         dotType = ".gpword"
             else:
                 labelSym = self.getSymbol(w, tryPlusOffset=False)
 
             if labelSym is not None:
                 labelType = labelSym.getTypeSpecial()
-                if labelType == common.SymbolSpecialType.jumptablelabel or labelType == common.SymbolSpecialType.function:
+                if labelType in validLabelTypes:
                     # We check for function references too because this symbol may have gotten wrongly identified as a jumptable because of tail call optimizations.
                     value = labelSym.getName()
-        else:
+        elif not self.context.isRawDataAddress(currentVram):
+            segmentedVram = common.GlobalConfig.getSegmentedAddressVram(w)
+            if segmentedVram is not None:
+                # Segmented address from a mapped segment
+                symbolRef = self.getSymbol(segmentedVram, tryPlusOffset=canReferenceSymbolsWithAddends)
+                if symbolRef is not None:
+                    value = self._getSegmentedAddressReference(w, symbolRef.getSymbolPlusOffset(segmentedVram))
             # This word could be a reference to a symbol
-            if not self.context.isAddressBanned(w):
+            elif not self.context.isAddressBanned(w):
                 symbolRef = self.getSymbol(w, tryPlusOffset=canReferenceSymbolsWithAddends)
-                if symbolRef is not None and not self.context.isAddressBanned(symbolRef.vram):
+                kseg0Vram = common.GlobalConfig.getKseg0AliasVram(w)
+                if symbolRef is None and kseg0Vram is not None and not self.context.isAddressBanned(kseg0Vram):
+                    # Uncached alias of a KSEG0 address
+                    aliasedRef = self.getSymbol(kseg0Vram, tryPlusOffset=canReferenceSymbolsWithAddends)
+                    if aliasedRef is not None and self._allowWordSymbolReference(aliasedRef, kseg0Vram):
+                        value = common.GlobalConfig.getKseg1AliasReference(aliasedRef.getSymbolPlusOffset(kseg0Vram))
+                elif symbolRef is not None and not self.context.isAddressBanned(symbolRef.vram):
                     if self._allowWordSymbolReference(symbolRef, w):
                         value = symbolRef.getSymbolPlusOffset(w)
                 elif canReferenceConstants:
@@ -425,8 +728,8 @@ class SymbolBase(common.ElementBase):
                         value = constant.getName()
 
         comment = self.generateAsmLineComment(localOffset, w)
-        output += f"{label}{comment} {dotType} {value}"
-        output += self.getEndOfLineComment(i)
+        output += label + self.joinCommentAndStatement(comment, self.formatDirective(dotType, value))
+        output += self.getEndOfLineComment(i, output)
         output += common.GlobalConfig.LINE_ENDS
 
         return output, 0
@@ -440,15 +743,15 @@ class SymbolBase(common.ElementBase):
 
         label = ""
         if i != 0:
-            label = self.getExtraLabelFromSymbol(self.getSymbol(currentVram, vromAddress=currentVrom, tryPlusOffset=False))
+            label = self.getExtraLabelFromSymbol(self._getNonInnerLabelSymbol(currentVram, currentVrom))
 
         dotType = ".float"
         floatValue = common.Utils.wordToFloat(w)
         value = f"{floatValue:.10g}"
 
         comment = self.generateAsmLineComment(localOffset, w)
-        output += f"{label}{comment} {dotType} {value}"
-        output += self.getEndOfLineComment(i)
+        output += label + self.joinCommentAndStatement(comment, self.formatDirective(dotType, value))
+        output += self.getEndOfLineComment(i, output)
         output += common.GlobalConfig.LINE_ENDS
 
         return output, 0
@@ -462,7 +765,7 @@ class SymbolBase(common.ElementBase):
 
         label = ""
         if i != 0:
-            label = self.getExtraLabelFromSymbol(self.getSymbol(currentVram, vromAddress=currentVrom, tryPlusOffset=False))
+            label = self.getExtraLabelFromSymbol(self._getNonInnerLabelSymbol(currentVram, currentVrom))
 
         dotType = ".double"
         if common.GlobalConfig.ENDIAN == common.InputEndian.LITTLE:
@@ -475,13 +778,70 @@ class SymbolBase(common.ElementBase):
         value = f"{doubleValue:.18g}"
 
         comment = self.generateAsmLineComment(localOffset, doubleWord, isDouble=True)
-        output += f"{label}{comment} {dotType} {value}"
-        output += self.getEndOfLineComment(i)
+        output += label + self.joinCommentAndStatement(comment, self.formatDirective(dotType, value))
+        output += self.getEndOfLineComment(i, output)
+        output += common.GlobalConfig.LINE_ENDS
+
+        return output, 1
+
+    def getNthWordAsDword(self, i: int) -> tuple[str, int]:
+        output = ""
+        localOffset = 4*i
+        currentVram = self.getVramOffset(localOffset)
+        currentVrom = self.getVromOffset(localOffset)
+        w = self.words[i]
+
+        label = ""
+        if i != 0:
+            label = self.getExtraLabelFromSymbol(self._getNonInnerLabelSymbol(currentVram, currentVrom))
+
+        dotType = ".dword"
+        otherHalf = self.words[i+1]
+        if common.GlobalConfig.ENDIAN == common.InputEndian.LITTLE:
+            dword = (otherHalf << 32) | w
+        else:
+            dword = (w << 32) | otherHalf
+        value = f"0x{dword:016X}"
+
+        comment = self.generateAsmLineComment(localOffset, dword, isDouble=True)
+        output += label + self.joinCommentAndStatement(comment, self.formatDirective(dotType, value))
+        output += self.getEndOfLineComment(i, output)
         output += common.GlobalConfig.LINE_ENDS
 
         return output, 1
 
+    def getNthWordAsCharmapString(self, i: int, charmap: common.Charmap) -> tuple[str, int]:
+        localOffset = 4*i
+
+        buffer = common.Utils.wordsToBytes(self.words)
+        decodedString, rawStringSize = charmap.decode(buffer, localOffset)
+        if rawStringSize < 0:
+            return "", -1
+
+        skip = rawStringSize // 4
+        comment = self.generateAsmLineComment(localOffset)
+        result = self.joinCommentAndStatement(comment, "")
+
+        continuationPadding = self._getContinuationPadding(comment)
+
+        if common.GlobalConfig.ASM_COMMENT:
+            escaped = decodedString.replace("*/", "* /")
+            result += f"/* \"{escaped}\" */{common.GlobalConfig.LINE_ENDS}" + continuationPadding
+
+        # Include the terminator
+        stringBytes = buffer[localOffset:localOffset+rawStringSize+1]
+        for j in range(0, len(stringBytes), 16):
+            if j != 0:
+                result += common.GlobalConfig.LINE_ENDS + continuationPadding
+            result += self.formatDirective(".byte", ", ".join(f"0x{x:02X}" for x in stringBytes[j:j+16]))
+        result += common.GlobalConfig.LINE_ENDS
+
+        return result, skip
+
     def getNthWordAsString(self, i: int) -> tuple[str, int]:
+        if self.charmap is not None:
+            return self.getNthWordAsCharmapString(i, self.charmap)
+
         localOffset = 4*i
 
         buffer = common.Utils.wordsToBytes(self.words)
@@ -491,18 +851,16 @@ class SymbolBase(common.ElementBase):
 
         skip = rawStringSize // 4
         comment = self.generateAsmLineComment(localOffset)
-        result = f"{comment} "
+        result = self.joinCommentAndStatement(comment, "")
 
-        commentPaddingNum = 22
-        if not common.GlobalConfig.ASM_COMMENT:
-            commentPaddingNum = 1
+        continuationPadding = self._getContinuationPadding(comment)
 
         if rawStringSize == 0:
             decodedStrings.append("")
         for decodedValue in decodedStrings[:-1]:
-            result += f'.ascii "{decodedValue}"'
-            result += common.GlobalConfig.LINE_ENDS + (commentPaddingNum * " ")
-        result += f'.asciz "{decodedStrings[-1]}"{common.GlobalConfig.LINE_ENDS}'
+            result += self.formatDirective(".ascii", f'"{decodedValue}"')
+            result += common.GlobalConfig.LINE_ENDS + continuationPadding
+        result += self.formatDirective(".asciz", f'"{decodedStrings[-1]}"') + common.GlobalConfig.LINE_ENDS
 
         return result, skip
 
@@ -516,18 +874,16 @@ class SymbolBase(common.ElementBase):
 
         skip = (rawStringSize - 1) // 4
         comment = self.generateAsmLineComment(localOffset)
-        result = f"{comment} "
+        result = self.joinCommentAndStatement(comment, "")
 
-        commentPaddingNum = 22
-        if not common.GlobalConfig.ASM_COMMENT:
-            commentPaddingNum = 1
+        continuationPadding = self._getContinuationPadding(comment)
 
         if rawStringSize == 0:
             decodedStrings.append("")
         for decodedValue in decodedStrings[:-1]:
-            result += f'.ascii "{decodedValue}"'
-            result += common.GlobalConfig.LINE_ENDS + (commentPaddingNum * " ")
-        result += f'.ascii "{decodedStrings[-1]}"{common.GlobalConfig.LINE_ENDS}'
+            result += self.formatDirective(".ascii", f'"{decodedValue}"')
+            result += common.GlobalConfig.LINE_ENDS + continuationPadding
+        result += self.formatDirective(".ascii", f'"{decodedStrings[-1]}"') + common.GlobalConfig.LINE_ENDS
 
         return result, skip
 
@@ -563,24 +919,28 @@ class SymbolBase(common.ElementBase):
         return f".align {shiftValue}{common.GlobalConfig.LINE_ENDS}"
 
     def getPrevAlignDirective(self, i: int) -> str:
+        shiftValue: int|None = None
         if self.isDouble(i):
-            shiftValue = common.GlobalConfig.COMPILER.value.prevAlign_double
-            if shiftValue is not None:
-                return self._getAlignDirectiveStr(shiftValue, i)
+            shiftValue = common.GlobalConfig.getCompilerProperties().prevAlign_double
         elif self.isJumpTable():
             if i == 0:
-                shiftValue = common.GlobalConfig.COMPILER.value.prevAlign_jumptable
-                if shiftValue is not None:
-                    return self._getAlignDirectiveStr(shiftValue, i)
+                shiftValue = common.GlobalConfig.getCompilerProperties().prevAlign_jumptable
         elif self.isString() or self.isPascalString():
-            shiftValue = common.GlobalConfig.COMPILER.value.prevAlign_string
-            if shiftValue is not None:
-                return self._getAlignDirectiveStr(shiftValue, i)
+            shiftValue = common.GlobalConfig.getCompilerProperties().prevAlign_string
         elif self.isFunction():
-            shiftValue = common.GlobalConfig.COMPILER.value.prevAlign_function
-            if shiftValue is not None:
-                return self._getAlignDirectiveStr(shiftValue, i)
+            shiftValue = common.GlobalConfig.getCompilerProperties().prevAlign_function
+
+        if shiftValue is None and i == 0 and self.contextSym.accessType == rabbitizer.AccessType.QUADWORD:
+            shiftValue = common.GlobalConfig.getCompilerProperties().prevAlign_quadword
 
+        if i == 0:
+            # The user declared alignment can only make the alignment stricter than the one expected by the compiler
+            userShiftValue = self.contextSym.getAlignmentShift()
+            if userShiftValue is not None and (shiftValue is None or userShiftValue > shiftValue):
+                shiftValue = userShiftValue
+
+        if shiftValue is not None:
+            return self._getAlignDirectiveStr(shiftValue, i)
         return ""
 
     def getPostAlignDirective(self, i: int) -> str:
@@ -589,9 +949,54 @@ class SymbolBase(common.ElementBase):
 
         return ""
 
+    def isFoldedPad(self) -> bool:
+        """
+        Returns `True` if this symbol is an unreferenced automatically
+        generated pad which will be emitted as trailing padding of the previous
+        symbol instead of as a symbol on its own.
+
+        See `GlobalConfig.FOLD_AUTOGENERATED_PADS`.
+        """
+        return self.contextSym.isFoldedPad()
+
+    def _getFoldedPadComment(self, size: int) -> str:
+        if not common.GlobalConfig.ASM_COMMENT:
+            return ""
+        mainSymbolInfo = ""
+        if self.contextSym.autoCreatedPadMainSymbol is not None:
+            mainSymbolInfo = f" of {self.contextSym.autoCreatedPadMainSymbol.getName()}"
+        return f"/* Trailing padding{mainSymbolInfo} (0x{size:X} bytes) */{common.GlobalConfig.LINE_ENDS}"
+
+    def disassembleAsFoldedPad(self, isSplittedSymbol: bool=False) -> str:
+        size = self.sizew * 4
+        output = self._getFoldedPadComment(size)
+
+        if all(word == 0 for word in self.words):
+            output += self.joinCommentAndStatement(self.generateAsmLineComment(0), self.formatDirective(".space", f"0x{size:02X}"))
+            output += common.GlobalConfig.LINE_ENDS
+            return output
+
+        canReferenceSymbolsWithAddends = self.canUseAddendsOnData()
+        canReferenceConstants = self.canUseConstantsOnData()
+
+        i = 0
+        while i < self.sizew:
+            data, skip = self.getNthWord(i, isSplittedSymbol=isSplittedSymbol, canReferenceSymbolsWithAddends=canReferenceSymbolsWithAddends, canReferenceConstants=canReferenceConstants)
+            output += data
+            i += skip
+            i += 1
+
+        return output
+
     def disassembleAsData(self, useGlobalLabel: bool=True, isSplittedSymbol: bool=False) -> str:
+        if self.isFoldedPad():
+            return self.disassembleAsFoldedPad(isSplittedSymbol=isSplittedSymbol)
+
         output = self.contextSym.getReferenceeSymbols()
         output += self.getPrevAlignDirective(0)
+        output += self.getDiagnosticsComments()
+        output += self.getCrossReferencesComment()
+        output += self.getDuplicatedStringsComment()
 
         symName = self.getName()
         output += self.getSymbolAsmDeclaration(symName, useGlobalLabel)
@@ -601,6 +1006,10 @@ class SymbolBase(common.ElementBase):
         canReferenceSymbolsWithAddends = self.canUseAddendsOnData()
         canReferenceConstants = self.canUseConstantsOnData()
 
+        elementSize = self.contextSym.getArrayElementSize()
+        elementCount = self.contextSym.getArrayElementCount()
+        lastElementIndex: int|None = None
+
         i = 0
         while i < self.sizew:
             currentVram = self.getVramOffset(i*4)
@@ -611,36 +1020,42 @@ class SymbolBase(common.ElementBase):
             sym3 = self.getSymbol(currentVram+3, vromAddress=currentVrom, tryPlusOffset=False, checkGlobalSegment=False)
 
             # Check for symbols in the middle of this word
-            if sym1 is not None or sym2 is not None or sym3 is not None or self.isByte(i) or self.isShort(i):
+            if sym1 is not None or sym2 is not None or sym3 is not None or self.isByte(i) or self.isShort(i) or self._hasShortReloc(i):
                 data, skip = self.getNthWordAsBytesAndShorts(i, sym1, sym2, sym3, lastSymName)
 
-                if sym3 is not None:
-                    lastSymName = sym3.getName()
-                elif sym2 is not None:
-                    lastSymName = sym2.getName()
-                elif sym1 is not None:
-                    lastSymName = sym1.getName()
+                for middleSym in (sym3, sym2, sym1):
+                    if middleSym is not None and middleSym.innerLabelOf is not self.contextSym:
+                        lastSymName = middleSym.getName()
+                        break
             elif self.isFloat(i):
                 data, skip = self.getNthWordAsFloat(i)
             elif self.isDouble(i):
                 data, skip = self.getNthWordAsDouble(i)
+            elif self.isDword(i):
+                data, skip = self.getNthWordAsDword(i)
             elif self.isString():
                 data, skip = self.getNthWordAsString(i)
                 if skip < 0:
-                    # Not a string
-                    self.contextSym.failedStringDecoding = True
+                    # Not a string. Already reported during analysis
                     data, skip = self.getNthWord(i, isSplittedSymbol=isSplittedSymbol, canReferenceSymbolsWithAddends=canReferenceSymbolsWithAddends, canReferenceConstants=canReferenceConstants)
             elif self.isPascalString():
                 data, skip = self.getNthWordAsPascalString(i)
                 if skip < 0:
-                    # Not a string
-                    self.contextSym.failedPascalStringDecoding = True
+                    # Not a string. Already reported during analysis
                     data, skip = self.getNthWord(i, isSplittedSymbol=isSplittedSymbol, canReferenceSymbolsWithAddends=canReferenceSymbolsWithAddends, canReferenceConstants=canReferenceConstants)
             else:
                 data, skip = self.getNthWord(i, isSplittedSymbol=isSplittedSymbol, canReferenceSymbolsWithAddends=canReferenceSymbolsWithAddends, canReferenceConstants=canReferenceConstants)
 
             if i != 0:
                 output += self.getPrevAlignDirective(i)
+                innerSym = self.getSymbol(currentVram, vromAddress=currentVrom, tryPlusOffset=False)
+                if innerSym is not None and innerSym.innerLabelOf is self.contextSym:
+                    output += self.getInnerLabel(innerSym)
+            if elementSize is not None and elementCount is not None:
+                elementIndex = (i * 4) // elementSize
+                if elementIndex != lastElementIndex:
+                    output += self.getArrayElementHeader(elementIndex, elementCount)
+                    lastElementIndex = elementIndex
             output += data
             if common.GlobalConfig.EMIT_INLINE_RELOC:
                 relocInfo = self.getReloc(i*4, None)
@@ -650,6 +1065,8 @@ class SymbolBase(common.ElementBase):
             i += skip
             i += 1
 
+        if common.GlobalConfig.ASM_DATA_END_LABEL:
+            output += f"{common.GlobalConfig.ASM_DATA_END_LABEL} {lastSymName}{common.GlobalConfig.LINE_ENDS}"
         output += self.getSizeDirective(lastSymName)
 
         nameEnd = self.getNameEnd()
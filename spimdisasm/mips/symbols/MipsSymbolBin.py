@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+from ... import common
+
+from . import SymbolBase
+
+
+class SymbolBin(SymbolBase):
+    BYTES_PER_LINE = 16
+
+    def __init__(self, context: common.Context, vromStart: int, vromEnd: int, inFileOffset: int, vram: int, rawBytes: bytes, segmentVromStart: int, overlayCategory: str|None) -> None:
+        super().__init__(context, vromStart, vromEnd, inFileOffset, vram, list(), common.FileSectionType.Bin, segmentVromStart, overlayCategory)
+
+        self.rawBytes: bytes = rawBytes
+
+        self.incbinPath: str|None = None
+        "If not `None` then this symbol is emitted as an `.incbin` directive of the given path instead of an hex dump"
+        self.incbinOffset: int = 0
+        "The offset of this symbol inside the file pointed by `incbinPath`"
+
+    @property
+    def sizew(self) -> int:
+        return len(self.rawBytes) // 4
+
+    @property
+    def vramEnd(self) -> int:
+        return self.vram + len(self.rawBytes)
+
+    def analyze(self) -> None:
+        # There's nothing to analyze on a raw blob
+        self.contextSym.inFileOffset = self.inFileOffset
+        if self.parent is not None:
+            self.contextSym.parentFileName = self.parent.getName()
+
+    def disassembleAsBin(self, useGlobalLabel: bool=True) -> str:
+        output = self.contextSym.getReferenceeSymbols()
+        output += self.getDiagnosticsComments()
+
+        symName = self.getName()
+        output += self.getSymbolAsmDeclaration(symName, useGlobalLabel)
+
+        if self.incbinPath is not None:
+            output += self.joinCommentAndStatement(self.generateAsmLineComment(0), self.formatDirective(".incbin", f"\"{self.incbinPath}\", 0x{self.incbinOffset:X}, 0x{len(self.rawBytes):X}"))
+            output += common.GlobalConfig.LINE_ENDS
+        else:
+            for i in range(0, len(self.rawBytes), self.BYTES_PER_LINE):
+                chunk = self.rawBytes[i:i+self.BYTES_PER_LINE]
+                output += self.joinCommentAndStatement(self.generateAsmLineComment(i), self.formatDirective(".byte", ", ".join(f"0x{x:02X}" for x in chunk)))
+                output += common.GlobalConfig.LINE_ENDS
+
+        output += self.getSizeDirective(symName)
+
+        nameEnd = self.getNameEnd()
+        if nameEnd is not None:
+            output += self.getSymbolAsmDeclaration(nameEnd, useGlobalLabel)
+
+        return output
+
+    def disassemble(self, migrate: bool=False, useGlobalLabel: bool=True, isSplittedSymbol: bool=False) -> str:
+        return self.disassembleAsBin(useGlobalLabel=useGlobalLabel)
@@ -40,6 +40,12 @@ class SymbolBss(SymbolBase):
     def sizew(self) -> int:
         return self.spaceSize // 4
 
+    def isDouble(self, index: int) -> bool:
+        # bss symbols have no words to check against
+        if index != 0:
+            return False
+        return self.contextSym.isDouble()
+
     def analyze(self) -> None:
         super().analyze()
 
@@ -51,18 +57,44 @@ class SymbolBss(SymbolBase):
 Range check triggered: .bss symbol (name: {self.getName()}, address: 0x{self.contextSym.vram:08X}):
     User declared size (0x{contextSymSize:X}) does not match the .space that will be emitted (0x{self.spaceSize:X}).
     Try checking the size again or look for symbols which overlaps this region"""
+                self.context.addDiagnostic(common.DiagnosticKind.OVERLAPPING_SYMBOLS, f"User declared size (0x{contextSymSize:X}) does not match the emitted size (0x{self.spaceSize:X})", self.vram, self.vromStart, self.getName())
                 if common.GlobalConfig.PANIC_RANGE_CHECK:
                     assert self.spaceSize == contextSymSize, warningMessage
                 else:
                     common.Utils.eprint(f"\n{warningMessage}\n")
 
+    def disassembleAsCommon(self) -> str:
+        output = self.contextSym.getReferenceeSymbols()
+
+        output += self.joinCommentAndStatement(self.generateAsmLineComment(0, emitRomOffset=False), self.formatDirective(".comm", f"{self.getName()}, 0x{self.spaceSize:02X}"))
+        if self.contextSym.commonAlignment is not None:
+            output += f", {self.contextSym.commonAlignment}"
+        output += common.GlobalConfig.LINE_ENDS
+
+        return output
+
     def disassembleAsBss(self, useGlobalLabel: bool = True) -> str:
+        if self.contextSym.isCommon:
+            return self.disassembleAsCommon()
+
+        if self.isFoldedPad():
+            output = self._getFoldedPadComment(self.spaceSize)
+            output += self.joinCommentAndStatement(self.generateAsmLineComment(0, emitRomOffset=False), self.formatDirective(".space", f"0x{self.spaceSize:02X}"))
+            output += common.GlobalConfig.LINE_ENDS
+            return output
+
         output = self.contextSym.getReferenceeSymbols()
         output += self.getPrevAlignDirective(0)
+        output += self.getDiagnosticsComments()
+        output += self.getCrossReferencesComment()
 
         output += self.getSymbolAsmDeclaration(self.getName(), useGlobalLabel)
-        output += self.generateAsmLineComment(0, emitRomOffset=False)
-        output += f" .space 0x{self.spaceSize:02X}{common.GlobalConfig.LINE_ENDS}"
+        output += self.joinCommentAndStatement(self.generateAsmLineComment(0, emitRomOffset=False), self.formatDirective(".space", f"0x{self.spaceSize:02X}"))
+        output += common.GlobalConfig.LINE_ENDS
+        if common.GlobalConfig.ASM_DATA_END_LABEL:
+            output += f"{common.GlobalConfig.ASM_DATA_END_LABEL} {self.getName()}{common.GlobalConfig.LINE_ENDS}"
+        if common.GlobalConfig.ASM_EMIT_BSS_SIZE_DIRECTIVE:
+            output += self.getSizeDirective(self.getName())
 
         nameEnd = self.getNameEnd()
         if nameEnd is not None:
@@ -46,6 +46,7 @@ class SymbolData(SymbolBase):
 Range check triggered: .data symbol (name: {self.getName()}, address: 0x{self.contextSym.vram:08X}):
     User declared size (0x{contextSymSize:X}) does not match the amount of bytes that will be emitted (0x{actualSize:X}).
     Try checking the size again or look for symbols which overlaps this region"""
+                self.context.addDiagnostic(common.DiagnosticKind.OVERLAPPING_SYMBOLS, f"User declared size (0x{contextSymSize:X}) does not match the emitted size (0x{actualSize:X})", self.vram, self.vromStart, self.getName())
                 if common.GlobalConfig.PANIC_RANGE_CHECK:
                     assert actualSize == contextSymSize, warningMessage
                 else:
@@ -29,6 +29,10 @@ class SymbolFunction(SymbolText):
         self.isLikelyHandwritten: bool = False
         self.gpRelHack: bool = False
 
+        self._resolvedLabels: dict[int, common.ContextSymbol|None]|None = None
+        self._resolvedBranchTargets: dict[int, common.ContextSymbol|None]|None = None
+        self._resolvedHiSymbols: dict[int, common.ContextSymbol|None]|None = None
+
     @property
     def nInstr(self) -> int:
         return len(self.instructions)
@@ -149,6 +153,8 @@ class SymbolFunction(SymbolText):
 
             instructionOffset += 4
 
+        self.instrAnalyzer.processTailCalls(self.instructions)
+
         self.instrAnalyzer.printSymbolFinderDebugInfo_UnpairedLuis()
 
     def _processElfRelocSymbols(self) -> None:
@@ -165,7 +171,7 @@ class SymbolFunction(SymbolText):
             if relocInfo is not None:
                 if relocInfo.relocType == common.RelocType.MIPS_26:
                     if relocInfo.staticReference is not None:
-                        targetVram = instr.getInstrIndexAsVram() + relocInfo.staticReference.sectionVram
+                        targetVram = instr.getInstrIndexAsVram() + relocInfo.staticReference.sectionVram + relocInfo.addend
                         if instr.doesLink():
                             # Function call
                             relocInfo.symbol = self.addFunction(targetVram, isAutogenerated=True)
@@ -181,7 +187,8 @@ class SymbolFunction(SymbolText):
 
                     if relocInfo.staticReference is not None:
                         # For static symbols which only reference the start of a section
-                        symbolVram = relocInfo.staticReference.sectionVram
+                        # `.rela` sections store the offset in the addend instead of the instruction itself
+                        symbolVram = relocInfo.staticReference.sectionVram + relocInfo.addend
                         if instr.hasOperandAlias(rabbitizer.OperandType.cpu_immediate):
                             if instructionOffset in self.instrAnalyzer.symbolInstrOffset:
                                 addressOffset = self.instrAnalyzer.symbolInstrOffset[instructionOffset]
@@ -202,6 +209,53 @@ class SymbolFunction(SymbolText):
             instructionOffset += 4
 
 
+    def getFrameInfo(self) -> analysis.FunctionFrameInfo:
+        "Returns a summary of the stack frame and register usage of this function"
+        return analysis.FunctionFrameInfo.fromInstructions(self.instructions)
+
+    def getDelaySlotHazards(self) -> list[analysis.DelaySlotHazard]:
+        "Returns every suspicious delay slot of this function. See `analysis.DelaySlotHazard`"
+        return analysis.DelaySlotHazard.fromInstructions(self.instructions)
+
+    def getAnalysisResults(self) -> analysis.InstrAnalysisResults:
+        """
+        Returns a read-only snapshot of the instruction analysis of this
+        function, like branch targets, function calls, referenced jumptables,
+        %hi/%lo pairs and constants.
+
+        Must be called after `analyze`.
+        """
+        return analysis.InstrAnalysisResults.fromAnalyzer(self.instrAnalyzer)
+
+    def _applyInstructionOverrides(self) -> None:
+        if len(self.context.instructionOverrides) == 0:
+            return
+
+        for instructionOffset in range(0, self.sizew * 4, 4):
+            override = self.context.instructionOverrides.get(self.getVromOffset(instructionOffset))
+            if override is None:
+                continue
+
+            instr = self.instructions[instructionOffset//4]
+
+            if override.pairedHiVrom is not None:
+                hiOffset = override.pairedHiVrom - self.vromStart
+                if 0 <= hiOffset < self.sizew * 4 and hiOffset % 4 == 0:
+                    hiInstr = self.instructions[hiOffset//4]
+                    if hiInstr.canBeHi() and instr.canBeLo():
+                        self.instrAnalyzer.unsetSymbolAtOffset(instructionOffset)
+                        self.instrAnalyzer.unsetSymbolAtOffset(hiOffset)
+                        address = (hiInstr.getProcessedImmediate() << 16) + instr.getProcessedImmediate()
+                        self.instrAnalyzer.processSymbol(address, hiOffset, instr, instructionOffset)
+
+            if override.isConstant or override.reloc is not None:
+                self.instrAnalyzer.unsetSymbolAtOffset(instructionOffset)
+
+            if override.reloc is not None and isinstance(override.reloc.symbol, common.ContextSymbol):
+                override.reloc.symbol.referenceCounter += 1
+                override.reloc.symbol.referenceFunctions.add(self.contextSym)
+                override.reloc.symbol.addReferenceLocation(self.contextSym, self.getVromOffset(instructionOffset), isFromFunction=True)
+
     def _postProcessGotAccesses(self) -> None:
         if not common.GlobalConfig.PIC:
             return
@@ -368,9 +422,11 @@ class SymbolFunction(SymbolText):
             instr = self.instructions[instrOffset//4]
 
             relocType = self._getRelocTypeForInstruction(instr, instrOffset, contextSym, gotHiLo, gotSmall)
-            if relocType == common.RelocType.MIPS_GPREL16:
-                contextSym.accessedAsGpRel = True
-            self.relocs[instrOffset] = common.RelocationInfo(relocType, contextSym, address - contextSym.vram)
+            addend = address - contextSym.vram
+            if instrOffset in self.instrAnalyzer.kseg1AliasInstrOffsets:
+                # Keep referencing the uncached alias
+                addend += 0x20000000
+            self.relocs[instrOffset] = common.RelocationInfo(relocType, contextSym, addend)
 
         for instrOffset in self.instrAnalyzer.cploadOffsets:
             # .cpload directive is meant to use the `_gp_disp` pseudo-symbol
@@ -394,7 +450,7 @@ class SymbolFunction(SymbolText):
             else:
                 # TODO: consider reusing the logic of the self.instrAnalyzer.symbolInstrOffset loop
                 address = gpInfo.value
-                if self.context.isAddressBanned(address):
+                if self.context.isAddressBanned(address) or not common.GlobalConfig.isPointerAllowed(address):
                     continue
 
                 contextSym = self.getSymbol(address)
@@ -440,9 +496,26 @@ class SymbolFunction(SymbolText):
             funcSym = self.getSymbolFromAnySegment(targetVram, lambda contextSym: contextSym.type == common.SymbolSpecialType.function or contextSym.type is None or (is_j and isinstance(contextSym.type, common.SymbolSpecialType) and contextSym.type.isTargetLabel()), tryPlusOffset=False)
             if funcSym is None:
                 continue
-            self.relocs[instrOffset] = common.RelocationInfo(common.RelocType.MIPS_26, funcSym)
+            # Tail calls may be done with a plain branch instead of a `j`
+            relocType = common.RelocType.MIPS_26 if instr.isJumpWithAddress() else common.RelocType.MIPS_PC16
+            # The addend is only non zero if the target belongs to an overlay loaded at an alternative address
+            self.relocs[instrOffset] = common.RelocationInfo(relocType, funcSym, targetVram - funcSym.vram)
 
 
+    def _checkMisalignedAccess(self, loOffset: int, symVram: int) -> None:
+        instr = self.instructions[loOffset//4]
+        if not instr.doesDereference():
+            return
+
+        accessInfo = common.ContextSymbols.gAccessKinds.get(instr.getAccessType())
+        if accessInfo is None or accessInfo.size is None:
+            return
+        alignment = accessInfo.size
+        if symVram % alignment == 0:
+            return
+
+        self.context.addDiagnostic(common.DiagnosticKind.MISALIGNED_REFERENCE, f"Access to 0x{symVram:08X} is not aligned to {alignment} bytes", self.getVramOffset(loOffset), self.getVromOffset(loOffset), self.getName())
+
     def analyze(self) -> None:
         self.contextSym.inFileOffset = self.inFileOffset
         if self.parent is not None:
@@ -463,6 +536,7 @@ class SymbolFunction(SymbolText):
 
         self._postProcessGotAccesses()
         self._processElfRelocSymbols()
+        self._applyInstructionOverrides()
 
         # Branches
         for instrOffset, targetBranchVram in self.instrAnalyzer.branchInstrOffsets.items():
@@ -470,17 +544,24 @@ class SymbolFunction(SymbolText):
                 if self.getVromOffset(instrOffset) in self.context.globalRelocationOverrides:
                     # Avoid creating wrong symbols on elf files
                     continue
+            if self.context.isSymbolCreationVetoed(targetBranchVram, common.SymbolCreationReason.BRANCH_TARGET):
+                continue
             branch = self.instrAnalyzer.branchTargetInstrOffsets[instrOffset]
+            if (branch < 0 or branch >= self.sizew * 4) and not self.context.areFunctionContinuations(self.vram, targetBranchVram):
+                self.context.addDiagnostic(common.DiagnosticKind.BRANCH_OUTSIDE_FUNCTION, f"Branch to 0x{targetBranchVram:08X} is outside of the function", self.getVramOffset(instrOffset), self.getVromOffset(instrOffset), self.getName())
             labelSym = self.addBranchLabel(targetBranchVram, isAutogenerated=True, symbolVrom=self.getVromOffset(branch))
             labelSym.referenceCounter += 1
             labelSym.referenceFunctions.add(self.contextSym)
+            labelSym.addReferenceLocation(self.contextSym, self.getVromOffset(instrOffset), isFromFunction=True)
             labelSym.parentFunction = self.contextSym
             labelSym.parentFileName = self.contextSym.parentFileName
             self.contextSym.branchLabels.add(labelSym.vram, labelSym)
 
         # Function calls
         for instrOffset, targetVram in self.instrAnalyzer.funcCallInstrOffsets.items():
-            if self.context.isAddressBanned(targetVram):
+            if self.context.isAddressBanned(targetVram) or not common.GlobalConfig.isPointerAllowed(targetVram):
+                continue
+            if self.context.isSymbolCreationVetoed(targetVram, common.SymbolCreationReason.FUNCTION_CALL):
                 continue
 
             if common.GlobalConfig.INPUT_FILE_TYPE == common.InputFileType.ELF:
@@ -490,7 +571,22 @@ class SymbolFunction(SymbolText):
             funcSym = self.addFunction(targetVram, isAutogenerated=True)
             funcSym.referenceCounter += 1
             funcSym.referenceFunctions.add(self.contextSym)
+            funcSym.addReferenceLocation(self.contextSym, self.getVromOffset(instrOffset), isFromFunction=True)
+
+        if common.GlobalConfig.ASM_COMMENT_TAIL_CALLS:
+            for instrOffset in self.instrAnalyzer.tailCallInstrOffsets:
+                if self.endOfLineComment.get(instrOffset//4) is None:
+                    self.endOfLineComment[instrOffset//4] = " /* tail call */"
+
 
+        if common.GlobalConfig.DETECT_DELAY_SLOT_HAZARDS:
+            for hazard in self.getDelaySlotHazards():
+                self.context.addDiagnostic(common.DiagnosticKind.DELAY_SLOT_HAZARD, f"Delay slot hazard: {hazard.description}", self.getVramOffset(hazard.instrOffset), self.getVromOffset(hazard.instrOffset), self.getName())
+                if self.endOfLineComment.get(hazard.instrOffset//4) is None:
+                    self.endOfLineComment[hazard.instrOffset//4] = hazard.toComment()
+
+        for instrOffset, targetVram in self.instrAnalyzer.funcCallOutsideRangesOffsets.items():
+            self.context.addDiagnostic(common.DiagnosticKind.CALL_OUTSIDE_KNOWN_RANGE, f"Function call to 0x{targetVram:08X} is outside of the known address range", self.getVramOffset(instrOffset), self.getVromOffset(instrOffset), self.getName())
 
         # if not self.isRsp and common.GlobalConfig.INPUT_FILE_TYPE != common.InputFileType.ELF:
         #     for outsideInstrOffset in self.instrAnalyzer.funcCallOutsideRangesOffsets.keys():
@@ -499,7 +595,9 @@ class SymbolFunction(SymbolText):
 
         # Symbols
         for loOffset, symVram in self.instrAnalyzer.symbolLoInstrOffset.items():
-            if self.context.isAddressBanned(symVram):
+            if self.context.isAddressBanned(symVram) or not common.GlobalConfig.isPointerAllowed(symVram):
+                continue
+            if self.context.isSymbolCreationVetoed(symVram, common.SymbolCreationReason.CODE_REFERENCE):
                 continue
 
             if common.GlobalConfig.INPUT_FILE_TYPE == common.InputFileType.ELF:
@@ -513,6 +611,8 @@ class SymbolFunction(SymbolText):
                 # Infer type info if there's only one access type
                 symAccess = list(symAccessDict)[0]
 
+            self._checkMisalignedAccess(loOffset, symVram)
+
             contextSym = self.addSymbol(symVram, isAutogenerated=True, allowAddendInstead=True)
             if contextSym is not None:
                 # TODO: do this in a less ugly way
@@ -526,8 +626,15 @@ class SymbolFunction(SymbolText):
                                     contextSym.setFirstLoAccessIfUnset(loOffset)
                                     contextSym = self.addSymbol(symVram, isAutogenerated=True)
 
+                if common.GlobalConfig.INNER_LABELS_FOR_CODE_REFERENCES and contextSym.address != symVram:
+                    if contextSym.sectionType not in {common.FileSectionType.Text, common.FileSectionType.Bss} and not isinstance(contextSym.getTypeSpecial(), common.SymbolSpecialType) and not contextSym.isString():
+                        parentSym = contextSym
+                        contextSym = self.addSymbol(symVram, isAutogenerated=True)
+                        contextSym.innerLabelOf = parentSym
+
             contextSym.referenceCounter += 1
             contextSym.referenceFunctions.add(self.contextSym)
+            contextSym.addReferenceLocation(self.contextSym, self.getVromOffset(loOffset), isFromFunction=True)
             contextSym.setFirstLoAccessIfUnset(loOffset)
             if symAccess is not None:
                 contextSym.setAccessTypeIfUnset(symAccess.accessType, symAccess.unsignedMemoryAccess)
@@ -550,6 +657,7 @@ class SymbolFunction(SymbolText):
                                     contextSym = self.addSymbol(symVram, isAutogenerated=True)
                                     contextSym.referenceCounter += 1
                                     contextSym.referenceFunctions.add(self.contextSym)
+                                    contextSym.addReferenceLocation(self.contextSym, self.getVromOffset(loOffset), isFromFunction=True)
                                     contextSym.setFirstLoAccessIfUnset(loOffset)
                                     contextSym.isGot = got
                                     contextSym.isGotLocal = gotLocal
@@ -559,9 +667,16 @@ class SymbolFunction(SymbolText):
                                 contextSym.isMips1Double = True
 
         self._generateRelocsFromInstructionAnalyzer()
+        for instrOffset in self.instrAnalyzer.symbolInstrOffset:
+            relocInfo = self.relocs.get(instrOffset)
+            if relocInfo is not None and relocInfo.relocType == common.RelocType.MIPS_GPREL16 and isinstance(relocInfo.symbol, common.ContextSymbol):
+                relocInfo.symbol.accessedAsGpRel = True
+        self._reportUnpairedHis()
 
         # Jump tables
         for targetVram in self.instrAnalyzer.referencedJumpTableOffsets.values():
+            if self.context.isSymbolCreationVetoed(targetVram, common.SymbolCreationReason.JUMPTABLE):
+                continue
             jumpTable = self.addJumpTable(targetVram, isAutogenerated=True)
             jumpTable.parentFunction = self.contextSym
             self.contextSym.jumpTables.add(jumpTable.vram, jumpTable)
@@ -579,6 +694,72 @@ class SymbolFunction(SymbolText):
 
         self.referencedVrams = self.instrAnalyzer.referencedVrams
 
+        self.defineLabels()
+
+        self._identifyBySignature()
+
+
+    def getNormalizedWords(self) -> list[int]:
+        """
+        The raw words of the instructions of this function, with the fields
+        which depend on where the function and its referenced symbols are
+        placed (jump targets, `%hi`/`%lo` pairs, `%gp_rel` accesses and
+        relocated fields) zeroed out.
+        """
+
+        relocatedOffsets: set[int] = set(self.relocs.keys())
+        relocatedOffsets |= self.instrAnalyzer.symbolHiInstrOffset.keys()
+        relocatedOffsets |= self.instrAnalyzer.symbolLoInstrOffset.keys()
+        relocatedOffsets |= self.instrAnalyzer.symbolGpInstrOffset.keys()
+
+        maskedWords: list[int] = []
+        for i, instr in enumerate(self.instructions):
+            word = instr.getRaw()
+            if instr.isJumpWithAddress():
+                word &= 0xFC000000
+            elif i*4 in relocatedOffsets:
+                word &= 0xFFFF0000
+            maskedWords.append(word)
+        return maskedWords
+
+    def getSignatureHash(self) -> str:
+        """
+        Hash of the normalized instructions of this function.
+
+        Two copies of the same function linked in different addresses should
+        produce the same hash, since relocated fields are masked out. See
+        `getStrictHash` for a hash of the unmasked words.
+        """
+
+        wordsBytes = common.Utils.endianessWordsToBytes(common.InputEndian.BIG, self.getNormalizedWords())
+        return common.Utils.getStrHash(wordsBytes)
+
+    def getStrictHash(self) -> str:
+        """
+        Hash of the exact instruction words of this function.
+
+        Meant to be used with `getSignatureHash` to track matching progress: a
+        recompiled function with the same signature hash but a different strict
+        hash only differs on relocated fields.
+        """
+
+        wordsBytes = common.Utils.endianessWordsToBytes(common.InputEndian.BIG, [instr.getRaw() for instr in self.instructions])
+        return common.Utils.getStrHash(wordsBytes)
+
+    def _identifyBySignature(self) -> None:
+        if len(self.context.functionSignatures) == 0:
+            return
+        if self.contextSym.name is not None:
+            # Don't override names given by the user
+            return
+
+        funcName = self.context.functionSignatures.get(self.getSignatureHash())
+        if funcName is None:
+            return
+
+        self.contextSym.name = funcName
+        self.contextSym.identifiedBySignature = True
+
 
     def countExtraPadding(self) -> int:
         count = 0
@@ -599,6 +780,31 @@ class SymbolFunction(SymbolText):
         return count
 
 
+    def _isUnpairedHi(self, luiOffset: int) -> bool:
+        "Checks if the `lui` at the given offset was not paired to a `%lo`, nor symbolized nor relocated in any other way"
+        if luiOffset in self.instrAnalyzer.hiToLowDict or luiOffset in self.instrAnalyzer.cploadOffsets:
+            return False
+        if luiOffset in self.instrAnalyzer.constantHiInstrOffset or luiOffset in self.instrAnalyzer.symbolInstrOffset:
+            return False
+        return luiOffset not in self.relocs
+
+    def countUnpairedHis(self) -> int:
+        "Returns how many `lui` instructions could not be paired to a `%lo` instruction"
+        return sum(1 for luiOffset in self.instrAnalyzer.luiInstrs.keys() if self._isUnpairedHi(luiOffset))
+
+    def countFailedSymbolizations(self) -> int:
+        "Returns how many instructions reference an address which has no symbol nor relocation"
+        count = 0
+        for instrOffset, address in self.instrAnalyzer.symbolInstrOffset.items():
+            if self.context.isAddressBanned(address):
+                continue
+            if self.getReloc(instrOffset, self.instructions[instrOffset//4]) is not None:
+                continue
+            if self.getSymbol(address) is None:
+                count += 1
+        return count
+
+
     def countDiffOpcodes(self, other: SymbolFunction) -> int:
         result = 0
         for i in range(min(self.nInstr, other.nInstr)):
@@ -704,12 +910,155 @@ class SymbolFunction(SymbolText):
         return None
 
 
+    def _generateUnpairedHiReloc(self, value: int, instr: rabbitizer.Instruction) -> common.RelocationInfo|None:
+        policy = common.GlobalConfig.UNPAIRED_HI_POLICY
+        if policy == common.UnpairedHiPolicy.HILO:
+            contextSym = self._lookupHiSymbol(value)
+            if contextSym is not None:
+                return common.RelocationInfo(common.RelocType.MIPS_HI16, contextSym)
+            return common.RelocationInfo(common.RelocType.MIPS_HI16, f"0x{value:X}")
+        if policy in {common.UnpairedHiPolicy.RAW, common.UnpairedHiPolicy.ERROR}:
+            return None
+        return self._generateHiLoConstantReloc(value, instr, None)
+
+    def _reportUnpairedHis(self) -> None:
+        if common.GlobalConfig.UNPAIRED_HI_POLICY != common.UnpairedHiPolicy.ERROR:
+            return
+
+        for instrOffset, instr in sorted(self.instrAnalyzer.luiInstrs.items()):
+            if not self._isUnpairedHi(instrOffset):
+                continue
+            value = instr.getProcessedImmediate() << 16
+            self.context.addDiagnostic(common.DiagnosticKind.UNPAIRED_HI, f"Unpaired `lui` with value 0x{value:08X}", self.getVramOffset(instrOffset), self.getVromOffset(instrOffset), self.getName())
+
+    def _lookupLabelSymbol(self, instructionOffset: int) -> common.ContextSymbol|None:
+        if self._resolvedLabels is not None and instructionOffset in self._resolvedLabels:
+            return self._resolvedLabels[instructionOffset]
+        return self.getSymbol(self.getVramOffset(instructionOffset), vromAddress=self.getVromOffset(instructionOffset), tryPlusOffset=False)
+
+    def _lookupBranchTargetSymbol(self, instr: rabbitizer.Instruction, instrOffset: int) -> common.ContextSymbol|None:
+        if self._resolvedBranchTargets is not None and instrOffset in self._resolvedBranchTargets:
+            return self._resolvedBranchTargets[instrOffset]
+        targetBranchVram = self.getVramOffset(instrOffset + instr.getBranchOffsetGeneric())
+        return self.getSymbol(targetBranchVram, tryPlusOffset=False)
+
+    def _lookupHiSymbol(self, value: int) -> common.ContextSymbol|None:
+        if self._resolvedHiSymbols is not None and value in self._resolvedHiSymbols:
+            return self._resolvedHiSymbols[value]
+        return self.getSymbol(value, tryPlusOffset=False)
+
+    def resolveDisplayReferences(self) -> None:
+        """
+        Looks up once every symbol this function's disassembly refers to by
+        address (its labels, the targets of its branches and the symbols of
+        its unpaired `%hi`s) and keeps them, so generating the disassembly
+        doesn't need to search the context segments for every instruction.
+
+        Only the symbols are kept, not their names, so renaming a symbol after
+        this call is still reflected on the disassembly.
+
+        Adding symbols to the context after this call makes the kept lookups
+        stale. Call this method again or `clearResolvedDisplayReferences` in
+        that case.
+        """
+        self._resolvedLabels = dict()
+        self._resolvedBranchTargets = dict()
+        self._resolvedHiSymbols = dict()
+
+        if self.pointersRemoved:
+            return
+
+        for instrOffset, instr in enumerate(self.instructions):
+            instrOffset *= 4
+
+            if instrOffset != 0:
+                self._resolvedLabels[instrOffset] = self._lookupLabelSymbol(instrOffset)
+
+            if instr.isBranch() or instr.isUnconditionalBranch():
+                self._resolvedBranchTargets[instrOffset] = self._lookupBranchTargetSymbol(instr, instrOffset)
+            elif instr.canBeHi() and instrOffset not in self.instrAnalyzer.symbolInstrOffset and instrOffset not in self.instrAnalyzer.hiToLowDict:
+                value = instr.getProcessedImmediate() << 16
+                if value not in self._resolvedHiSymbols:
+                    self._resolvedHiSymbols[value] = self._lookupHiSymbol(value)
+
+    def defineLabels(self) -> None:
+        """
+        Marks every label inside this function as defined by it.
+
+        Other sections may add labels to this function after it was analyzed
+        (jumptables, except tables, etc), so this should be called again after
+        every section has been analyzed. Generating the disassembly does not
+        modify the context, so it does not do this by itself.
+        """
+        if not common.GlobalConfig.DISASSEMBLE_UNKNOWN_INSTRUCTIONS and self.hasUnimplementedIntrs:
+            return
+
+        for instrOffset in range(4, self.sizew * 4, 4):
+            labelSym = self._lookupLabelSymbol(instrOffset)
+            if labelSym is None or labelSym.overlayCategory != self.overlayCategory:
+                continue
+            if common.GlobalConfig.IGNORE_BRANCHES and labelSym.getTypeSpecial() != common.SymbolSpecialType.gccexcepttablelabel:
+                # Not emitted by `getLabelForOffset`
+                continue
+            labelSym.isDefined = True
+            labelSym.sectionType = self.sectionType
+            labelSym.vromAddress = self.getVromOffset(instrOffset)
+
+    def clearResolvedDisplayReferences(self) -> None:
+        "Discards the symbols kept by `resolveDisplayReferences`"
+        self._resolvedLabels = None
+        self._resolvedBranchTargets = None
+        self._resolvedHiSymbols = None
+
+    def _getNumericLabelIndex(self, labelSym: common.ContextSymbol) -> int|None:
+        """
+        Returns the number used to emit the given label as a numeric local
+        label, or `None` if the label should be emitted by name.
+
+        Only plain branch labels of this function which are not referenced
+        from anywhere else can be emitted as numeric labels.
+        """
+        if common.GlobalConfig.BRANCH_LABEL_STYLE != common.BranchLabelStyle.NUMERIC:
+            return None
+        if not (self.vram <= labelSym.vram < self.vramEnd):
+            # Branches to other functions (i.e. function continuations) need the label name
+            return None
+        if labelSym.getTypeSpecial() != common.SymbolSpecialType.branchlabel:
+            return None
+        if labelSym.name is not None or labelSym.nameGetCallback is not None:
+            return None
+        if len(labelSym.referenceSymbols) > 0:
+            return None
+        index = self.contextSym.branchLabels.index(labelSym.vram)
+        if index is None:
+            return None
+        return index + 1
+
+    def _getBranchLabelReference(self, labelSym: common.ContextSymbol, instrOffset: int) -> str:
+        numericIndex = self._getNumericLabelIndex(labelSym)
+        if numericIndex is None:
+            return labelSym.getName()
+        # Branching to itself refers to the label emitted right before this instruction
+        direction = "f" if labelSym.vram > self.getVramOffset(instrOffset) else "b"
+        return f"{numericIndex}{direction}"
+
     def _getImmOverrideForInstruction(self, instr: rabbitizer.Instruction, instrOffset: int, isSplittedSymbol: bool=False) -> tuple[str|None, common.RelocationInfo|None]:
+        immOverride, relocInfo = self._getDefaultImmOverrideForInstruction(instr, instrOffset, isSplittedSymbol=isSplittedSymbol)
+        if common.GlobalConfig.OPERAND_SYMBOLIZATION_CALLBACK is not None:
+            customImm = common.GlobalConfig.OPERAND_SYMBOLIZATION_CALLBACK(instr, self.getVramOffset(instrOffset), immOverride, relocInfo)
+            if customImm is not None:
+                immOverride = customImm
+        return immOverride, relocInfo
+
+    def _getDefaultImmOverrideForInstruction(self, instr: rabbitizer.Instruction, instrOffset: int, isSplittedSymbol: bool=False) -> tuple[str|None, common.RelocationInfo|None]:
         if self.pointersRemoved:
             return None, None
 
         relocInfo = self.getReloc(instrOffset, instr)
         if relocInfo is not None and not relocInfo.isRelocNone():
+            if relocInfo.relocType == common.RelocType.MIPS_PC16 and isinstance(relocInfo.symbol, common.ContextSymbol) and relocInfo.addend == 0:
+                # Keep relocated branches consistent with the chosen label style
+                return self._getBranchLabelReference(relocInfo.symbol, instrOffset), relocInfo
             ignoredRelocs = set()
             if self.gpRelHack:
                 ignoredRelocs.add(common.RelocType.MIPS_GPREL16)
@@ -718,11 +1067,9 @@ class SymbolFunction(SymbolText):
         if instr.isBranch() or instr.isUnconditionalBranch():
             if common.GlobalConfig.IGNORE_BRANCHES:
                 return None, None
-            branchOffset = instr.getBranchOffsetGeneric()
-            targetBranchVram = self.getVramOffset(instrOffset + branchOffset)
-            labelSymbol = self.getSymbol(targetBranchVram, tryPlusOffset=False)
+            labelSymbol = self._lookupBranchTargetSymbol(instr, instrOffset)
             if labelSymbol is not None:
-                return labelSymbol.getName(), None
+                return self._getBranchLabelReference(labelSymbol, instrOffset), None
             return None, None
 
         if instr.hasOperandAlias(rabbitizer.OperandType.cpu_immediate):
@@ -734,30 +1081,35 @@ class SymbolFunction(SymbolText):
 
             elif instr.canBeHi():
                 # Unpaired LUI
-                relocInfo = self._generateHiLoConstantReloc(instr.getProcessedImmediate()<<16, instr, None)
+                if instrOffset in self.instrAnalyzer.hiToLowDict:
+                    relocInfo = self._generateHiLoConstantReloc(instr.getProcessedImmediate()<<16, instr, None)
+                else:
+                    relocInfo = self._generateUnpairedHiReloc(instr.getProcessedImmediate()<<16, instr)
                 if relocInfo is not None:
                     return relocInfo.getNameWithReloc(isSplittedSymbol=isSplittedSymbol), relocInfo
 
         return None, None
 
     def getLabelForOffset(self, instructionOffset: int, migrate: bool=False) -> str:
-        if common.GlobalConfig.IGNORE_BRANCHES or instructionOffset == 0:
+        if instructionOffset == 0:
             # Skip over this function to avoid duplication
             return ""
 
-        currentVram = self.getVramOffset(instructionOffset)
-        currentVrom = self.getVromOffset(instructionOffset)
-        labelSym = self.getSymbol(currentVram, vromAddress=currentVrom, tryPlusOffset=False)
+        labelSym = self._lookupLabelSymbol(instructionOffset)
 
         if labelSym is None or labelSym.overlayCategory != self.overlayCategory:
             return ""
 
-        labelSym.isDefined = True
-        labelSym.sectionType = self.sectionType
-        labelSym.vromAddress = currentVrom
+        if common.GlobalConfig.IGNORE_BRANCHES:
+            # Landing pads are referenced from the except tables, so they must always be emitted
+            if labelSym.getTypeSpecial() != common.SymbolSpecialType.gccexcepttablelabel:
+                return ""
+
         labelSymType = labelSym.getTypeSpecial()
 
         useLabelMacro = labelSymType is None or labelSymType == common.SymbolSpecialType.function or (labelSymType == common.SymbolSpecialType.jumptablelabel and not migrate) or labelSymType == common.SymbolSpecialType.gccexcepttablelabel
+        if labelSymType == common.SymbolSpecialType.branchlabel and common.GlobalConfig.BRANCH_LABEL_STYLE == common.BranchLabelStyle.GLOBAL:
+            useLabelMacro = True
         if not useLabelMacro:
             if common.GlobalConfig.ASM_GLOBALIZE_TEXT_LABELS_REFERENCED_BY_NON_JUMPTABLE:
                 # Check if any non-jumptable symbol references this label
@@ -774,21 +1126,32 @@ class SymbolFunction(SymbolText):
             if common.GlobalConfig.ASM_TEXT_FUNC_AS_LABEL:
                 label += f"{labelSym.getName()}:{common.GlobalConfig.LINE_ENDS}"
         else:
-            label = labelSym.getName() + ":" + common.GlobalConfig.LINE_ENDS
-        label = (" " * common.GlobalConfig.ASM_INDENTATION_LABELS) + label
+            numericIndex = self._getNumericLabelIndex(labelSym)
+            if numericIndex is not None:
+                label = f"{numericIndex}:{common.GlobalConfig.LINE_ENDS}"
+            else:
+                label = labelSym.getName() + ":" + common.GlobalConfig.LINE_ENDS
+        label = common.Utils.asmIndentation(common.GlobalConfig.ASM_INDENTATION_LABELS) + label
         return label
 
     def _emitInstruction(self, instr: rabbitizer.Instruction, instructionOffset: int, wasLastInstABranch: bool, isSplittedSymbol: bool=False) -> str:
         immOverride, relocInfo = self._getImmOverrideForInstruction(instr, instructionOffset, isSplittedSymbol=isSplittedSymbol)
         comment = self.generateAsmLineComment(instructionOffset, instr.getRaw())
         extraLJust = 0
+        delaySlotPadding = ""
 
         if wasLastInstABranch:
             extraLJust = -1
-            comment += " "
+            delaySlotPadding = " "
 
         line = instr.disassemble(immOverride, extraLJust=extraLJust)
 
+        if immOverride is None and self._canEmitAsLi(instr):
+            # `li` is shorter than both `addiu` and `ori`, so pad it to keep the operands aligned
+            opcodeName = instr.getOpcodeName()
+            line = "li" + " " * (len(opcodeName) - 2) + line[len(opcodeName):]
+            line = line.replace(", $zero, ", ", ", 1).replace(", $0, ", ", ", 1)
+
         if self.gpRelHack:
             # Get rid of `%gp_rel` and `$gp` since old assemblers don't support `%gp_rel`.
             #
@@ -814,7 +1177,82 @@ class SymbolFunction(SymbolText):
                 endComment += f" /* gp_rel: {immOverride} */"
                 self.endOfLineComment[instructionOffset//4] = endComment
 
-        return f"{comment}  {line}"
+        return self.joinCommentAndStatement(comment, delaySlotPadding + line, defaultSpacing=2)
+
+
+    def _canEmitAsLi(self, instr: rabbitizer.Instruction) -> bool:
+        """
+        Only the forms the assembler would pick by itself when expanding a
+        16-bit `li` are converted, so it reassembles into the same instruction.
+        """
+        if not common.GlobalConfig.ASM_PSEUDO_LI or not rabbitizer.config.pseudos_enablePseudos:
+            return False
+        if instr.rs.value != 0:
+            return False
+        if instr.uniqueId == rabbitizer.InstrId.cpu_addiu:
+            return True
+        if instr.uniqueId == rabbitizer.InstrId.cpu_ori:
+            # Values fitting on a signed 16-bit immediate are expanded into an `addiu` instead
+            return instr.getProcessedImmediate() >= 0x8000
+        return False
+
+    def _canFoldHiLoIntoLa(self, instructionOffset: int, wasLastInstABranch: bool) -> bool:
+        if not common.GlobalConfig.ASM_FOLD_HI_LO_INTO_LA:
+            return False
+        if common.GlobalConfig.PIC or common.GlobalConfig.EMIT_INLINE_RELOC or self.gpRelHack:
+            return False
+        if wasLastInstABranch:
+            # `la` would expand into two instructions inside a delay slot
+            return False
+        if common.GlobalConfig.ABI in {common.Abi.O64, common.Abi.N64, common.Abi.EABI64}:
+            # `la` expands into a full 64-bit address load on 64-bit ABIs
+            return False
+        if common.GlobalConfig.GP_VALUE is not None:
+            # The assembler may turn `la` into a single `$gp` relative `addiu` if the symbol ends up being small data (`-G`)
+            return False
+
+        loOffset = self.instrAnalyzer.hiToLowDict.get(instructionOffset)
+        if loOffset != instructionOffset + 4:
+            return False
+
+        hiInstr = self.instructions[instructionOffset//4]
+        loInstr = self.instructions[loOffset//4]
+        if hiInstr.uniqueId != rabbitizer.InstrId.cpu_lui or loInstr.uniqueId != rabbitizer.InstrId.cpu_addiu:
+            return False
+        if hiInstr.rt != loInstr.rt or loInstr.rs != hiInstr.rt:
+            return False
+
+        # A label on the `addiu` means something may jump right in the middle of the `la`
+        loVram = self.getVramOffset(loOffset)
+        loVrom = self.getVromOffset(loOffset)
+        if self.getSymbol(loVram, vromAddress=loVrom, tryPlusOffset=False) is not None:
+            return False
+
+        hiReloc = self.getReloc(instructionOffset, hiInstr)
+        loReloc = self.getReloc(loOffset, loInstr)
+        if hiReloc is None or loReloc is None:
+            return False
+        if hiReloc.relocType != common.RelocType.MIPS_HI16 or loReloc.relocType != common.RelocType.MIPS_LO16:
+            return False
+        if hiReloc.symbol is not loReloc.symbol or hiReloc.addend != loReloc.addend:
+            return False
+        if hiReloc.staticReference is not None or loReloc.staticReference is not None:
+            return False
+        if isinstance(loReloc.symbol, common.ContextSymbol) and loReloc.symbol.isSmallData:
+            return False
+        return True
+
+    def _emitLa(self, instructionOffset: int) -> str:
+        hiInstr = self.instructions[instructionOffset//4]
+        loReloc = self.getReloc(instructionOffset + 4, self.instructions[instructionOffset//4 + 1])
+        assert loReloc is not None
+
+        comment = self.generateAsmLineComment(instructionOffset, hiInstr.getRaw())
+        line = hiInstr.disassemble(loReloc.getName())
+        assert line.startswith("lui"), line
+        # `la` has one less character than `lui`, so pad it to keep the operands aligned
+        line = "la " + line[3:]
+        return self.joinCommentAndStatement(comment, line, defaultSpacing=2)
 
 
     def _emitCpload(self, instr: rabbitizer.Instruction, instructionOffset: int, wasLastInstABranch: bool, isSplittedSymbol: bool=False) -> str:
@@ -838,6 +1276,47 @@ class SymbolFunction(SymbolText):
             # don't emit the other instructions which are part of .cpload if the directive was emitted
         return output
 
+    def canBeAssembledInReorderMode(self) -> bool:
+        """
+        Checks if every delay slot of this function is a `nop` which the
+        assembler would insert by itself in reorder mode.
+
+        Other hazards the assembler may fix by inserting `nop`s on reorder mode
+        (like the load delay slots of MIPS I) are not checked, so this is a
+        heuristic. See `GlobalConfig.ASM_REORDER_EQUIVALENT_FUNCTIONS`.
+        """
+
+        if self.isLikelyHandwritten or self.isRsp or self.hasUnimplementedIntrs:
+            return False
+        if common.GlobalConfig.ARCHLEVEL <= common.ArchLevel.MIPS1:
+            return False
+
+        nInstr = len(self.instructions)
+        for i, instr in enumerate(self.instructions):
+            if not instr.hasDelaySlot():
+                continue
+            if instr.isBranchLikely() or i + 1 >= nInstr:
+                return False
+            if not self.instructions[i+1].isNop():
+                return False
+            delaySlotOffset = (i + 1) * 4
+            if self.getSymbol(self.getVramOffset(delaySlotOffset), vromAddress=self.getVromOffset(delaySlotOffset), tryPlusOffset=False) is not None:
+                return False
+        return True
+
+    def _getAssemblerModesDirectives(self, reorderMode: bool) -> tuple[str, str]:
+        "Returns the directives to emit before and after this function to set the assembler modes"
+        if common.GlobalConfig.ASM_ASSEMBLER_MODES_PLACEMENT != common.AsmAssemblerModesPlacement.FUNCTION:
+            return "", ""
+
+        indentation = common.Utils.asmIndentation(common.GlobalConfig.ASM_INDENTATION)
+        before = f"{indentation}.set noat{common.GlobalConfig.LINE_ENDS}"
+        after = f"{indentation}.set at{common.GlobalConfig.LINE_ENDS}"
+        if not reorderMode:
+            before += f"{indentation}.set noreorder{common.GlobalConfig.LINE_ENDS}"
+            after = f"{indentation}.set reorder{common.GlobalConfig.LINE_ENDS}" + after
+        return before, after
+
     def disassemble(self, migrate: bool=False, useGlobalLabel: bool=True, isSplittedSymbol: bool=False) -> str:
         output = ""
 
@@ -858,30 +1337,65 @@ class SymbolFunction(SymbolText):
 
         output += self.contextSym.getReferenceeSymbols()
         output += self.getPrevAlignDirective(0)
+        output += self.getDiagnosticsComments()
 
         if self.isLikelyHandwritten and common.GlobalConfig.ASM_COMMENT:
             if not self.isRsp:
                 # RSP functions are always handwritten, so this is redundant
                 output += "/* Handwritten function */" + common.GlobalConfig.LINE_ENDS
 
+        if common.GlobalConfig.ASM_COMMENT and common.GlobalConfig.ASM_COMMENT_FRAME_INFO:
+            output += self.getFrameInfo().toComment() + common.GlobalConfig.LINE_ENDS
+
         self._generateRelocsFromInstructionAnalyzer()
 
+        if common.GlobalConfig.ASM_COMMENT:
+            continuation = self.context.getFunctionContinuationStartingAt(self.vram)
+            if continuation is not None:
+                output += f"/* Continuation of the code at 0x{continuation.fromRange.start:08X}-0x{continuation.fromRange.end:08X} */{common.GlobalConfig.LINE_ENDS}"
+
+        reorderMode = False
+        if common.GlobalConfig.ASM_REORDER_EQUIVALENT_FUNCTIONS and common.GlobalConfig.ASM_ASSEMBLER_MODES_PLACEMENT == common.AsmAssemblerModesPlacement.FUNCTION:
+            reorderMode = self.canBeAssembledInReorderMode()
+        modesBefore, modesAfter = self._getAssemblerModesDirectives(reorderMode)
+        output += modesBefore
+
         symName = self.getName()
         symSize = self.contextSym.getSize()
         output += self.getSymbolAsmDeclaration(symName, useGlobalLabel)
 
+        emitSourceLines = common.GlobalConfig.ASM_COMMENT and common.GlobalConfig.ASM_COMMENT_SOURCE_LINES and len(self.context.sourceLines) > 0
+        lastSourceLocation: common.SourceLocation|None = None
+
         wasLastInstABranch = False
+        foldedLoOffset: int|None = None
         instructionOffset = 0
         for instr in self.instructions:
             currentLine = self.getLabelForOffset(instructionOffset, migrate=migrate)
 
+            if emitSourceLines:
+                sourceLocation = self.context.getSourceLine(self.getVramOffset(instructionOffset))
+                if sourceLocation is not None and sourceLocation != lastSourceLocation:
+                    currentLine += f"/* {sourceLocation} */{common.GlobalConfig.LINE_ENDS}"
+                    lastSourceLocation = sourceLocation
+
             isCpload = instructionOffset in self.instrAnalyzer.cploadOffsets
-            if isCpload:
+            if reorderMode and wasLastInstABranch:
+                # The assembler fills this delay slot by itself
+                pass
+            elif instructionOffset == foldedLoOffset:
+                # Already emitted as part of the previous `la`, keep its comment on its own line
+                if common.GlobalConfig.ASM_COMMENT:
+                    currentLine += self.generateAsmLineComment(instructionOffset, instr.getRaw())
+            elif isCpload:
                 currentLine += self._emitCpload(instr, instructionOffset, wasLastInstABranch, isSplittedSymbol=isSplittedSymbol)
+            elif self._canFoldHiLoIntoLa(instructionOffset, wasLastInstABranch):
+                currentLine += self._emitLa(instructionOffset)
+                foldedLoOffset = instructionOffset + 4
             else:
                 currentLine += self._emitInstruction(instr, instructionOffset, wasLastInstABranch, isSplittedSymbol=isSplittedSymbol)
 
-            currentLine += self.getEndOfLineComment(instructionOffset//4)
+            currentLine += self.getEndOfLineComment(instructionOffset//4, currentLine)
             if currentLine != "":
                 currentLine += common.GlobalConfig.LINE_ENDS
 
@@ -900,10 +1414,17 @@ class SymbolFunction(SymbolText):
 
                 output += self.getSizeDirective(symName)
 
+        if common.GlobalConfig.ASM_COMMENT:
+            continuation = self.context.getFunctionContinuationEndingAt(self.vramEnd)
+            if continuation is not None:
+                output += f"/* Continues at 0x{continuation.toRange.start:08X} */{common.GlobalConfig.LINE_ENDS}"
+
         nameEnd = self.getNameEnd()
         if nameEnd is not None:
             output += self.getSymbolAsmDeclaration(nameEnd, useGlobalLabel)
 
+        output += modesAfter
+
         return output
 
     def disassembleAsData(self, useGlobalLabel: bool=True, isSplittedSymbol: bool=False) -> str:
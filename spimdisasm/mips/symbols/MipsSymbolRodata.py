@@ -15,6 +15,7 @@ class SymbolRodata(SymbolBase):
         super().__init__(context, vromStart, vromEnd, inFileOffset, vram, words, common.FileSectionType.Rodata, segmentVromStart, overlayCategory)
 
         self.stringEncoding = common.GlobalConfig.RODATA_STRING_ENCODING
+        self.charmap = common.GlobalConfig.RODATA_CHARMAP
 
     def isJumpTable(self) -> bool:
         # jumptables must have at least 3 labels
@@ -73,12 +74,13 @@ class SymbolRodata(SymbolBase):
         if len(self.contextSym.referenceSymbols) > 0:
             return False
         if len(self.contextSym.referenceFunctions) > 1:
-            return False
+            if self.contextSym.getSharedRodataMigrationPolicy() == common.SharedRodataMigrationPolicy.KEEP_IN_RODATA:
+                return False
 
         if self.isMaybeConstVariable():
             if common.GlobalConfig.ALLOW_MIGRATING_CONST_VARIABLES:
                 return True
-            if not common.GlobalConfig.COMPILER.value.allowRdataMigration:
+            if not common.GlobalConfig.getCompilerProperties().allowRdataMigration:
                 return False
 
         return True
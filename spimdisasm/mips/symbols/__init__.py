@@ -14,5 +14,6 @@ from .MipsSymbolData import SymbolData as SymbolData
 from .MipsSymbolRodata import SymbolRodata as SymbolRodata
 from .MipsSymbolBss import SymbolBss as SymbolBss
 from .MipsSymbolGccExceptTable import SymbolGccExceptTable as SymbolGccExceptTable
+from .MipsSymbolBin import SymbolBin as SymbolBin
 
 from .MipsSymbolFunction import SymbolFunction as SymbolFunction
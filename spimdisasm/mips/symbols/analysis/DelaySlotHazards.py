@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import rabbitizer
+
+
+_REG_ZERO = 0
+_REG_RA = 31
+
+
+def _getReadGprs(instr: rabbitizer.Instruction) -> set[int]:
+    regs: set[int] = set()
+    if instr.readsRs():
+        regs.add(instr.rs.value)
+    if instr.readsRt():
+        regs.add(instr.rt.value)
+    if instr.readsRd():
+        regs.add(instr.rd.value)
+    regs.discard(_REG_ZERO)
+    return regs
+
+def _getModifiedGprs(instr: rabbitizer.Instruction) -> set[int]:
+    regs: set[int] = set()
+    if instr.modifiesRt():
+        regs.add(instr.rt.value)
+    if instr.modifiesRd():
+        regs.add(instr.rd.value)
+    regs.discard(_REG_ZERO)
+    return regs
+
+
+@dataclasses.dataclass
+class DelaySlotHazard:
+    """
+    A delay slot which would behave differently than it looks like, or which
+    is unlikely to be emitted by a compiler or an assembler in reorder mode.
+
+    Usually a sign of handwritten code or of data being decoded as code.
+
+    The recommended way to get these is by using
+    `SymbolFunction.getDelaySlotHazards`.
+    """
+
+    instrOffset: int
+    "Offset of the instruction in the delay slot, relative to the start of the function"
+    description: str
+
+    def toComment(self) -> str:
+        return f" /* delay slot hazard: {self.description} */"
+
+    @staticmethod
+    def fromInstructions(instructions: list[rabbitizer.Instruction]) -> list[DelaySlotHazard]:
+        hazards: list[DelaySlotHazard] = []
+
+        for i in range(len(instructions) - 1):
+            instr = instructions[i]
+            if not instr.hasDelaySlot():
+                continue
+            delaySlot = instructions[i+1]
+            delaySlotOffset = (i + 1) * 4
+
+            if not delaySlot.isImplemented() or not delaySlot.isValid():
+                hazards.append(DelaySlotHazard(delaySlotOffset, "invalid instruction"))
+                continue
+            if delaySlot.hasDelaySlot():
+                hazards.append(DelaySlotHazard(delaySlotOffset, "branch or jump inside a delay slot"))
+                continue
+
+            modifiedRegs = _getModifiedGprs(delaySlot)
+            conditionRegs = _getReadGprs(instr)
+            if len(modifiedRegs & conditionRegs) > 0:
+                hazards.append(DelaySlotHazard(delaySlotOffset, f"modifies a register used by the {instr.getOpcodeName()}"))
+                continue
+
+            if instr.doesLink():
+                linkReg = instr.rd.value if instr.modifiesRd() else _REG_RA
+                if linkReg in modifiedRegs or linkReg in _getReadGprs(delaySlot):
+                    hazards.append(DelaySlotHazard(delaySlotOffset, f"uses the link register of the {instr.getOpcodeName()}"))
+
+        return hazards
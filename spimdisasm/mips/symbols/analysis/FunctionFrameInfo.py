@@ -0,0 +1,98 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+import rabbitizer
+
+
+_REG_GP = 28
+_REG_SP = 29
+_REG_FP = 30
+_REG_RA = 31
+
+_CALLEE_SAVED_GPRS = set(range(16, 24)) | {_REG_FP, _REG_RA}
+"$s0-$s7, $fp and $ra"
+_CALLEE_SAVED_FPRS = set(range(20, 32))
+"$f20-$f31"
+
+
+def _getUsedGprs(instr: rabbitizer.Instruction) -> set[int]:
+    regs: set[int] = set()
+    if instr.readsRs():
+        regs.add(instr.rs.value)
+    if instr.readsRt() or instr.modifiesRt():
+        regs.add(instr.rt.value)
+    if instr.readsRd() or instr.modifiesRd():
+        regs.add(instr.rd.value)
+    return regs
+
+
+@dataclasses.dataclass
+class FunctionFrameInfo:
+    """
+    Summary of the stack frame and register usage of a function, meant as a
+    decompilation aid.
+
+    The recommended way to instance this class is by using
+    `SymbolFunction.getFrameInfo`.
+    """
+
+    stackFrameSize: int = 0
+    "Size reserved by the first `addiu $sp, $sp, -N` of the function, or 0 if the function doesn't have a stack frame"
+    savedRegisters: dict[str, int] = dataclasses.field(default_factory=dict)
+    "Callee-saved registers stored on the stack. key: register name, value: offset relative to `$sp`"
+    usesGp: bool = False
+    "The function reads or writes the `$gp` register"
+    usesFp: bool = False
+    "The function uses the `$fp` register for something other than saving and restoring it"
+    isLeaf: bool = True
+    "The function doesn't call other functions. Tail calls are not considered calls"
+
+    @staticmethod
+    def fromInstructions(instructions: list[rabbitizer.Instruction]) -> FunctionFrameInfo:
+        info = FunctionFrameInfo()
+
+        for instr in instructions:
+            if instr.doesLink():
+                info.isLeaf = False
+
+            usedGprs = _getUsedGprs(instr)
+            isStackAccess = instr.doesDereference() and instr.readsRs() and instr.rs.value == _REG_SP
+
+            if info.stackFrameSize == 0 and instr.uniqueId in {rabbitizer.InstrId.cpu_addiu, rabbitizer.InstrId.cpu_daddiu}:
+                if instr.rs.value == _REG_SP and instr.rt.value == _REG_SP:
+                    imm = instr.getProcessedImmediate()
+                    if imm < 0:
+                        info.stackFrameSize = -imm
+
+            if isStackAccess and instr.doesStore():
+                savedName: str|None = None
+                if instr.isFloat():
+                    if instr.ft.value in _CALLEE_SAVED_FPRS:
+                        savedName = instr.ft.name
+                elif instr.rt.value in _CALLEE_SAVED_GPRS:
+                    savedName = instr.rt.name
+                if savedName is not None and savedName not in info.savedRegisters:
+                    info.savedRegisters[savedName] = instr.getProcessedImmediate()
+
+            if _REG_GP in usedGprs:
+                info.usesGp = True
+            if _REG_FP in usedGprs and not isStackAccess:
+                info.usesFp = True
+
+        return info
+
+    def toComment(self) -> str:
+        output = f"Frame size: 0x{self.stackFrameSize:X}"
+        if len(self.savedRegisters) > 0:
+            output += ", saved: " + ", ".join(f"${name} (0x{offset:X})" for name, offset in self.savedRegisters.items())
+        if self.usesGp:
+            output += ", uses $gp"
+        if self.usesFp:
+            output += ", uses $fp"
+        output += ", leaf" if self.isLeaf else ", non-leaf"
+        return f"/* {output} */"
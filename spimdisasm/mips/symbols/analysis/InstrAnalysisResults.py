@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+
+# SPDX-FileCopyrightText: © 2025 Decompollaborate
+# SPDX-License-Identifier: MIT
+
+from __future__ import annotations
+
+import dataclasses
+from types import MappingProxyType
+from typing import Mapping
+
+from .InstrAnalyzer import InstrAnalyzer, IndirectCallTarget
+
+
+@dataclasses.dataclass(frozen=True)
+class InstrAnalysisResults:
+    """
+    Read-only view of the results of the instruction analysis of a function.
+
+    Every offset is relative to the start of the function.
+
+    The recommended way to instance this class is by using
+    `SymbolFunction.getAnalysisResults` after the function has been analyzed.
+    """
+
+    branchTargets: Mapping[int, int]
+    "key: branch instruction offset, value: target vram"
+    funcCalls: Mapping[int, int]
+    "key: function call instruction offset, value: target vram"
+    tailCalls: Mapping[int, int]
+    "key: tail call instruction offset, value: target vram. Subset of `funcCalls`"
+    indirectFuncCalls: Mapping[int, int]
+    "key: `jalr` instruction offset, value: target vram"
+    indirectCallTargets: Mapping[int, tuple[IndirectCallTarget, ...]]
+    "key: `jalr` instruction offset, value: inferred call targets. Only filled by `mips.inferIndirectCallTargets`"
+    referencedJumptables: Mapping[int, int]
+    "key: offset of the instruction referencing the jumptable, value: jumptable vram"
+    jumptableJumps: Mapping[int, int]
+    "key: `jr` instruction offset, value: jumptable vram"
+    hiToLo: Mapping[int, int]
+    "key: %hi instruction offset, value: paired %lo instruction offset"
+    loToHi: Mapping[int, int]
+    "key: %lo instruction offset, value: paired %hi instruction offset"
+    symbols: Mapping[int, int]
+    "key: offset of an instruction referencing a symbol, value: symbol vram"
+    constants: Mapping[int, int]
+    "key: offset of an instruction building a constant, value: constant"
+    gpReferencedSymbols: frozenset[int]
+    "Vrams of the symbols accessed relative to `$gp`"
+
+    @staticmethod
+    def fromAnalyzer(instrAnalyzer: InstrAnalyzer) -> InstrAnalysisResults:
+        return InstrAnalysisResults(
+            branchTargets=MappingProxyType(dict(instrAnalyzer.branchInstrOffsets)),
+            funcCalls=MappingProxyType(dict(instrAnalyzer.funcCallInstrOffsets)),
+            tailCalls=MappingProxyType(dict(instrAnalyzer.tailCallInstrOffsets)),
+            indirectFuncCalls=MappingProxyType(dict(instrAnalyzer.indirectFunctionCallIntrOffset)),
+            indirectCallTargets=MappingProxyType({offset: tuple(targets) for offset, targets in instrAnalyzer.indirectCallTargets.items()}),
+            referencedJumptables=MappingProxyType(dict(instrAnalyzer.referencedJumpTableOffsets)),
+            jumptableJumps=MappingProxyType(dict(instrAnalyzer.jumpRegisterIntrOffset)),
+            hiToLo=MappingProxyType(dict(instrAnalyzer.hiToLowDict)),
+            loToHi=MappingProxyType(dict(instrAnalyzer.lowToHiDict)),
+            symbols=MappingProxyType(dict(instrAnalyzer.symbolInstrOffset)),
+            constants=MappingProxyType(dict(instrAnalyzer.constantInstrOffset)),
+            gpReferencedSymbols=frozenset(instrAnalyzer.gpReferencedSymbols),
+        )
@@ -6,6 +6,7 @@
 from __future__ import annotations
 
 import dataclasses
+import enum
 import rabbitizer
 
 from .... import common
@@ -42,6 +43,26 @@ class GpSetInfo:
     value: int
 
 
+class IndirectCallConfidence(enum.Enum):
+    """How reliable an inferred target of an indirect function call is"""
+
+    HIGH = "high"
+    "The called register is set directly to the address of the function, either with a `%hi`/`%lo` pair or through the GOT"
+    MEDIUM = "medium"
+    "The called register is loaded from a variable holding a single function pointer. The target is the initial value of said variable"
+    LOW = "low"
+    "The called register is loaded from a table of function pointers. The target is one of the entries of the table"
+
+@dataclasses.dataclass(frozen=True)
+class IndirectCallTarget:
+    """A possible target of an indirect function call (`jalr`)"""
+
+    vram: int
+    confidence: IndirectCallConfidence
+    pointerVram: int|None = None
+    "Address of the variable or table the target was read from, if any"
+
+
 class InstrAnalyzer:
     def __init__(self, funcVram: int, context: common.Context) -> None:
         self.funcVram = funcVram
@@ -71,6 +92,8 @@ class InstrAnalyzer:
         "key: func call instruction offset, value: target vram"
         self.funcCallOutsideRangesOffsets: dict[int, int] = dict()
         "key: func call instruction offset, value: target vram which is outside the known vram address range"
+        self.tailCallInstrOffsets: dict[int, int] = dict()
+        "key: offset of a jump or branch which leaves the function without linking, value: target vram. Subset of `funcCallInstrOffsets`"
 
         # Jump register (jumptables)
         self.jumpRegisterIntrOffset: dict[int, int] = dict()
@@ -81,6 +104,10 @@ class InstrAnalyzer:
         # Jump and link (functions)
         self.indirectFunctionCallIntrOffset: dict[int, int] = dict()
         self.indirectFunctionCallOffsets: dict[int, int] = dict()
+        self.indirectFunctionCallRegOffsets: dict[int, int] = dict()
+        "key: `jalr` instruction offset, value: offset of the instruction which set the called register"
+        self.indirectCallTargets: dict[int, list[IndirectCallTarget]] = dict()
+        "key: `jalr` instruction offset, value: inferred targets. Filled by `mips.inferIndirectCallTargets`"
 
         # Constants
         self.constantHiInstrOffset: dict[int, int] = dict()
@@ -101,6 +128,9 @@ class InstrAnalyzer:
 
         self.symbolInstrOffset: dict[int, int] = dict()
 
+        self.kseg1AliasInstrOffsets: set[int] = set()
+        "Offsets of the instructions referencing a symbol through its KSEG1 alias. The address stored for them is the KSEG0 one"
+
         self.possibleSymbolTypes: dict[int, dict[SymbolTypeInfo, int]] = dict()
         "key: address, value: {<SymbolTypeInfo>: number of times this type appears in code}"
         self.symbolTypesOffsets: dict[int, SymbolTypeInfo] = dict()
@@ -164,6 +194,53 @@ class InstrAnalyzer:
         self.funcCallInstrOffsets[instrOffset] = target
 
 
+    def processTailCalls(self, instructions: list[rabbitizer.Instruction]) -> None:
+        """
+        Reclassifies the unconditional jumps and branches which leave the
+        function as tail calls, so their targets are treated as functions
+        instead of branch labels.
+
+        Must be called after every instruction of the function has been
+        processed.
+        """
+        funcSize = len(instructions) * 4
+
+        for instrOffset, branch in list(self.branchTargetInstrOffsets.items()):
+            if 0 <= branch < funcSize:
+                continue
+            instr = instructions[instrOffset//4]
+            if not instr.isUnconditionalBranch():
+                continue
+
+            target = self.branchInstrOffsets[instrOffset]
+            if self.context.areFunctionContinuations(self.funcVram, target):
+                # The function continues on another range
+                continue
+            contextSym = self.context.globalSegment.getSymbol(target, tryPlusOffset=False)
+            if contextSym is not None and isinstance(contextSym.type, common.SymbolSpecialType) and contextSym.type.isTargetLabel():
+                # Branching to a known label of another function, not a call
+                continue
+
+            del self.branchInstrOffsets[instrOffset]
+            del self.branchTargetInstrOffsets[instrOffset]
+            if branch not in self.branchTargetInstrOffsets.values():
+                self.branchLabelOffsets.discard(branch)
+
+            if not self.context.isAddressInGlobalRange(target):
+                self.funcCallOutsideRangesOffsets[instrOffset] = target
+            self.referencedVramsInstrOffset[instrOffset] = target
+            self.funcCallInstrOffsets[instrOffset] = target
+            self.tailCallInstrOffsets[instrOffset] = target
+
+        for instrOffset, target in self.funcCallInstrOffsets.items():
+            instr = instructions[instrOffset//4]
+            if not instr.isJumpWithAddress() or instr.doesLink():
+                continue
+            if self.funcVram <= target < self.funcVram + funcSize:
+                continue
+            self.tailCallInstrOffsets[instrOffset] = target
+
+
     def processConstant(self, regsTracker: rabbitizer.RegistersTracker, luiInstr: rabbitizer.Instruction, luiOffset: int, lowerInstr: rabbitizer.Instruction, lowerOffset: int) -> int|None:
         upperHalf = luiInstr.getProcessedImmediate() << 16
         lowerHalf = lowerInstr.getProcessedImmediate()
@@ -202,7 +279,7 @@ class InstrAnalyzer:
                         if hiValue != otherLuiInstr.getProcessedImmediate() << 16:
                             return None
 
-            if not common.GlobalConfig.COMPILER.value.pairMultipleHiToSameLow:
+            if not common.GlobalConfig.getCompilerProperties().pairMultipleHiToSameLow:
                 # IDO does not pair multiples %hi to the same %lo
                 return self.symbolLoInstrOffset[lowerOffset]
 
@@ -254,6 +331,14 @@ class InstrAnalyzer:
             if not common.GlobalConfig.PIC:
                 return None
 
+        kseg0Address = common.GlobalConfig.getKseg0AliasVram(address)
+        if kseg0Address is not None and not self.context.totalVramRange.isInRange(address) and self.context.globalSegment.getSymbol(address, tryPlusOffset=False) is None:
+            # Uncached alias of a KSEG0 address
+            address = kseg0Address
+            self.kseg1AliasInstrOffsets.add(lowerOffset)
+            if luiOffset is not None:
+                self.kseg1AliasInstrOffsets.add(luiOffset)
+
         # filter out stuff that may not be a real symbol
         filterOut = False
         if not self.context.totalVramRange.isInRange(address):
@@ -262,6 +347,9 @@ class InstrAnalyzer:
                 filterOut |= common.GlobalConfig.SYMBOL_FINDER_FILTER_HIGH_ADDRESSES and address >= common.GlobalConfig.SYMBOL_FINDER_FILTER_ADDRESSES_ADDR_HIGH
             else:
                 filterOut |= True
+        if not common.GlobalConfig.isPointerAllowed(address):
+            # Handled the same way as addresses outside of every segment
+            filterOut = True
 
         if filterOut:
             contextSym = self.context.globalSegment.getSymbol(address)
@@ -271,7 +359,7 @@ class InstrAnalyzer:
                     filterOut = False
 
         if address > 0 and filterOut and lowerInstr.uniqueId != rabbitizer.InstrId.cpu_addiu:
-            if common.GlobalConfig.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_CONSTANTS:
+            if common.GlobalConfig.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_CONSTANTS or common.GlobalConfig.SYMBOL_FINDER_FILTERED_ADDRESSES_AS_HILO:
                 # Let's pretend this value is a constant
                 constant = address
                 self.referencedConstants.add(constant)
@@ -311,6 +399,28 @@ class InstrAnalyzer:
 
         return address
 
+    def unsetSymbolAtOffset(self, instrOffset: int) -> None:
+        "Discards the symbol or constant found at the given instruction offset and at its %hi/%lo counterpart"
+        offsets = {instrOffset}
+        hiOffset = self.lowToHiDict.get(instrOffset)
+        if hiOffset is not None:
+            offsets.add(hiOffset)
+        loOffset = self.hiToLowDict.get(instrOffset)
+        if loOffset is not None:
+            offsets.add(loOffset)
+
+        for offset in offsets:
+            self.symbolHiInstrOffset.pop(offset, None)
+            self.symbolLoInstrOffset.pop(offset, None)
+            self.symbolGpInstrOffset.pop(offset, None)
+            self.symbolInstrOffset.pop(offset, None)
+            self.referencedVramsInstrOffset.pop(offset, None)
+            self.constantHiInstrOffset.pop(offset, None)
+            self.constantLoInstrOffset.pop(offset, None)
+            self.constantInstrOffset.pop(offset, None)
+            self.hiToLowDict.pop(offset, None)
+            self.lowToHiDict.pop(offset, None)
+
     def processGotSymbol(self, address: int, instr: rabbitizer.Instruction, instrOffset: int) -> None:
         if address <= 0:
             return
@@ -454,6 +564,7 @@ class InstrAnalyzer:
 
             self.indirectFunctionCallOffsets[offset] = address
             self.indirectFunctionCallIntrOffset[instrOffset] = address
+            self.indirectFunctionCallRegOffsets[instrOffset] = offset
             if not common.GlobalConfig.PIC:
                 self.referencedVrams.add(address)
 
@@ -6,3 +6,8 @@
 from __future__ import annotations
 
 from .InstrAnalyzer import InstrAnalyzer as InstrAnalyzer
+from .InstrAnalyzer import IndirectCallConfidence as IndirectCallConfidence
+from .InstrAnalyzer import IndirectCallTarget as IndirectCallTarget
+from .InstrAnalysisResults import InstrAnalysisResults as InstrAnalysisResults
+from .FunctionFrameInfo import FunctionFrameInfo as FunctionFrameInfo
+from .DelaySlotHazards import DelaySlotHazard as DelaySlotHazard
@@ -75,7 +75,7 @@ def processArguments(args: argparse.Namespace) -> int:
     applyGlobalConfigurations()
 
     binaryPath = Path(args.binary)
-    array_of_bytes = common.Utils.readFileAsBytearray(binaryPath)
+    array_of_bytes = common.Utils.normalizeRomBytes(common.Utils.readFileAsBytearray(binaryPath))
     inputName = binaryPath.stem
 
     start = int(args.start, 16)
@@ -99,6 +99,21 @@ def processArguments(args: argparse.Namespace) -> int:
         contextPath.parent.mkdir(parents=True, exist_ok=True)
         context.saveContextToFile(contextPath)
 
+    if args.save_c_header is not None:
+        headerPath = Path(args.save_c_header)
+        headerPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveCHeaderToFile(headerPath, common.Context.parseCHeaderTypeMappingArgs(args))
+
+    if args.save_unknown_segment_report is not None:
+        reportPath = Path(args.save_unknown_segment_report)
+        reportPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveUnknownSegmentReportToFile(reportPath)
+
+    if args.save_unreferenced_functions_report is not None:
+        reportPath = Path(args.save_unreferenced_functions_report)
+        reportPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveUnreferencedFunctionsReportToFile(reportPath)
+
     return 0
 
 def addSubparser(subparser: argparse._SubParsersAction[argparse.ArgumentParser]) -> None:
@@ -50,6 +50,7 @@ def addOptionsToParser(parser: argparse.ArgumentParser) -> argparse.ArgumentPars
     parser.add_argument("--write-binary", help=f"Produce a binary from the processed file. Defaults to {common.GlobalConfig.WRITE_BINARY}", action=common.Utils.BooleanOptionalAction)
 
     parser.add_argument("--function-info", help="Specifies a path where to output a csvs sumary file of every analyzed function", metavar="PATH")
+    parser.add_argument("--json-ir", help="Specifies a path where to output a JSON representation of every analyzed section, including its symbols, relocations, instructions and data words", metavar="PATH")
 
 
     common.Context.addParametersToArgParse(parser)
@@ -156,7 +157,7 @@ def processArguments(args: argparse.Namespace) -> int:
         common.Utils.eprint(f"ERROR: '{textOutput}' is not a valid directory")
         return 2
 
-    array_of_bytes = common.Utils.readFileAsBytearray(inputPath)
+    array_of_bytes = common.Utils.normalizeRomBytes(common.Utils.readFileAsBytearray(inputPath))
     if len(array_of_bytes) == 0:
         common.Utils.eprint(f"ERROR: Input file '{inputPath}' is empty")
         return 3
@@ -210,9 +211,27 @@ def processArguments(args: argparse.Namespace) -> int:
         contextPath.parent.mkdir(parents=True, exist_ok=True)
         context.saveContextToFile(contextPath)
 
+    if args.save_c_header is not None:
+        headerPath = Path(args.save_c_header)
+        headerPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveCHeaderToFile(headerPath, common.Context.parseCHeaderTypeMappingArgs(args))
+
+    if args.save_unknown_segment_report is not None:
+        reportPath = Path(args.save_unknown_segment_report)
+        reportPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveUnknownSegmentReportToFile(reportPath)
+
+    if args.save_unreferenced_functions_report is not None:
+        reportPath = Path(args.save_unreferenced_functions_report)
+        reportPath.parent.mkdir(parents=True, exist_ok=True)
+        context.saveUnreferencedFunctionsReportToFile(reportPath)
+
     if args.function_info is not None:
         fec.FrontendUtilities.writeFunctionInfoCsv(processedFiles, Path(args.function_info))
 
+    if args.json_ir is not None:
+        mips.writeSectionsIRToFile([x for sectionFiles in processedFiles.values() for x in sectionFiles], Path(args.json_ir))
+
     common.Utils.printQuietless(500*" " + "\r", end="")
     common.Utils.printQuietless(f"Done: {args.binary}")
 